@@ -9,11 +9,47 @@ pub enum ErrorCode {
 
     #[msg("CpAmm vault owner mismatch: the vault is not owned by the expected program.")]
     InvalidCpAmmVaultOwner,
+
+    #[msg("Fee recipient token accounts do not match the fee recipients of AmmsConfig.")]
+    InvalidFeeRecipientAccount,
+
+    #[msg("Fee authority LP token account is missing or not owned by the AmmsConfig fee authority.")]
+    InvalidFeeAuthorityLpAccount,
+
+    #[msg("Referrer token account isn't in the swap input mint or is owned by the swap signer.")]
+    InvalidReferrerAccount,
+
+    #[msg("Fee authority must sign to redirect protocol fees to recipient accounts.")]
+    FeeAuthorityNotSigner,
     
+    #[msg("Account layout is already at the current version.")]
+    AccountAlreadyMigrated,
+
+    #[msg("Account data doesn't match the legacy layout of its type.")]
+    InvalidLegacyAccount,
+
+    #[msg("Account has the legacy layout of its type, which can't hold its new state. Migrate the account first.")]
+    AccountNotMigrated,
+
     // AmmsConfig
     #[msg("The provided fee rate for AmmsConfig exceeds the maximum allowed value of 10000 basis points (100%).")]
     ConfigFeeRateExceeded,
 
+    #[msg("The overridden fee rates for CpAmm exceed the maximum fee rate of its AmmsConfig.")]
+    FeeRateOverrideExceeded,
+
+    #[msg("The creator fee share for CpAmm exceeds the maximum creator fee share of its AmmsConfig.")]
+    CreatorFeeShareExceeded,
+
+    #[msg("The fee recipients list is too long, contains a zero weight or a duplicated recipient.")]
+    InvalidFeeRecipients,
+
+    #[msg("AmmsConfig is halted by the AmmsConfigsManager kill switch.")]
+    AmmsConfigHalted,
+
+    #[msg("The AmmsConfigsManager halt epoch overflowed.")]
+    HaltEpochOverflow,
+
     // CpAmm state errors
     #[msg("Quote liquidity is zero.")]
     QuoteLiquidityIsZero,
@@ -42,6 +78,93 @@ pub enum ErrorCode {
     #[msg("CpAmm is already launched.")]
     CpAmmAlreadyLaunched,
 
+    #[msg("CpAmm is closed.")]
+    CpAmmClosed,
+
+    #[msg("CpAmm is not closed.")]
+    CpAmmNotClosed,
+
+    #[msg("Only the creator can cancel CpAmm before its launch deadline expires.")]
+    CpAmmLaunchDeadlineNotExpired,
+
+    #[msg("CpAmm can't be closed while liquidity providers hold LP tokens.")]
+    CpAmmNotDrained,
+
+    #[msg("CpAmm can't be closed before its protocol fees are collected.")]
+    CpAmmProtocolFeesNotCollected,
+
+    #[msg("CpAmm is not open for trading yet.")]
+    CpAmmNotOpen,
+
+    #[msg("Launch fee schedule start fee rate exceeds the maximum or its duration is zero.")]
+    InvalidLaunchFeeSchedule,
+
+    #[msg("Donations to CpAmm are disabled by its AmmsConfig.")]
+    DonationsDisabled,
+
+    #[msg("Swaps in CpAmm are paused.")]
+    CpAmmSwapPaused,
+
+    #[msg("Providing liquidity to CpAmm is paused.")]
+    CpAmmProvidePaused,
+
+    #[msg("Withdrawing liquidity from CpAmm is paused.")]
+    CpAmmWithdrawPaused,
+
+    // LpLock errors
+    #[msg("Amount of LP tokens to lock is zero.")]
+    LpLockAmountIsZero,
+
+    #[msg("LP lock vesting must start before the unlock, and the unlock must be in the future.")]
+    InvalidLpLockSchedule,
+
+    #[msg("LP lock has no unlocked LP tokens to claim.")]
+    LpLockNothingToClaim,
+
+    #[msg("Creator LP lock share exceeds 100% or its accounts are missing.")]
+    InvalidCreatorLpLock,
+
+    // Farm errors
+    #[msg("Farm already has the maximum number of rewards.")]
+    FarmRewardsLimitReached,
+
+    #[msg("Reward mint or vault doesn't match the farm reward.")]
+    InvalidFarmRewardAccounts,
+
+    #[msg("Farm has no reward at the provided index.")]
+    InvalidFarmRewardIndex,
+
+    #[msg("Farm reward emission must end after its start and in the future, and can't be shortened.")]
+    InvalidFarmRewardSchedule,
+
+    #[msg("Amount of LP tokens to stake or unstake is zero.")]
+    FarmStakeAmountIsZero,
+
+    #[msg("Farm stake has insufficient LP tokens to unstake.")]
+    InsufficientFarmStake,
+
+    #[msg("Farm stake has no rewards to claim.")]
+    FarmNothingToClaim,
+
+    #[msg("Farm reward emission hasn't ended yet.")]
+    FarmRewardNotEnded,
+
+    #[msg("Farm reward has no unemitted tokens to reclaim.")]
+    FarmNothingToReclaim,
+
+    #[msg("Overflow error in farm rewards accounting.")]
+    FarmOverflowError,
+
+    // BondingCurve errors
+    #[msg("Bonding curve base reserves, virtual quote reserves and graduation threshold must be non-zero.")]
+    InvalidBondingCurveParameters,
+
+    #[msg("Swap exceeds the real reserves of the bonding curve.")]
+    BondingCurveReservesExceeded,
+
+    #[msg("Bonding curve price at graduation differs from the launch price of CpAmm.")]
+    BondingCurveGraduationPriceMismatch,
+
     // CpAmm operations inputs errors
     #[msg("Provided quote liquidity is zero.")]
     ProvidedQuoteLiquidityIsZero,
@@ -52,6 +175,9 @@ pub enum ErrorCode {
     #[msg("Provided liquidity tokens are zero.")]
     ProvidedLpTokensIsZero,
 
+    #[msg("Donated base and quote liquidity are zero.")]
+    DonationIsZero,
+
     #[msg("Swap amount cannot be zero.")]
     SwapAmountIsZero,
 
@@ -65,6 +191,9 @@ pub enum ErrorCode {
     #[msg("Failed to calculate liquidity tokens to mint due to invalid input or overflow.")]
     LpTokensCalculationFailed,
 
+    #[msg("Failed to calculate reserves discrepancy due to overflow.")]
+    ReservesDiscrepancyCalculationFailed,
+
     #[msg("Failed to calculate afterswap state due to invalid input or overflow.")]
     AfterswapCalculationFailed,
     
@@ -89,6 +218,9 @@ pub enum ErrorCode {
     
     #[msg("Protocol fees to redeem is zero")]
     ProvidersFeesIsZero,
+
+    #[msg("Creator fees to redeem is zero")]
+    CreatorFeesIsZero,
     
     // CpAmm integrity errors
     #[msg("Failed to calculate base-to-quote liquidity ratio due to invalid input or overflow.")]
@@ -102,6 +234,24 @@ pub enum ErrorCode {
 
     #[msg("Liquidity ratio tolerance exceeded.")]
     LiquidityRatioToleranceExceeded,
+
+    #[msg("Base vault balance is less than the base liquidity plus the base fees to redeem.")]
+    BaseVaultBalanceBelowReserves,
+
+    #[msg("Quote vault balance is less than the quote liquidity plus the quote fees to redeem.")]
+    QuoteVaultBalanceBelowReserves,
+
+    #[msg("LP mint supply doesn't match the recorded liquidity tokens supply.")]
+    LpTokensSupplyMismatch,
+
+    #[msg("Recorded constant product doesn't match the base and quote liquidity.")]
+    ConstantProductMismatch,
+
+    #[msg("Recorded base-to-quote liquidity ratio doesn't match the base and quote liquidity.")]
+    BaseQuoteRatioMismatch,
+
+    #[msg("Locked LP vault holds less than the initial locked liquidity.")]
+    LockedLiquidityMismatch,
     
     
     
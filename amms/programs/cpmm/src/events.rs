@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// Emitted on reserves reconciliation of a `CpAmm` with the difference between its vault balances
+/// and its recorded reserves plus the protocol and creator fees to redeem.
+#[event]
+pub struct CpAmmReservesReconciled {
+    /// The public key of the reconciled `CpAmm`.
+    pub cp_amm: Pubkey,
+
+    /// The base tokens held by the base vault above the recorded amount.
+    pub base_surplus: u64,
+
+    /// The quote tokens held by the quote vault above the recorded amount.
+    pub quote_surplus: u64,
+
+    /// The base tokens missing from the base vault below the recorded amount.
+    pub base_shortfall: u64,
+
+    /// The quote tokens missing from the quote vault below the recorded amount.
+    pub quote_shortfall: u64,
+
+    /// Whether the surplus was folded into the reserves rather than sent out of the vaults.
+    pub is_synced: bool,
+}
@@ -0,0 +1,215 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{AmmsConfigsManager, cp_amm::CpAmm};
+use crate::utils::token_accounts_instructions::ClosePdaTokenAccountInstruction;
+use crate::utils::token_instructions::{BurnTokensInstructions, TransferTokensInstruction};
+
+#[derive(Accounts)]
+pub struct CloseCpAmm<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Recipient of the ownerless remaining reserves and the vaults rent, chosen by the authority (e.g. the fee authority)
+    recipient: AccountInfo<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+
+    base_mint: Box<InterfaceAccount<'info, Mint>>,
+    quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    lp_mint: Box<Account<'info, token::Mint>>,
+
+    #[account(
+        mut,
+        constraint = recipient_base_account.owner == recipient.key()
+    )]
+    // Token program will check mint via token_instructions instruction
+    recipient_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = recipient_quote_account.owner == recipient.key()
+    )]
+    // Token program will check mint via token_instructions instruction
+    recipient_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::authority = cp_amm.creator(),
+        token::token_program = base_token_program
+    )]
+    // Receives the unredeemed creator fees in base tokens
+    creator_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::authority = cp_amm.creator(),
+        token::token_program = quote_token_program
+    )]
+    // Receives the unredeemed creator fees in quote tokens
+    creator_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = lp_mint.key() == cp_amm.lp_mint,
+        constraint = base_mint.key() == cp_amm.base_mint().key(),
+        constraint = quote_mint.key() == cp_amm.quote_mint().key(),
+        constraint = cp_amm_locked_lp_vault.key() == cp_amm.locked_lp_vault().key(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        constraint = cp_amm_quote_vault.key() == cp_amm.quote_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
+        bump = cp_amm.base_vault_bump()
+    )]
+    cp_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.quote_mint().as_ref()],
+        bump = cp_amm.quote_vault_bump()
+    )]
+    cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.locked_lp_vault_bump()
+    )]
+    cp_amm_locked_lp_vault: Box<Account<'info, token::TokenAccount>>,
+
+    lp_token_program: Program<'info, Token>,
+    base_token_program: Interface<'info, TokenInterface>,
+    quote_token_program: Interface<'info, TokenInterface>,
+}
+
+/// The `CpAmm` account stays on chain with the `Closed` status, so the pool can't be initialized again for its LP mint.
+/// Protocol fees must be collected beforehand, unredeemed creator fees are paid to the creator's token accounts
+/// and only the ownerless remaining reserves are swept to the recipient.
+pub(crate) fn handler(ctx: Context<CloseCpAmm>) -> Result<()> {
+    let close_payload = ctx.accounts.cp_amm.get_close_payload()?;
+
+    let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
+    let close_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+
+    let creator_base_fees = close_payload.creator_base_fees_to_redeem();
+    if creator_base_fees > 0 {
+        ctx.accounts.get_creator_base_fees_transfer_instruction(creator_base_fees)?.execute(Some(close_instruction_seeds))?;
+    }
+    let creator_quote_fees = close_payload.creator_quote_fees_to_redeem();
+    if creator_quote_fees > 0 {
+        ctx.accounts.get_creator_quote_fees_transfer_instruction(creator_quote_fees)?.execute(Some(close_instruction_seeds))?;
+    }
+
+    let base_vault_balance = ctx.accounts.cp_amm_base_vault.amount - creator_base_fees;
+    if base_vault_balance > 0 {
+        ctx.accounts.get_sweep_base_vault_transfer_instruction(base_vault_balance)?.execute(Some(close_instruction_seeds))?;
+    }
+    let quote_vault_balance = ctx.accounts.cp_amm_quote_vault.amount - creator_quote_fees;
+    if quote_vault_balance > 0 {
+        ctx.accounts.get_sweep_quote_vault_transfer_instruction(quote_vault_balance)?.execute(Some(close_instruction_seeds))?;
+    }
+    let locked_lp_vault_balance = ctx.accounts.cp_amm_locked_lp_vault.amount;
+    if locked_lp_vault_balance > 0 {
+        ctx.accounts.get_burn_locked_liquidity_instruction(locked_lp_vault_balance)?.execute(Some(close_instruction_seeds))?;
+    }
+
+    ctx.accounts.get_close_base_vault_instruction().execute(close_instruction_seeds)?;
+    ctx.accounts.get_close_quote_vault_instruction().execute(close_instruction_seeds)?;
+    ctx.accounts.get_close_locked_lp_vault_instruction().execute(close_instruction_seeds)?;
+
+    ctx.accounts.cp_amm.mark_closed();
+    Ok(())
+}
+
+impl<'info> CloseCpAmm<'info> {
+    fn get_creator_base_fees_transfer_instruction(&self, base_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            base_amount,
+            &self.base_mint,
+            &self.cp_amm_base_vault,
+            self.cp_amm.to_account_info(),
+            &self.creator_base_account,
+            &self.base_token_program
+        )
+    }
+    fn get_creator_quote_fees_transfer_instruction(&self, quote_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            quote_amount,
+            &self.quote_mint,
+            &self.cp_amm_quote_vault,
+            self.cp_amm.to_account_info(),
+            &self.creator_quote_account,
+            &self.quote_token_program
+        )
+    }
+    fn get_sweep_base_vault_transfer_instruction(&self, base_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            base_amount,
+            &self.base_mint,
+            &self.cp_amm_base_vault,
+            self.cp_amm.to_account_info(),
+            &self.recipient_base_account,
+            &self.base_token_program
+        )
+    }
+    fn get_sweep_quote_vault_transfer_instruction(&self, quote_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            quote_amount,
+            &self.quote_mint,
+            &self.cp_amm_quote_vault,
+            self.cp_amm.to_account_info(),
+            &self.recipient_quote_account,
+            &self.quote_token_program
+        )
+    }
+    fn get_burn_locked_liquidity_instruction(&self, locked_liquidity: u64) -> Result<BurnTokensInstructions<'_, '_, '_, 'info>> {
+        BurnTokensInstructions::try_new(
+            locked_liquidity,
+            &self.lp_mint,
+            &self.cp_amm_locked_lp_vault,
+            self.cp_amm.to_account_info(),
+            &self.lp_token_program
+        )
+    }
+    fn get_close_base_vault_instruction(&self) -> ClosePdaTokenAccountInstruction<'_, '_, '_, 'info> {
+        ClosePdaTokenAccountInstruction::new(
+            self.cp_amm_base_vault.to_account_info(),
+            self.recipient.to_account_info(),
+            self.cp_amm.to_account_info(),
+            self.base_token_program.to_account_info()
+        )
+    }
+    fn get_close_quote_vault_instruction(&self) -> ClosePdaTokenAccountInstruction<'_, '_, '_, 'info> {
+        ClosePdaTokenAccountInstruction::new(
+            self.cp_amm_quote_vault.to_account_info(),
+            self.recipient.to_account_info(),
+            self.cp_amm.to_account_info(),
+            self.quote_token_program.to_account_info()
+        )
+    }
+    fn get_close_locked_lp_vault_instruction(&self) -> ClosePdaTokenAccountInstruction<'_, '_, '_, 'info> {
+        ClosePdaTokenAccountInstruction::new(
+            self.cp_amm_locked_lp_vault.to_account_info(),
+            self.recipient.to_account_info(),
+            self.cp_amm.to_account_info(),
+            self.lp_token_program.to_account_info()
+        )
+    }
+}
@@ -37,6 +37,10 @@ pub(crate) fn handler(ctx: Context<InitializeAmmsConfig>, protocol_fee_rate_basi
         ctx.accounts.amms_configs_manager.configs_count(),
         ctx.bumps.amms_config
     )?;
+    ctx.accounts.amms_config.sync_halt(
+        ctx.accounts.amms_configs_manager.is_halted(),
+        ctx.accounts.amms_configs_manager.halt_epoch()
+    );
     ctx.accounts.amms_configs_manager.increment_configs_count();
     Ok(())
 }
\ No newline at end of file
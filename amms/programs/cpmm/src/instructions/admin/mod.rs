@@ -2,15 +2,41 @@
 pub mod initialize_amms_configs_manager;
 pub mod update_amms_configs_manager_authority;
 pub mod update_amms_configs_manager_head_authority;
+pub mod update_amms_configs_manager_pauser;
+pub mod update_amms_configs_manager_halt;
 pub mod initialize_amms_config;
 pub mod update_amms_config_fee_authority;
 pub mod update_amms_config_providers_fee_rate;
 pub mod update_amms_config_protocol_fee_rate;
+pub mod update_amms_config_max_fee_rate;
+pub mod update_amms_config_max_creator_fee_share;
+pub mod update_amms_config_referral_fee_share;
+pub mod update_amms_config_fee_recipients;
+pub mod update_amms_config_protocol_fee_mode;
+pub mod update_amms_config_donation_ratio_policy;
+pub mod sync_amms_config_halt;
+pub mod update_cp_amm_fee_rates_override;
+pub mod update_cp_amm_pause_flags;
+pub mod close_cp_amm;
+pub mod sync_cp_amm;
 
 pub use initialize_amms_configs_manager::*;
 pub use update_amms_configs_manager_authority::*;
 pub use update_amms_configs_manager_head_authority::*;
+pub use update_amms_configs_manager_pauser::*;
+pub use update_amms_configs_manager_halt::*;
 pub use initialize_amms_config::*;
 pub use update_amms_config_fee_authority::*;
 pub use update_amms_config_providers_fee_rate::*;
-pub use update_amms_config_protocol_fee_rate::*;
\ No newline at end of file
+pub use update_amms_config_protocol_fee_rate::*;
+pub use update_amms_config_max_fee_rate::*;
+pub use update_amms_config_max_creator_fee_share::*;
+pub use update_amms_config_referral_fee_share::*;
+pub use update_amms_config_fee_recipients::*;
+pub use update_amms_config_protocol_fee_mode::*;
+pub use update_amms_config_donation_ratio_policy::*;
+pub use sync_amms_config_halt::*;
+pub use update_cp_amm_fee_rates_override::*;
+pub use update_cp_amm_pause_flags::*;
+pub use close_cp_amm::*;
+pub use sync_cp_amm::*;
\ No newline at end of file
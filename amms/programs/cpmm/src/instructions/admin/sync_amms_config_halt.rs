@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::state::{AmmsConfig, AmmsConfigsManager};
+
+#[derive(Accounts)]
+pub struct SyncAmmsConfigHalt<'info> {
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        mut,
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    amms_config: Account<'info, AmmsConfig>,
+}
+pub(crate) fn handler(ctx: Context<SyncAmmsConfigHalt>) -> Result<()> {
+    ctx.accounts.amms_config.sync_halt(
+        ctx.accounts.amms_configs_manager.is_halted(),
+        ctx.accounts.amms_configs_manager.halt_epoch()
+    );
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::events::CpAmmReservesReconciled;
+use crate::state::{AmmsConfigsManager, cp_amm::CpAmm};
+
+#[derive(Accounts)]
+pub struct SyncCpAmm<'info> {
+    #[account(
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Box<Account<'info, AmmsConfigsManager>>,
+
+    #[account(
+        mut,
+        constraint = cp_amm.status().is_launched(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        constraint = cp_amm_quote_vault.key() == cp_amm.quote_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    cp_amm: Box<Account<'info, CpAmm>>,
+
+    cp_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// Folds the vaults surplus into the reserves of the `CpAmm`, a shortfall is only reported.
+pub(crate) fn handler(ctx: Context<SyncCpAmm>) -> Result<()> {
+    let reserves_discrepancy = ctx.accounts.cp_amm.get_reserves_discrepancy(ctx.accounts.cp_amm_base_vault.amount, ctx.accounts.cp_amm_quote_vault.amount)?;
+    let sync_payload = ctx.accounts.cp_amm.get_sync_payload(&reserves_discrepancy)?;
+    ctx.accounts.cp_amm.donate(sync_payload);
+
+    emit!(CpAmmReservesReconciled {
+        cp_amm: ctx.accounts.cp_amm.key(),
+        base_surplus: reserves_discrepancy.base_surplus(),
+        quote_surplus: reserves_discrepancy.quote_surplus(),
+        base_shortfall: reserves_discrepancy.base_shortfall(),
+        quote_shortfall: reserves_discrepancy.quote_shortfall(),
+        is_synced: true,
+    });
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::state::AmmsConfigsManager;
+
+#[derive(Accounts)]
+pub struct UpdateAmmsConfigsManagerPauser<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    /// CHECK: New pauser can be arbitrary
+    new_pauser: UncheckedAccount<'info>,
+}
+pub(crate) fn handler(ctx: Context<UpdateAmmsConfigsManagerPauser>) -> Result<()> {
+    ctx.accounts.amms_configs_manager.update_pauser(
+        ctx.accounts.new_pauser.key()
+    );
+    Ok(())
+}
\ No newline at end of file
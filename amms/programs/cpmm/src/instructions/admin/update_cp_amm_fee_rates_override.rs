@@ -0,0 +1,37 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use crate::state::{AmmsConfig, AmmsConfigsManager, cp_amm::CpAmm};
+
+#[derive(Accounts)]
+pub struct UpdateCpAmmFeeRatesOverride<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    amms_config: Account<'info, AmmsConfig>,
+    #[account(
+        mut,
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    cp_amm: Box<Account<'info, CpAmm>>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateCpAmmFeeRatesOverride>, providers_fee_rate_basis_points_override: Option<u16>, protocol_fee_rate_basis_points_override: Option<u16>) -> Result<()> {
+    ctx.accounts.cp_amm.update_fee_rates_override(
+        providers_fee_rate_basis_points_override,
+        protocol_fee_rate_basis_points_override,
+        &ctx.accounts.amms_config
+    )
+}
\ No newline at end of file
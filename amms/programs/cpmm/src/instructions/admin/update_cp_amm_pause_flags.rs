@@ -0,0 +1,81 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use crate::state::{AmmsConfigsManager, cp_amm::CpAmm};
+
+#[derive(Accounts)]
+pub struct UpdateCpAmmPauseFlags<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key() || authority.key() == amms_configs_manager.pauser().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        mut,
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    cp_amm: Box<Account<'info, CpAmm>>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateCpAmmPauseFlags>, is_swap_paused: bool, is_provide_paused: bool, is_withdraw_paused: bool) -> Result<()> {
+    ctx.accounts.cp_amm.update_pause_flags(is_swap_paused, is_provide_paused, is_withdraw_paused);
+    Ok(())
+}
+
+#[cfg(test)]
+mod update_cp_amm_pause_flags_tests {
+    use anchor_lang::{Discriminator, InstructionData};
+    use crate::constants::ANCHOR_DISCRIMINATOR;
+    use crate::error::ErrorCode;
+    use crate::state::{LegacyAmmsConfigsManager, cp_amm::LegacyCpAmm};
+    use super::*;
+
+    /// Tests that the instruction reads the baseline layouts of `AmmsConfigsManager` and `CpAmm`,
+    /// and writes the pool back in its legacy layout as long as its state fits it.
+    #[test]
+    fn test_update_cp_amm_pause_flags_with_legacy_accounts() {
+        let authority = Pubkey::new_unique();
+        let lp_mint = Pubkey::new_unique();
+        let (manager_key, manager_bump) = Pubkey::find_program_address(&[AmmsConfigsManager::SEED], &crate::ID);
+        let (cp_amm_key, cp_amm_bump) = Pubkey::find_program_address(&[CpAmm::SEED, lp_mint.as_ref()], &crate::ID);
+
+        let mut manager_data = vec![0u8; ANCHOR_DISCRIMINATOR + LegacyAmmsConfigsManager::INIT_SPACE];
+        manager_data[..ANCHOR_DISCRIMINATOR].copy_from_slice(&AmmsConfigsManager::DISCRIMINATOR);
+        // authority
+        manager_data[8..40].copy_from_slice(authority.as_ref());
+        // bump
+        manager_data[80] = manager_bump;
+
+        let mut cp_amm_data = vec![0u8; ANCHOR_DISCRIMINATOR + LegacyCpAmm::INIT_SPACE];
+        cp_amm_data[..ANCHOR_DISCRIMINATOR].copy_from_slice(&CpAmm::DISCRIMINATOR);
+        // is_initialized, is_launched and bump
+        cp_amm_data[8] = 1;
+        cp_amm_data[9] = 1;
+        cp_amm_data[10] = cp_amm_bump;
+        // lp_mint
+        cp_amm_data[174..206].copy_from_slice(lp_mint.as_ref());
+        let legacy_cp_amm_data = cp_amm_data.clone();
+
+        let (mut authority_lamports, mut manager_lamports, mut cp_amm_lamports) = (1_000_000_000u64, 1_000_000_000u64, 1_000_000_000u64);
+        let mut authority_data = vec![];
+        let system_program = anchor_lang::system_program::ID;
+        let accounts = [
+            AccountInfo::new(&authority, true, true, &mut authority_lamports, &mut authority_data, &system_program, false, 0),
+            AccountInfo::new(&manager_key, false, false, &mut manager_lamports, &mut manager_data, &crate::ID, false, 0),
+            AccountInfo::new(&cp_amm_key, false, true, &mut cp_amm_lamports, &mut cp_amm_data, &crate::ID, false, 0),
+        ];
+
+        let unpause = crate::instruction::UpdateCpAmmPauseFlags { is_swap_paused: false, is_provide_paused: false, is_withdraw_paused: false };
+        crate::entry(&crate::ID, &accounts, &unpause.data()).unwrap();
+        assert_eq!(*accounts[2].try_borrow_data().unwrap(), legacy_cp_amm_data.as_slice());
+
+        let pause = crate::instruction::UpdateCpAmmPauseFlags { is_swap_paused: true, is_provide_paused: false, is_withdraw_paused: false };
+        assert_eq!(crate::entry(&crate::ID, &accounts, &pause.data()).unwrap_err(), ProgramError::Custom(ErrorCode::AccountNotMigrated.into()));
+        assert_eq!(*accounts[2].try_borrow_data().unwrap(), legacy_cp_amm_data.as_slice());
+    }
+}
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::cp_amm::{CpAmm, PoolStatus};
+use crate::utils::token_accounts_instructions::ClosePdaTokenAccountInstruction;
+use crate::utils::token_instructions::TransferTokensInstruction;
+
+#[derive(Accounts)]
+pub struct CancelCpAmm<'info> {
+    // The creator at any time before the launch, anyone after the launch deadline
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Receives the rent of the closed accounts, checked to be the CpAmm creator
+    pub creator: AccountInfo<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub lp_mint: Box<Account<'info, token::Mint>>,
+
+    #[account(
+        mut,
+        constraint = creator_base_account.owner == creator.key()
+    )]
+    // Receives tokens sent to the vault before the launch, token program will check mint via token_instructions instruction
+    pub creator_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = creator_quote_account.owner == creator.key()
+    )]
+    // Receives tokens sent to the vault before the launch, token program will check mint via token_instructions instruction
+    pub creator_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = creator,
+        constraint = cp_amm.status() == PoolStatus::Initialized,
+        constraint = creator.key() == cp_amm.creator().key(),
+        constraint = lp_mint.key() == cp_amm.lp_mint,
+        constraint = base_mint.key() == cp_amm.base_mint().key(),
+        constraint = quote_mint.key() == cp_amm.quote_mint().key(),
+        constraint = cp_amm_locked_lp_vault.key() == cp_amm.locked_lp_vault().key(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        constraint = cp_amm_quote_vault.key() == cp_amm.quote_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
+        bump = cp_amm.base_vault_bump()
+    )]
+    pub cp_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.quote_mint().as_ref()],
+        bump = cp_amm.quote_vault_bump()
+    )]
+    pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.locked_lp_vault_bump()
+    )]
+    pub cp_amm_locked_lp_vault: Box<Account<'info, token::TokenAccount>>,
+
+    pub lp_token_program: Program<'info, Token>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+/// The LP mint stays on chain, since the SPL Token program can't close mints.
+pub(crate) fn handler(ctx: Context<CancelCpAmm>) -> Result<()> {
+    ctx.accounts.cp_amm.check_cancel(&ctx.accounts.signer.key(), Clock::get()?.unix_timestamp)?;
+
+    let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
+    let cancel_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+
+    let base_vault_balance = ctx.accounts.cp_amm_base_vault.amount;
+    if base_vault_balance > 0 {
+        ctx.accounts.get_sweep_base_vault_transfer_instruction(base_vault_balance)?.execute(Some(cancel_instruction_seeds))?;
+    }
+    let quote_vault_balance = ctx.accounts.cp_amm_quote_vault.amount;
+    if quote_vault_balance > 0 {
+        ctx.accounts.get_sweep_quote_vault_transfer_instruction(quote_vault_balance)?.execute(Some(cancel_instruction_seeds))?;
+    }
+
+    ctx.accounts.get_close_base_vault_instruction().execute(cancel_instruction_seeds)?;
+    ctx.accounts.get_close_quote_vault_instruction().execute(cancel_instruction_seeds)?;
+    ctx.accounts.get_close_locked_lp_vault_instruction().execute(cancel_instruction_seeds)?;
+
+    Ok(())
+}
+
+impl<'info> CancelCpAmm<'info> {
+    fn get_sweep_base_vault_transfer_instruction(&self, base_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            base_amount,
+            &self.base_mint,
+            &self.cp_amm_base_vault,
+            self.cp_amm.to_account_info(),
+            &self.creator_base_account,
+            &self.base_token_program
+        )
+    }
+    fn get_sweep_quote_vault_transfer_instruction(&self, quote_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            quote_amount,
+            &self.quote_mint,
+            &self.cp_amm_quote_vault,
+            self.cp_amm.to_account_info(),
+            &self.creator_quote_account,
+            &self.quote_token_program
+        )
+    }
+    fn get_close_base_vault_instruction(&self) -> ClosePdaTokenAccountInstruction<'_, '_, '_, 'info> {
+        ClosePdaTokenAccountInstruction::new(
+            self.cp_amm_base_vault.to_account_info(),
+            self.creator.to_account_info(),
+            self.cp_amm.to_account_info(),
+            self.base_token_program.to_account_info()
+        )
+    }
+    fn get_close_quote_vault_instruction(&self) -> ClosePdaTokenAccountInstruction<'_, '_, '_, 'info> {
+        ClosePdaTokenAccountInstruction::new(
+            self.cp_amm_quote_vault.to_account_info(),
+            self.creator.to_account_info(),
+            self.cp_amm.to_account_info(),
+            self.quote_token_program.to_account_info()
+        )
+    }
+    fn get_close_locked_lp_vault_instruction(&self) -> ClosePdaTokenAccountInstruction<'_, '_, '_, 'info> {
+        ClosePdaTokenAccountInstruction::new(
+            self.cp_amm_locked_lp_vault.to_account_info(),
+            self.creator.to_account_info(),
+            self.cp_amm.to_account_info(),
+            self.lp_token_program.to_account_info()
+        )
+    }
+}
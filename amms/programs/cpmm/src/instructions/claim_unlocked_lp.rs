@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::LpLock;
+use crate::utils::token_instructions::TransferTokensInstruction;
+
+#[derive(Accounts)]
+pub struct ClaimUnlockedLp<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = lp_mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = lp_token_program
+    )]
+    pub beneficiary_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = beneficiary.key() == lp_lock.beneficiary().key(),
+        constraint = lp_mint.key() == lp_lock.lp_mint().key(),
+        seeds = [LpLock::SEED, lp_lock.lp_mint().as_ref(), lp_lock.owner().as_ref(), lp_lock.nonce().to_le_bytes().as_ref()],
+        bump = lp_lock.bump()
+    )]
+    pub lp_lock: Box<Account<'info, LpLock>>,
+
+    #[account(
+        mut,
+        seeds = [LpLock::VAULT_SEED, lp_lock.lp_mint().as_ref(), lp_lock.owner().as_ref(), lp_lock.nonce().to_le_bytes().as_ref()],
+        bump = lp_lock.vault_bump()
+    )]
+    pub lp_lock_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub lp_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<ClaimUnlockedLp>) -> Result<()> {
+    let claimable_amount = ctx.accounts.lp_lock.get_claimable_amount(Clock::get()?.unix_timestamp)?;
+
+    let lp_lock_nonce = ctx.accounts.lp_lock.nonce().to_le_bytes();
+    let lp_lock_bump = [ctx.accounts.lp_lock.bump()];
+    let lp_lock_seeds: [&[u8]; 5] = [
+        LpLock::SEED,
+        ctx.accounts.lp_lock.lp_mint().as_ref(),
+        ctx.accounts.lp_lock.owner().as_ref(),
+        lp_lock_nonce.as_ref(),
+        lp_lock_bump.as_ref()
+    ];
+    let claim_instruction_seeds: &[&[&[u8]]] = &[&lp_lock_seeds];
+
+    ctx.accounts.get_claim_transfer_instruction(claimable_amount)?.execute(Some(claim_instruction_seeds))?;
+
+    ctx.accounts.lp_lock.claim(claimable_amount);
+    Ok(())
+}
+
+impl<'info> ClaimUnlockedLp<'info> {
+    fn get_claim_transfer_instruction(&self, amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            amount,
+            &self.lp_mint,
+            &self.lp_lock_vault,
+            self.lp_lock.to_account_info(),
+            &self.beneficiary_lp_account,
+            &self.lp_token_program
+        )
+    }
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
-use crate::state::AmmsConfig;
+use crate::error::ErrorCode;
+use crate::state::{AmmsConfig, FeeRecipient};
 use crate::state::cp_amm::CpAmm;
 use crate::utils::token_instructions::TransferTokensInstruction;
 
@@ -32,6 +33,22 @@ pub struct CollectFeesFromCpAmm<'info> {
         associated_token::token_program = quote_token_program
     )]
     pub fee_authority_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::token_program = base_token_program
+    )]
+    // Receives the base fees instead of the fee authority's account, requires the fee authority signature
+    pub recipient_base_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::token_program = quote_token_program
+    )]
+    // Receives the quote fees instead of the fee authority's account, requires the fee authority signature
+    pub recipient_quote_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
     
     #[account(
         constraint = amms_config.fee_authority().key() == fee_authority.key(),
@@ -42,7 +59,7 @@ pub struct CollectFeesFromCpAmm<'info> {
 
     #[account(
         mut,
-        constraint = cp_amm.is_launched(),
+        constraint = cp_amm.status().is_launched(),
         constraint = amms_config.key() == cp_amm.amms_config().key(),
         constraint = base_mint.key() == cp_amm.base_mint().key(),
         constraint = quote_mint.key() == cp_amm.quote_mint().key(),
@@ -73,24 +90,57 @@ pub struct CollectFeesFromCpAmm<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub(crate) fn handler(ctx: Context<CollectFeesFromCpAmm>) -> Result<()> {
+/// Fee recipients' token accounts are passed in `remaining_accounts` as a base and quote account pair
+/// for each of the `AmmsConfig` fee recipients in the same order.
+/// Without fee recipients, the fee authority can redirect the fees to any recipient accounts by signing.
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CollectFeesFromCpAmm<'info>>) -> Result<()> {
+    require!(
+        ctx.accounts.fee_authority.is_signer || (ctx.accounts.recipient_base_account.is_none() && ctx.accounts.recipient_quote_account.is_none()),
+        ErrorCode::FeeAuthorityNotSigner
+    );
     let collect_fees_payload = ctx.accounts.cp_amm.get_collect_fees_payload()?;
     let (protocol_base_fees_to_redeem, protocol_quote_fees_to_redeem) = (collect_fees_payload.protocol_base_fees_to_redeem(), collect_fees_payload.protocol_quote_fees_to_redeem());
 
     let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
     let collect_fees_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
 
-    if protocol_base_fees_to_redeem > 0{
-        ctx.accounts.get_collect_base_fees_transfer_instruction(protocol_base_fees_to_redeem)?.execute(Some(collect_fees_instruction_seeds))?;
+    let fee_recipients = ctx.accounts.amms_config.fee_recipients();
+    if fee_recipients.is_empty() {
+        if protocol_base_fees_to_redeem > 0{
+            ctx.accounts.get_collect_base_fees_transfer_instruction(protocol_base_fees_to_redeem)?.execute(Some(collect_fees_instruction_seeds))?;
+        }
+        if protocol_quote_fees_to_redeem > 0{
+            ctx.accounts.get_collect_quote_fees_transfer_instruction(protocol_quote_fees_to_redeem)?.execute(Some(collect_fees_instruction_seeds))?;
+        }
     }
-    if protocol_quote_fees_to_redeem > 0{
-        ctx.accounts.get_collect_quote_fees_transfer_instruction(protocol_quote_fees_to_redeem)?.execute(Some(collect_fees_instruction_seeds))?;
+    else {
+        require!(ctx.remaining_accounts.len() == fee_recipients.len() * 2, ErrorCode::InvalidFeeRecipientAccount);
+        let base_amounts = ctx.accounts.amms_config.calculate_fee_recipients_amounts(protocol_base_fees_to_redeem);
+        let quote_amounts = ctx.accounts.amms_config.calculate_fee_recipients_amounts(protocol_quote_fees_to_redeem);
+
+        for (index, fee_recipient) in fee_recipients.iter().enumerate() {
+            if base_amounts[index] > 0 {
+                let recipient_base_account = get_fee_recipient_account(&ctx.remaining_accounts[index * 2], fee_recipient, &ctx.accounts.base_mint)?;
+                ctx.accounts.get_distribute_base_fees_transfer_instruction(base_amounts[index], &recipient_base_account)?.execute(Some(collect_fees_instruction_seeds))?;
+            }
+            if quote_amounts[index] > 0 {
+                let recipient_quote_account = get_fee_recipient_account(&ctx.remaining_accounts[index * 2 + 1], fee_recipient, &ctx.accounts.quote_mint)?;
+                ctx.accounts.get_distribute_quote_fees_transfer_instruction(quote_amounts[index], &recipient_quote_account)?.execute(Some(collect_fees_instruction_seeds))?;
+            }
+        }
     }
 
     ctx.accounts.cp_amm.collect_fees(collect_fees_payload);
     Ok(())
 }
 
+fn get_fee_recipient_account<'info>(account_info: &'info AccountInfo<'info>, fee_recipient: &FeeRecipient, mint: &InterfaceAccount<'info, Mint>) -> Result<InterfaceAccount<'info, TokenAccount>> {
+    let token_account = InterfaceAccount::<TokenAccount>::try_from(account_info)?;
+    require!(token_account.owner == fee_recipient.recipient, ErrorCode::InvalidFeeRecipientAccount);
+    require!(token_account.mint == mint.key(), ErrorCode::InvalidFeeRecipientAccount);
+    Ok(token_account)
+}
+
 impl<'info> CollectFeesFromCpAmm<'info> {
     fn get_collect_base_fees_transfer_instruction(&self, base_fees: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
         TransferTokensInstruction::try_new(
@@ -98,7 +148,7 @@ impl<'info> CollectFeesFromCpAmm<'info> {
             &self.base_mint,
             &self.cp_amm_base_vault,
             self.cp_amm.to_account_info(),
-            &self.fee_authority_base_account,
+            self.recipient_base_account.as_ref().unwrap_or(&self.fee_authority_base_account),
             &self.base_token_program
         )
     }
@@ -108,7 +158,27 @@ impl<'info> CollectFeesFromCpAmm<'info> {
             &self.quote_mint,
             &self.cp_amm_quote_vault,
             self.cp_amm.to_account_info(),
-            &self.fee_authority_quote_account,
+            self.recipient_quote_account.as_ref().unwrap_or(&self.fee_authority_quote_account),
+            &self.quote_token_program
+        )
+    }
+    fn get_distribute_base_fees_transfer_instruction(&self, base_fees: u64, recipient_base_account: &InterfaceAccount<'info, TokenAccount>) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            base_fees,
+            &self.base_mint,
+            &self.cp_amm_base_vault,
+            self.cp_amm.to_account_info(),
+            recipient_base_account,
+            &self.base_token_program
+        )
+    }
+    fn get_distribute_quote_fees_transfer_instruction(&self, quote_fees: u64, recipient_quote_account: &InterfaceAccount<'info, TokenAccount>) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            quote_fees,
+            &self.quote_mint,
+            &self.cp_amm_quote_vault,
+            self.cp_amm.to_account_info(),
+            recipient_quote_account,
             &self.quote_token_program
         )
     }
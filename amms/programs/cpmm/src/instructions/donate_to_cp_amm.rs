@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::error::ErrorCode;
+use crate::state::{AmmsConfig, AmmsConfigsManager, cp_amm::CpAmm};
+use crate::utils::token_instructions::TransferTokensInstruction;
+
+#[derive(Accounts)]
+pub struct DonateToCpAmm<'info>{
+    pub signer: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub signer_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub signer_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    pub amms_configs_manager: Box<Account<'info, AmmsConfigsManager>>,
+
+    #[account(
+        mut,
+        constraint = cp_amm.status().is_launched(),
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        constraint = base_mint.key() == cp_amm.base_mint().key(),
+        constraint = quote_mint.key() == cp_amm.quote_mint().key(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        constraint = cp_amm_quote_vault.key() == cp_amm.quote_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
+        bump = cp_amm.base_vault_bump()
+    )]
+    pub cp_amm_base_vault:Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.quote_mint().as_ref()],
+        bump = cp_amm.quote_vault_bump()
+    )]
+    pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Donated liquidity raises the value of every LP share, no LP tokens are minted.
+/// Whether a donation may change the pool price is decided by the donation ratio policy of the `AmmsConfig`.
+pub(crate) fn handler(ctx: Context<DonateToCpAmm>, base_liquidity: u64, quote_liquidity: u64) -> Result<()> {
+    require!(!ctx.accounts.amms_configs_manager.is_halted(), ErrorCode::AmmsConfigHalted);
+    let base_transfer_instruction = Box::new(ctx.accounts.get_base_liquidity_transfer_instruction(base_liquidity)?);
+    let quote_transfer_instruction = Box::new(ctx.accounts.get_quote_liquidity_transfer_instruction(quote_liquidity)?);
+
+    let donate_payload = ctx.accounts.cp_amm.get_donate_payload(
+        (*base_transfer_instruction).as_ref().map_or(0, |instruction| instruction.get_amount_after_fee()),
+        (*quote_transfer_instruction).as_ref().map_or(0, |instruction| instruction.get_amount_after_fee()),
+        ctx.accounts.amms_config.donation_ratio_policy()
+    )?;
+
+    if let Some(base_transfer_instruction) = *base_transfer_instruction {
+        base_transfer_instruction.execute(None)?;
+    }
+    if let Some(quote_transfer_instruction) = *quote_transfer_instruction {
+        quote_transfer_instruction.execute(None)?;
+    }
+
+    ctx.accounts.cp_amm.donate(donate_payload);
+    Ok(())
+}
+
+impl<'info> DonateToCpAmm<'info>{
+    fn get_base_liquidity_transfer_instruction(&self, base_liquidity: u64) -> Result<Option<TransferTokensInstruction<'_, '_, '_, 'info>>> {
+        if base_liquidity == 0 {
+            return Ok(None);
+        }
+        TransferTokensInstruction::try_new(
+            base_liquidity,
+            &self.base_mint,
+            &self.signer_base_account,
+            self.signer.to_account_info(),
+            &self.cp_amm_base_vault,
+            &self.base_token_program
+        ).map(Some)
+    }
+    fn get_quote_liquidity_transfer_instruction(&self, quote_liquidity: u64) -> Result<Option<TransferTokensInstruction<'_, '_, '_, 'info>>> {
+        if quote_liquidity == 0 {
+            return Ok(None);
+        }
+        TransferTokensInstruction::try_new(
+            quote_liquidity,
+            &self.quote_mint,
+            &self.signer_quote_account,
+            self.signer.to_account_info(),
+            &self.cp_amm_quote_vault,
+            &self.quote_token_program
+        ).map(Some)
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Farm, FarmRewardSchedule};
+
+#[derive(Accounts)]
+pub struct AddFarmReward<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == farm.authority().key()
+    )]
+    pub authority: Signer<'info>,
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [Farm::SEED, farm.cp_amm().as_ref()],
+        bump = farm.bump()
+    )]
+    pub farm: Box<Account<'info, Farm>>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = reward_mint,
+        token::authority = farm,
+        token::token_program = reward_token_program,
+        seeds = [Farm::REWARD_VAULT_SEED, farm.key().as_ref(), reward_mint.key().as_ref()],
+        bump
+    )]
+    pub farm_reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<AddFarmReward>, emission_rate_per_second: u64, start_timestamp: i64, end_timestamp: i64) -> Result<()> {
+    ctx.accounts.farm.add_reward(
+        ctx.accounts.reward_mint.key(),
+        ctx.accounts.farm_reward_vault.key(),
+        ctx.bumps.farm_reward_vault,
+        FarmRewardSchedule { emission_rate_per_second, start_timestamp, end_timestamp },
+        Clock::get()?.unix_timestamp
+    )
+}
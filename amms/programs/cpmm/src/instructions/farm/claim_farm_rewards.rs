@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::error::ErrorCode;
+use crate::state::{Farm, FarmStake};
+use crate::utils::token_instructions::TransferTokensInstruction;
+
+#[derive(Accounts)]
+pub struct ClaimFarmRewards<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = reward_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = reward_token_program
+    )]
+    pub owner_reward_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [Farm::SEED, farm.cp_amm().as_ref()],
+        bump = farm.bump()
+    )]
+    pub farm: Box<Account<'info, Farm>>,
+
+    #[account(
+        mut,
+        seeds = [FarmStake::SEED, farm.key().as_ref(), owner.key().as_ref()],
+        bump = farm_stake.bump()
+    )]
+    pub farm_stake: Box<Account<'info, FarmStake>>,
+
+    #[account(mut)]
+    pub farm_reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Claims the rewards of a single farm reward, selected by `reward_index`.
+pub(crate) fn handler(mut ctx: Context<ClaimFarmRewards>, reward_index: u8) -> Result<()> {
+    let reward = ctx.accounts.farm.reward(reward_index as usize)?;
+    require!(
+        ctx.accounts.reward_mint.key() == reward.mint && ctx.accounts.farm_reward_vault.key() == reward.vault,
+        ErrorCode::InvalidFarmRewardAccounts
+    );
+
+    let accounts = &mut ctx.accounts;
+    let amount = accounts.farm.claim(&mut accounts.farm_stake, reward_index as usize, Clock::get()?.unix_timestamp)?;
+
+    let farm_seeds = ctx.accounts.farm.seeds();
+    let claim_instruction_seeds: &[&[&[u8]]] = &[&farm_seeds];
+    ctx.accounts.get_claim_transfer_instruction(amount)?.execute(Some(claim_instruction_seeds))
+}
+
+impl<'info> ClaimFarmRewards<'info> {
+    fn get_claim_transfer_instruction(&self, amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            amount,
+            &self.reward_mint,
+            &self.farm_reward_vault,
+            self.farm.to_account_info(),
+            &self.owner_reward_account,
+            &self.reward_token_program
+        )
+    }
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use crate::state::Farm;
+
+#[derive(Accounts)]
+pub struct ExtendFarmReward<'info> {
+    #[account(
+        constraint = authority.key() == farm.authority().key()
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Farm::SEED, farm.cp_amm().as_ref()],
+        bump = farm.bump()
+    )]
+    pub farm: Box<Account<'info, Farm>>,
+}
+
+pub(crate) fn handler(ctx: Context<ExtendFarmReward>, reward_index: u8, end_timestamp: i64, emission_rate_per_second: u64) -> Result<()> {
+    ctx.accounts.farm.extend_reward(reward_index as usize, end_timestamp, emission_rate_per_second, Clock::get()?.unix_timestamp)
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::error::ErrorCode;
+use crate::state::Farm;
+use crate::utils::token_instructions::TransferTokensInstruction;
+
+#[derive(Accounts)]
+pub struct FundFarmReward<'info> {
+    pub funder: Signer<'info>,
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub funder_reward_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [Farm::SEED, farm.cp_amm().as_ref()],
+        bump = farm.bump()
+    )]
+    pub farm: Box<Account<'info, Farm>>,
+
+    #[account(mut)]
+    pub farm_reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub reward_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Anyone can fund a reward, the amount received by the vault after transfer fees is added to the reward budget.
+pub(crate) fn handler(ctx: Context<FundFarmReward>, reward_index: u8, amount: u64) -> Result<()> {
+    let reward = ctx.accounts.farm.reward(reward_index as usize)?;
+    require!(
+        ctx.accounts.reward_mint.key() == reward.mint && ctx.accounts.farm_reward_vault.key() == reward.vault,
+        ErrorCode::InvalidFarmRewardAccounts
+    );
+
+    let fund_instruction = ctx.accounts.get_fund_transfer_instruction(amount)?;
+    let funded_amount = fund_instruction.get_amount_after_fee();
+    fund_instruction.execute(None)?;
+
+    ctx.accounts.farm.fund_reward(reward_index as usize, funded_amount)
+}
+
+impl<'info> FundFarmReward<'info> {
+    fn get_fund_transfer_instruction(&self, amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            amount,
+            &self.reward_mint,
+            &self.funder_reward_account,
+            self.funder.to_account_info(),
+            &self.farm_reward_vault,
+            &self.reward_token_program
+        )
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{AmmsConfigsManager, Farm, cp_amm::CpAmm};
+
+#[derive(Accounts)]
+pub struct InitializeFarm<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == cp_amm.creator().key() || authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    pub amms_configs_manager: Box<Account<'info, AmmsConfigsManager>>,
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = cp_amm.status().is_launched(),
+        constraint = lp_mint.key() == cp_amm.lp_mint,
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Farm::INIT_SPACE,
+        seeds = [Farm::SEED, cp_amm.key().as_ref()],
+        bump
+    )]
+    pub farm: Box<Account<'info, Farm>>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = lp_mint,
+        token::authority = farm,
+        token::token_program = lp_token_program,
+        seeds = [Farm::STAKE_VAULT_SEED, farm.key().as_ref()],
+        bump
+    )]
+    pub farm_stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub lp_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The signer becomes the farm authority, which adds and extends its rewards.
+pub(crate) fn handler(ctx: Context<InitializeFarm>) -> Result<()> {
+    ctx.accounts.farm.initialize(
+        ctx.accounts.cp_amm.key(),
+        ctx.accounts.lp_mint.key(),
+        ctx.accounts.authority.key(),
+        Clock::get()?.unix_timestamp,
+        ctx.bumps.farm,
+        ctx.bumps.farm_stake_vault
+    );
+    Ok(())
+}
@@ -0,0 +1,18 @@
+#![allow(ambiguous_glob_reexports)]
+pub mod initialize_farm;
+pub mod add_farm_reward;
+pub mod fund_farm_reward;
+pub mod extend_farm_reward;
+pub mod reclaim_farm_reward;
+pub mod stake_lp;
+pub mod unstake_lp;
+pub mod claim_farm_rewards;
+
+pub use initialize_farm::*;
+pub use add_farm_reward::*;
+pub use fund_farm_reward::*;
+pub use extend_farm_reward::*;
+pub use reclaim_farm_reward::*;
+pub use stake_lp::*;
+pub use unstake_lp::*;
+pub use claim_farm_rewards::*;
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::error::ErrorCode;
+use crate::state::Farm;
+use crate::utils::token_instructions::TransferTokensInstruction;
+
+#[derive(Accounts)]
+pub struct ReclaimFarmReward<'info> {
+    #[account(
+        constraint = authority.key() == farm.authority().key()
+    )]
+    pub authority: Signer<'info>,
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    // Token program will check mint via token_instructions instruction
+    pub authority_reward_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [Farm::SEED, farm.cp_amm().as_ref()],
+        bump = farm.bump()
+    )]
+    pub farm: Box<Account<'info, Farm>>,
+
+    #[account(mut)]
+    pub farm_reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub reward_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Sends the funded tokens of an ended reward that were never emitted to stakers back to the farm authority.
+pub(crate) fn handler(ctx: Context<ReclaimFarmReward>, reward_index: u8) -> Result<()> {
+    let reward = ctx.accounts.farm.reward(reward_index as usize)?;
+    require!(
+        ctx.accounts.reward_mint.key() == reward.mint && ctx.accounts.farm_reward_vault.key() == reward.vault,
+        ErrorCode::InvalidFarmRewardAccounts
+    );
+
+    let amount = ctx.accounts.farm.reclaim_unemitted_reward(reward_index as usize, Clock::get()?.unix_timestamp)?;
+
+    let farm_seeds = ctx.accounts.farm.seeds();
+    let reclaim_instruction_seeds: &[&[&[u8]]] = &[&farm_seeds];
+    ctx.accounts.get_reclaim_transfer_instruction(amount)?.execute(Some(reclaim_instruction_seeds))
+}
+
+impl<'info> ReclaimFarmReward<'info> {
+    fn get_reclaim_transfer_instruction(&self, amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            amount,
+            &self.reward_mint,
+            &self.farm_reward_vault,
+            self.farm.to_account_info(),
+            &self.authority_reward_account,
+            &self.reward_token_program
+        )
+    }
+}
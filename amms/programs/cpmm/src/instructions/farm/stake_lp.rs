@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Farm, FarmStake};
+use crate::utils::token_instructions::TransferTokensInstruction;
+
+#[derive(Accounts)]
+pub struct StakeLp<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub owner_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = lp_mint.key() == farm.lp_mint().key(),
+        seeds = [Farm::SEED, farm.cp_amm().as_ref()],
+        bump = farm.bump()
+    )]
+    pub farm: Box<Account<'info, Farm>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + FarmStake::INIT_SPACE,
+        seeds = [FarmStake::SEED, farm.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub farm_stake: Box<Account<'info, FarmStake>>,
+
+    #[account(
+        mut,
+        seeds = [Farm::STAKE_VAULT_SEED, farm.key().as_ref()],
+        bump = farm.stake_vault_bump()
+    )]
+    pub farm_stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub lp_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(mut ctx: Context<StakeLp>, amount: u64) -> Result<()> {
+    let (farm_key, owner_key) = (ctx.accounts.farm.key(), ctx.accounts.owner.key());
+    ctx.accounts.farm_stake.initialize_if_needed(farm_key, owner_key, ctx.bumps.farm_stake);
+
+    ctx.accounts.get_stake_transfer_instruction(amount)?.execute(None)?;
+
+    let accounts = &mut ctx.accounts;
+    accounts.farm.stake(&mut accounts.farm_stake, amount, Clock::get()?.unix_timestamp)
+}
+
+impl<'info> StakeLp<'info> {
+    fn get_stake_transfer_instruction(&self, amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            amount,
+            &self.lp_mint,
+            &self.owner_lp_account,
+            self.owner.to_account_info(),
+            &self.farm_stake_vault,
+            &self.lp_token_program
+        )
+    }
+}
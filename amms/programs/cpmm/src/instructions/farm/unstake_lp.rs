@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Farm, FarmStake};
+use crate::utils::token_instructions::TransferTokensInstruction;
+
+#[derive(Accounts)]
+pub struct UnstakeLp<'info> {
+    pub owner: Signer<'info>,
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    // Token program will check mint via token_instructions instruction
+    pub owner_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = lp_mint.key() == farm.lp_mint().key(),
+        seeds = [Farm::SEED, farm.cp_amm().as_ref()],
+        bump = farm.bump()
+    )]
+    pub farm: Box<Account<'info, Farm>>,
+
+    #[account(
+        mut,
+        seeds = [FarmStake::SEED, farm.key().as_ref(), owner.key().as_ref()],
+        bump = farm_stake.bump()
+    )]
+    pub farm_stake: Box<Account<'info, FarmStake>>,
+
+    #[account(
+        mut,
+        seeds = [Farm::STAKE_VAULT_SEED, farm.key().as_ref()],
+        bump = farm.stake_vault_bump()
+    )]
+    pub farm_stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub lp_token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler(mut ctx: Context<UnstakeLp>, amount: u64) -> Result<()> {
+    let accounts = &mut ctx.accounts;
+    accounts.farm.unstake(&mut accounts.farm_stake, amount, Clock::get()?.unix_timestamp)?;
+
+    let farm_seeds = ctx.accounts.farm.seeds();
+    let unstake_instruction_seeds: &[&[&[u8]]] = &[&farm_seeds];
+    ctx.accounts.get_unstake_transfer_instruction(amount)?.execute(Some(unstake_instruction_seeds))
+}
+
+impl<'info> UnstakeLp<'info> {
+    fn get_unstake_transfer_instruction(&self, amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            amount,
+            &self.lp_mint,
+            &self.farm_stake_vault,
+            self.farm.to_account_info(),
+            &self.owner_lp_account,
+            &self.lp_token_program
+        )
+    }
+}
@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token::{Mint, Token, TokenAccount}, token_interface};
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::{AmmsConfig, AmmsConfigsManager, CreatorLpLockConfig, LpLock, cp_amm::{
+    CpAmm,
+    CpAmmBumps,
+    CpAmmCalculate,
+    LaunchFeeSchedule
+}};
+use super::initialize_cp_amm::InitializeAccounts;
+use super::launch_cp_amm::LaunchAccounts;
+
+#[derive(Accounts)]
+pub struct InitializeAndLaunchCpAmm<'info> {
+    #[account(mut)]
+    // Pays the initialization price and the rent of the created accounts
+    pub payer: Signer<'info>,
+    // The creator of CpAmm, can be a PDA signing via CPI
+    pub creator: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Amms config's fee authority can be arbitrary type
+    pub fee_authority: AccountInfo<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        constraint = base_mint.key() != quote_mint.key()
+    )]
+    pub quote_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = CpAmm::LP_MINT_INITIAL_DECIMALS,
+        mint::authority = cp_amm,
+        mint::token_program = lp_token_program
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub creator_base_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub creator_quote_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    /// CHECK: Owner of the launch LP tokens, can be any account or PDA, the creator itself by default
+    pub lp_beneficiary: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = lp_mint,
+        associated_token::authority = lp_beneficiary,
+        associated_token::token_program = lp_token_program,
+    )]
+    pub beneficiary_lp_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        constraint = amms_config.fee_authority().key() == fee_authority.key(),
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    pub amms_configs_manager: Box<Account<'info, AmmsConfigsManager>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CpAmm::INIT_SPACE,
+        seeds = [CpAmm::SEED, lp_mint.key().as_ref()],
+        bump
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), base_mint.key().as_ref()],
+        bump
+    )]
+    pub cp_amm_base_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), quote_mint.key().as_ref()],
+        bump
+    )]
+    pub cp_amm_quote_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), lp_mint.key().as_ref()],
+        bump
+    )]
+    pub cp_amm_locked_lp_vault: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + LpLock::INIT_SPACE,
+        seeds = [LpLock::SEED, lp_mint.key().as_ref(), creator.key().as_ref(), LpLock::LAUNCH_NONCE.to_le_bytes().as_ref()],
+        bump
+    )]
+    // Required when a share of the creator's LP tokens is locked at the launch
+    pub creator_lp_lock: Option<Box<Account<'info, LpLock>>>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = lp_mint,
+        token::authority = creator_lp_lock,
+        token::token_program = lp_token_program,
+        seeds = [LpLock::VAULT_SEED, lp_mint.key().as_ref(), creator.key().as_ref(), LpLock::LAUNCH_NONCE.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub creator_lp_lock_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub lp_token_program: Program<'info, Token>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Initializes and launches `CpAmm` atomically, so the pool is never observable before its launch liquidity.
+/// The creator funds the liquidity and can be a launchpad PDA calling via CPI, while the payer covers the initialization price and rent.
+/// The launch LP tokens go to the associated account of `lp_beneficiary`, the options match `initialize_cp_amm` and `launch_cp_amm`.
+pub(crate) fn handler(ctx: Context<InitializeAndLaunchCpAmm>, creator_fee_share_basis_points: u16, base_liquidity: u64, quote_liquidity: u64, open_time: i64, launch_fee_schedule: Option<LaunchFeeSchedule>, creator_lp_lock_config: Option<CreatorLpLockConfig>) -> Result<()> {
+    let bumps = CpAmmBumps {
+        cp_amm: ctx.bumps.cp_amm,
+        base_vault: ctx.bumps.cp_amm_base_vault,
+        quote_vault: ctx.bumps.cp_amm_quote_vault,
+        locked_lp_vault: ctx.bumps.cp_amm_locked_lp_vault,
+    };
+    let accounts = &mut *ctx.accounts;
+    InitializeAccounts {
+        payer: &accounts.payer,
+        creator: &accounts.creator,
+        fee_authority: &accounts.fee_authority,
+        base_mint: &accounts.base_mint,
+        quote_mint: &accounts.quote_mint,
+        lp_mint: &accounts.lp_mint,
+        amms_config: &accounts.amms_config,
+        amms_configs_manager: &accounts.amms_configs_manager,
+        cp_amm: &mut accounts.cp_amm,
+        cp_amm_base_vault: &accounts.cp_amm_base_vault,
+        cp_amm_quote_vault: &accounts.cp_amm_quote_vault,
+        cp_amm_locked_lp_vault: &accounts.cp_amm_locked_lp_vault,
+        system_program: &accounts.system_program,
+        lp_token_program: &accounts.lp_token_program,
+        base_token_program: &accounts.base_token_program,
+        quote_token_program: &accounts.quote_token_program,
+    }.initialize(bumps, creator_fee_share_basis_points)?;
+
+    LaunchAccounts {
+        creator: &accounts.creator,
+        base_mint: &accounts.base_mint,
+        quote_mint: &accounts.quote_mint,
+        lp_mint: &accounts.lp_mint,
+        creator_base_account: &accounts.creator_base_account,
+        creator_quote_account: &accounts.creator_quote_account,
+        lp_account: &accounts.beneficiary_lp_account,
+        amms_config: &accounts.amms_config,
+        amms_configs_manager: &accounts.amms_configs_manager,
+        cp_amm: &mut accounts.cp_amm,
+        cp_amm_base_vault: accounts.cp_amm_base_vault.to_account_info(),
+        cp_amm_quote_vault: accounts.cp_amm_quote_vault.to_account_info(),
+        cp_amm_locked_lp_vault: accounts.cp_amm_locked_lp_vault.to_account_info(),
+        creator_lp_lock: accounts.creator_lp_lock.as_deref_mut(),
+        creator_lp_lock_bump: ctx.bumps.creator_lp_lock,
+        creator_lp_lock_vault: accounts.creator_lp_lock_vault.as_deref(),
+        creator_lp_lock_vault_bump: ctx.bumps.creator_lp_lock_vault,
+        lp_token_program: &accounts.lp_token_program,
+        base_token_program: &accounts.base_token_program,
+        quote_token_program: &accounts.quote_token_program,
+    }.launch(base_liquidity, quote_liquidity, open_time, launch_fee_schedule, creator_lp_lock_config)
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::error::ErrorCode;
+use crate::state::{AmmsConfig, AmmsConfigsManager, BondingCurve, cp_amm::{CpAmm, PoolStatus}};
+use crate::utils::token_instructions::TransferTokensInstruction;
+
+#[derive(Accounts)]
+pub struct InitializeBondingCurve<'info> {
+    #[account(mut)]
+    // The creator or the launch authority of CpAmm
+    pub signer: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub signer_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    pub amms_configs_manager: Box<Account<'info, AmmsConfigsManager>>,
+
+    #[account(
+        mut,
+        constraint = cp_amm.status() == PoolStatus::Initialized,
+        constraint = cp_amm.can_launch(&signer.key()),
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        constraint = base_mint.key() == cp_amm.base_mint().key(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + BondingCurve::INIT_SPACE,
+        seeds = [BondingCurve::SEED, cp_amm.key().as_ref()],
+        bump
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
+        bump = cp_amm.base_vault_bump()
+    )]
+    pub cp_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposits the base tokens for sale and moves `CpAmm` to its bonding curve, replacing `launch_cp_amm`.
+/// The virtual reserves set the curve price, they should match the price of the real reserves at `graduation_quote_threshold`
+/// for the launched `CpAmm` to continue at the final curve price.
+pub(crate) fn handler(ctx: Context<InitializeBondingCurve>, base_amount: u64, virtual_base_reserves: u64, virtual_quote_reserves: u64, graduation_quote_threshold: u64) -> Result<()> {
+    require!(!ctx.accounts.amms_configs_manager.is_halted(), ErrorCode::AmmsConfigHalted);
+
+    let deposit_base_instruction = Box::new(ctx.accounts.get_deposit_base_transfer_instruction(base_amount)?);
+    let base_reserves = deposit_base_instruction.get_amount_after_fee();
+    deposit_base_instruction.execute(None)?;
+
+    let cp_amm_key = ctx.accounts.cp_amm.key();
+    ctx.accounts.bonding_curve.initialize(
+        cp_amm_key,
+        base_reserves,
+        virtual_base_reserves,
+        virtual_quote_reserves,
+        graduation_quote_threshold,
+        ctx.bumps.bonding_curve
+    )?;
+    ctx.accounts.cp_amm.start_bonding_curve()
+}
+
+impl<'info> InitializeBondingCurve<'info> {
+    fn get_deposit_base_transfer_instruction(&self, base_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            base_amount,
+            &self.base_mint,
+            &self.signer_base_account,
+            self.signer.to_account_info(),
+            &self.cp_amm_base_vault,
+            &self.base_token_program
+        )
+    }
+}
@@ -2,9 +2,12 @@ use anchor_lang::prelude::*;
 use anchor_spl::{token::{Mint, Token}, token_interface};
 use anchor_spl::token_interface::TokenInterface;
 use crate::constants::CP_AMM_INITIALIZE_PRICE_IN_LAMPORTS;
-use crate::state::{AmmsConfig, cp_amm::{
+use crate::error::ErrorCode;
+use crate::state::{AmmsConfig, AmmsConfigsManager, cp_amm::{
     CpAmm, 
-    CpAmmCalculate
+    CpAmmBumps,
+    CpAmmCalculate,
+    CpAmmInitializeOptions
 }};
 use crate::utils::system_instructions::TransferLamportsInstruction;
 use crate::utils::token_accounts_instructions::CreatePdaTokenAccountInstruction;
@@ -38,6 +41,11 @@ pub struct InitializeCpAmm<'info> {
         bump = amms_config.bump()
     )]
     pub amms_config: Box<Account<'info, AmmsConfig>>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    pub amms_configs_manager: Box<Account<'info, AmmsConfigsManager>>,
     
     #[account(
         init,
@@ -76,72 +84,112 @@ pub struct InitializeCpAmm<'info> {
     pub quote_token_program: Interface<'info, TokenInterface>,
 }
 
-pub(crate) fn handler(ctx: Context<InitializeCpAmm>) -> Result<()> {
-    ctx.accounts.validate_base_mint()?;
-    ctx.accounts.validate_quote_mint()?;
-    {
-        let cp_amm_key = ctx.accounts.cp_amm.key();
-        {
-            let base_mint_key = ctx.accounts.base_mint.key();
-            let create_cp_amm_base_vault = Box::new(ctx.accounts.get_create_cp_amm_base_vault_instruction()?);
-            let cp_amm_base_vault_seeds = [CpAmm::VAULT_SEED, cp_amm_key.as_ref(), base_mint_key.as_ref(), &[ctx.bumps.cp_amm_base_vault]];
-            create_cp_amm_base_vault.execute(&[&cp_amm_base_vault_seeds])?;
-        }
-        {
-            let quote_mint_key = ctx.accounts.quote_mint.key();
-            let create_cp_amm_quote_vault = Box::new(ctx.accounts.get_create_cp_amm_quote_vault_instruction()?);
-            let cp_amm_quote_vault_seeds = [CpAmm::VAULT_SEED, cp_amm_key.as_ref(),quote_mint_key.as_ref(), &[ctx.bumps.cp_amm_quote_vault]];
-            create_cp_amm_quote_vault.execute(&[&cp_amm_quote_vault_seeds])?;
-        }
+pub(crate) fn handler(ctx: Context<InitializeCpAmm>, creator_fee_share_basis_points: u16) -> Result<()> {
+    let bumps = CpAmmBumps {
+        cp_amm: ctx.bumps.cp_amm,
+        base_vault: ctx.bumps.cp_amm_base_vault,
+        quote_vault: ctx.bumps.cp_amm_quote_vault,
+        locked_lp_vault: ctx.bumps.cp_amm_locked_lp_vault,
+    };
+    let accounts = &mut *ctx.accounts;
+    InitializeAccounts {
+        payer: &accounts.signer,
+        creator: &accounts.signer,
+        fee_authority: &accounts.fee_authority,
+        base_mint: &accounts.base_mint,
+        quote_mint: &accounts.quote_mint,
+        lp_mint: &accounts.lp_mint,
+        amms_config: &accounts.amms_config,
+        amms_configs_manager: &accounts.amms_configs_manager,
+        cp_amm: &mut accounts.cp_amm,
+        cp_amm_base_vault: &accounts.cp_amm_base_vault,
+        cp_amm_quote_vault: &accounts.cp_amm_quote_vault,
+        cp_amm_locked_lp_vault: &accounts.cp_amm_locked_lp_vault,
+        system_program: &accounts.system_program,
+        lp_token_program: &accounts.lp_token_program,
+        base_token_program: &accounts.base_token_program,
+        quote_token_program: &accounts.quote_token_program,
+    }.initialize(bumps, creator_fee_share_basis_points)
+}
+
+/// Accounts of a `CpAmm` initialization, shared by `initialize_cp_amm` and `initialize_and_launch_cp_amm`.
+pub(crate) struct InitializeAccounts<'a, 'info> {
+    pub payer: &'a Signer<'info>,
+    pub creator: &'a Signer<'info>,
+    pub fee_authority: &'a AccountInfo<'info>,
+    pub base_mint: &'a InterfaceAccount<'info, token_interface::Mint>,
+    pub quote_mint: &'a InterfaceAccount<'info, token_interface::Mint>,
+    pub lp_mint: &'a Account<'info, Mint>,
+    pub amms_config: &'a Account<'info, AmmsConfig>,
+    pub amms_configs_manager: &'a AmmsConfigsManager,
+    pub cp_amm: &'a mut Account<'info, CpAmm>,
+    pub cp_amm_base_vault: &'a AccountInfo<'info>,
+    pub cp_amm_quote_vault: &'a AccountInfo<'info>,
+    pub cp_amm_locked_lp_vault: &'a AccountInfo<'info>,
+    pub system_program: &'a Program<'info, System>,
+    pub lp_token_program: &'a Program<'info, Token>,
+    pub base_token_program: &'a Interface<'info, TokenInterface>,
+    pub quote_token_program: &'a Interface<'info, TokenInterface>,
+}
+
+impl<'info> InitializeAccounts<'_, 'info> {
+    /// Creates the vaults of `CpAmm`, pays the initialization price to the fee authority and initializes `CpAmm`.
+    /// The payer funds the vaults rent and the initialization price.
+    pub(crate) fn initialize(self, bumps: CpAmmBumps, creator_fee_share_basis_points: u16) -> Result<()> {
+        require!(!self.amms_configs_manager.is_halted(), ErrorCode::AmmsConfigHalted);
+        validate_tradable_mint(self.base_mint)?;
+        validate_tradable_mint(self.quote_mint)?;
         {
-            let lp_mint_key = ctx.accounts.lp_mint.key();
-            let create_cp_amm_locked_lp_vault = Box::new(ctx.accounts.get_create_cp_amm_locked_lp_vault_instruction()?);
-            let cp_amm_locked_lp_vault_seeds = [CpAmm::VAULT_SEED, cp_amm_key.as_ref(), lp_mint_key.as_ref(), &[ctx.bumps.cp_amm_locked_lp_vault]];
-            create_cp_amm_locked_lp_vault.execute(&[&cp_amm_locked_lp_vault_seeds])?;
+            let cp_amm_key = self.cp_amm.key();
+            {
+                let base_mint_key = self.base_mint.key();
+                let create_cp_amm_base_vault = Box::new(self.get_create_cp_amm_base_vault_instruction()?);
+                let cp_amm_base_vault_seeds = [CpAmm::VAULT_SEED, cp_amm_key.as_ref(), base_mint_key.as_ref(), &[bumps.base_vault]];
+                create_cp_amm_base_vault.execute(&[&cp_amm_base_vault_seeds])?;
+            }
+            {
+                let quote_mint_key = self.quote_mint.key();
+                let create_cp_amm_quote_vault = Box::new(self.get_create_cp_amm_quote_vault_instruction()?);
+                let cp_amm_quote_vault_seeds = [CpAmm::VAULT_SEED, cp_amm_key.as_ref(),quote_mint_key.as_ref(), &[bumps.quote_vault]];
+                create_cp_amm_quote_vault.execute(&[&cp_amm_quote_vault_seeds])?;
+            }
+            {
+                let lp_mint_key = self.lp_mint.key();
+                let create_cp_amm_locked_lp_vault = Box::new(self.get_create_cp_amm_locked_lp_vault_instruction()?);
+                let cp_amm_locked_lp_vault_seeds = [CpAmm::VAULT_SEED, cp_amm_key.as_ref(), lp_mint_key.as_ref(), &[bumps.locked_lp_vault]];
+                create_cp_amm_locked_lp_vault.execute(&[&cp_amm_locked_lp_vault_seeds])?;
+            }
         }
-    }
-    let accounts = ctx.accounts;
 
-    let pay_initial_lamports_instruction = Box::new(accounts.get_pay_initial_lamports_instruction(CP_AMM_INITIALIZE_PRICE_IN_LAMPORTS)?);
-    pay_initial_lamports_instruction.execute()?;
-    
-    accounts.cp_amm.initialize(
-        &accounts.base_mint,
-        &accounts.quote_mint,
-        &accounts.lp_mint,
-        &accounts.amms_config,
-        &accounts.signer.to_account_info(),
-        &accounts.cp_amm_base_vault,
-        &accounts.cp_amm_quote_vault,
-        &accounts.cp_amm_locked_lp_vault,
-        ctx.bumps.cp_amm,
-        ctx.bumps.cp_amm_base_vault,
-        ctx.bumps.cp_amm_quote_vault,
-        ctx.bumps.cp_amm_locked_lp_vault
-    )
-}
+        let pay_initial_lamports_instruction = Box::new(self.get_pay_initial_lamports_instruction(CP_AMM_INITIALIZE_PRICE_IN_LAMPORTS)?);
+        pay_initial_lamports_instruction.execute()?;
 
-impl<'info> InitializeCpAmm<'info>{
-    fn validate_base_mint(&self) -> Result<()> {
-        let base_mint = self.base_mint.as_ref();
-        validate_tradable_mint(base_mint)
-    }
-    fn validate_quote_mint(&self) -> Result<()> {
-        let quote_mint = self.quote_mint.as_ref();
-        validate_tradable_mint(quote_mint)
+        self.cp_amm.initialize(
+            self.base_mint,
+            self.quote_mint,
+            self.lp_mint,
+            self.amms_config,
+            &self.creator.to_account_info(),
+            self.cp_amm_base_vault,
+            self.cp_amm_quote_vault,
+            self.cp_amm_locked_lp_vault,
+            bumps,
+            CpAmmInitializeOptions { creator_fee_share_basis_points, initialized_at: Clock::get()?.unix_timestamp }
+        )
     }
+
     fn get_pay_initial_lamports_instruction(&self, lamports: u64) -> Result<TransferLamportsInstruction<'_, '_, '_, 'info>>{
         TransferLamportsInstruction::new(
             lamports,
-            self.signer.to_account_info(),
+            self.payer.to_account_info(),
             self.fee_authority.to_account_info(),
-            &self.system_program
+            self.system_program
         )
     }
     #[inline(never)]
     fn get_create_cp_amm_base_vault_instruction(&self) -> Result<CreatePdaTokenAccountInstruction<'_, '_, '_, 'info>>{
         CreatePdaTokenAccountInstruction::try_new(
-            self.signer.to_account_info(),
+            self.payer.to_account_info(),
             self.cp_amm_base_vault.to_account_info(),
             self.cp_amm.to_account_info(),
             self.base_mint.to_account_info(),
@@ -152,7 +200,7 @@ impl<'info> InitializeCpAmm<'info>{
     #[inline(never)]
     fn get_create_cp_amm_quote_vault_instruction(&self) -> Result<CreatePdaTokenAccountInstruction<'_, '_, '_, 'info>>{
         CreatePdaTokenAccountInstruction::try_new(
-            self.signer.to_account_info(),
+            self.payer.to_account_info(),
             self.cp_amm_quote_vault.to_account_info(),
             self.cp_amm.to_account_info(),
             self.quote_mint.to_account_info(),
@@ -163,7 +211,7 @@ impl<'info> InitializeCpAmm<'info>{
     #[inline(never)]
     fn get_create_cp_amm_locked_lp_vault_instruction(&self) -> Result<CreatePdaTokenAccountInstruction<'_, '_, '_, 'info>>{
         CreatePdaTokenAccountInstruction::try_new(
-            self.signer.to_account_info(),
+            self.payer.to_account_info(),
             self.cp_amm_locked_lp_vault.to_account_info(),
             self.cp_amm.to_account_info(),
             self.lp_mint.to_account_info(),
@@ -171,4 +219,4 @@ impl<'info> InitializeCpAmm<'info>{
             self.system_program.to_account_info()
         )
     }
-}
\ No newline at end of file
+}
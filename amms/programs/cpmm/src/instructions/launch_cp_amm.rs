@@ -5,7 +5,8 @@ use anchor_spl::{
     token_interface::{TokenAccount as InterfaceTokenAccount, Mint, TokenInterface}
 };
 use anchor_spl::associated_token::AssociatedToken;
-use crate::state::{AmmsConfig, cp_amm::CpAmm};
+use crate::error::ErrorCode;
+use crate::state::{AmmsConfig, AmmsConfigsManager, CreatorLpLockConfig, LpLock, LpLockTerms, cp_amm::{CpAmm, LaunchFeeSchedule, PoolStatus}};
 use crate::utils::{
     token_instructions::{MintTokensInstructions, TransferTokensInstruction}
 };
@@ -13,6 +14,7 @@ use crate::utils::{
 #[derive(Accounts)]
 pub struct LaunchCpAmm<'info>{
     #[account(mut)]
+    // The creator of CpAmm, or its launch authority launching on behalf of the creator
     pub creator: Signer<'info>,
     pub base_mint: Box<InterfaceAccount<'info, Mint>>,
     pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
@@ -26,7 +28,7 @@ pub struct LaunchCpAmm<'info>{
     pub creator_quote_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
 
     #[account(
-        init,
+        init_if_needed,
         payer = creator,
         associated_token::mint = lp_mint,
         associated_token::authority = creator,
@@ -34,16 +36,30 @@ pub struct LaunchCpAmm<'info>{
     )]
     pub creator_lp_account: Box<Account<'info, TokenAccount>>,
 
+    #[account(
+        mut,
+        token::mint = lp_mint,
+        token::token_program = lp_token_program
+    )]
+    // Receives the minted LP tokens instead of the creator's account, can be owned by any account or PDA
+    pub beneficiary_lp_account: Option<Box<Account<'info, TokenAccount>>>,
+
     #[account(
         seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
         bump = amms_config.bump()
     )]
     pub amms_config: Box<Account<'info, AmmsConfig>>,
 
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    pub amms_configs_manager: Box<Account<'info, AmmsConfigsManager>>,
+
     #[account(
         mut,
-        constraint = !cp_amm.is_launched(),
-        constraint = creator.key() == cp_amm.creator().key(),
+        constraint = cp_amm.status() == PoolStatus::Initialized,
+        constraint = cp_amm.can_launch(&creator.key()),
         constraint = amms_config.key() == cp_amm.amms_config().key(),
         constraint = lp_mint.key() == cp_amm.lp_mint,
         constraint = base_mint.key() == cp_amm.base_mint().key(),
@@ -76,6 +92,27 @@ pub struct LaunchCpAmm<'info>{
         bump = cp_amm.locked_lp_vault_bump()
     )]
     pub cp_amm_locked_lp_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + LpLock::INIT_SPACE,
+        seeds = [LpLock::SEED, lp_mint.key().as_ref(), cp_amm.creator().as_ref(), LpLock::LAUNCH_NONCE.to_le_bytes().as_ref()],
+        bump
+    )]
+    // Required when a share of the creator's LP tokens is locked at the launch
+    pub creator_lp_lock: Option<Box<Account<'info, LpLock>>>,
+
+    #[account(
+        init,
+        payer = creator,
+        token::mint = lp_mint,
+        token::authority = creator_lp_lock,
+        token::token_program = lp_token_program,
+        seeds = [LpLock::VAULT_SEED, lp_mint.key().as_ref(), cp_amm.creator().as_ref(), LpLock::LAUNCH_NONCE.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub creator_lp_lock_vault: Option<Box<Account<'info, TokenAccount>>>,
     
     pub lp_token_program: Program<'info, Token>,
     pub base_token_program: Interface<'info, TokenInterface>,
@@ -84,54 +121,135 @@ pub struct LaunchCpAmm<'info>{
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
-pub(crate) fn handler(ctx: Context<LaunchCpAmm>, base_liquidity: u64, quote_liquidity: u64) -> Result<()> {
-    
-    let provide_base_liquidity_instruction = Box::new(ctx.accounts.get_provide_base_liquidity_transfer_instruction(base_liquidity)?);
-    let provide_quote_liquidity_instruction = Box::new(ctx.accounts.get_provide_quote_liquidity_transfer_instruction(quote_liquidity)?);
+/// `open_time` delays swaps to protect the launch from snipers, only the creator can provide liquidity before it.
+/// `launch_fee_schedule` additionally taxes the first swaps after `open_time`, the extra fee goes to the liquidity providers.
+/// `creator_lp_lock_config` escrows a share of the creator's launch LP tokens in an `LpLock`, with the creator as its beneficiary.
+/// `beneficiary_lp_account` receives the unlocked launch LP tokens instead of `creator_lp_account`.
+/// The creator or its launch authority launches the AMM as `creator`, funding the liquidity from its own token accounts.
+pub(crate) fn handler(ctx: Context<LaunchCpAmm>, base_liquidity: u64, quote_liquidity: u64, open_time: i64, launch_fee_schedule: Option<LaunchFeeSchedule>, creator_lp_lock_config: Option<CreatorLpLockConfig>) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    LaunchAccounts {
+        creator: &accounts.creator,
+        base_mint: &accounts.base_mint,
+        quote_mint: &accounts.quote_mint,
+        lp_mint: &accounts.lp_mint,
+        creator_base_account: &accounts.creator_base_account,
+        creator_quote_account: &accounts.creator_quote_account,
+        lp_account: accounts.beneficiary_lp_account.as_deref().unwrap_or(&accounts.creator_lp_account),
+        amms_config: &accounts.amms_config,
+        amms_configs_manager: &accounts.amms_configs_manager,
+        cp_amm: &mut accounts.cp_amm,
+        cp_amm_base_vault: accounts.cp_amm_base_vault.to_account_info(),
+        cp_amm_quote_vault: accounts.cp_amm_quote_vault.to_account_info(),
+        cp_amm_locked_lp_vault: accounts.cp_amm_locked_lp_vault.to_account_info(),
+        creator_lp_lock: accounts.creator_lp_lock.as_deref_mut(),
+        creator_lp_lock_bump: ctx.bumps.creator_lp_lock,
+        creator_lp_lock_vault: accounts.creator_lp_lock_vault.as_deref(),
+        creator_lp_lock_vault_bump: ctx.bumps.creator_lp_lock_vault,
+        lp_token_program: &accounts.lp_token_program,
+        base_token_program: &accounts.base_token_program,
+        quote_token_program: &accounts.quote_token_program,
+    }.launch(base_liquidity, quote_liquidity, open_time, launch_fee_schedule, creator_lp_lock_config)
+}
 
-    let base_liquidity_to_provide = provide_base_liquidity_instruction.get_amount_after_fee();
-    let quote_liquidity_to_provide = provide_quote_liquidity_instruction.get_amount_after_fee();
+/// Accounts of a `CpAmm` launch, shared by `launch_cp_amm` and `initialize_and_launch_cp_amm`.
+pub(crate) struct LaunchAccounts<'a, 'info> {
+    pub creator: &'a Signer<'info>,
+    pub base_mint: &'a InterfaceAccount<'info, Mint>,
+    pub quote_mint: &'a InterfaceAccount<'info, Mint>,
+    pub lp_mint: &'a Account<'info, token::Mint>,
+    pub creator_base_account: &'a InterfaceAccount<'info, InterfaceTokenAccount>,
+    pub creator_quote_account: &'a InterfaceAccount<'info, InterfaceTokenAccount>,
+    pub lp_account: &'a Account<'info, TokenAccount>,
+    pub amms_config: &'a AmmsConfig,
+    pub amms_configs_manager: &'a AmmsConfigsManager,
+    pub cp_amm: &'a mut Account<'info, CpAmm>,
+    pub cp_amm_base_vault: AccountInfo<'info>,
+    pub cp_amm_quote_vault: AccountInfo<'info>,
+    pub cp_amm_locked_lp_vault: AccountInfo<'info>,
+    pub creator_lp_lock: Option<&'a mut Account<'info, LpLock>>,
+    pub creator_lp_lock_bump: Option<u8>,
+    pub creator_lp_lock_vault: Option<&'a Account<'info, TokenAccount>>,
+    pub creator_lp_lock_vault_bump: Option<u8>,
+    pub lp_token_program: &'a Program<'info, Token>,
+    pub base_token_program: &'a Interface<'info, TokenInterface>,
+    pub quote_token_program: &'a Interface<'info, TokenInterface>,
+}
 
-    let launch_payload = Box::new(ctx.accounts.cp_amm.get_launch_payload(base_liquidity_to_provide, quote_liquidity_to_provide)?);
+impl<'info> LaunchAccounts<'_, 'info> {
+    /// Funds the launch liquidity from the creator's token accounts, mints the launch LP tokens to `lp_account`
+    /// and locks the initial liquidity, as well as a share of the creator's LP tokens if `creator_lp_lock_config` is set.
+    pub(crate) fn launch(mut self, base_liquidity: u64, quote_liquidity: u64, open_time: i64, launch_fee_schedule: Option<LaunchFeeSchedule>, creator_lp_lock_config: Option<CreatorLpLockConfig>) -> Result<()> {
+        require!(!self.amms_configs_manager.is_halted(), ErrorCode::AmmsConfigHalted);
+        if let Some(launch_fee_schedule) = &launch_fee_schedule {
+            launch_fee_schedule.validate()?;
+        }
 
-    let launch_liquidity_mint_instruction = Box::new(ctx.accounts.get_launch_liquidity_mint_instruction(launch_payload.launch_liquidity()));
-    let initial_locked_liquidity_mint_instruction = Box::new(ctx.accounts.get_initial_locked_liquidity_mint_instruction(launch_payload.initial_locked_liquidity()));
+        let provide_base_liquidity_instruction = Box::new(self.get_provide_base_liquidity_transfer_instruction(base_liquidity)?);
+        let provide_quote_liquidity_instruction = Box::new(self.get_provide_quote_liquidity_transfer_instruction(quote_liquidity)?);
 
-    provide_base_liquidity_instruction.execute(None)?;
-    provide_quote_liquidity_instruction.execute(None)?;
+        let base_liquidity_to_provide = provide_base_liquidity_instruction.get_amount_after_fee();
+        let quote_liquidity_to_provide = provide_quote_liquidity_instruction.get_amount_after_fee();
 
-    let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
-    let mint_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+        let launch_payload = Box::new(self.cp_amm.get_launch_payload(
+            base_liquidity_to_provide,
+            quote_liquidity_to_provide,
+            self.amms_config.protocol_fee_mode()
+        )?);
 
-    launch_liquidity_mint_instruction.execute(Some(mint_instruction_seeds))?;
-    initial_locked_liquidity_mint_instruction.execute(Some(mint_instruction_seeds))?;
+        require!(
+            creator_lp_lock_config.is_some() == self.creator_lp_lock.is_some() &&
+                creator_lp_lock_config.is_some() == self.creator_lp_lock_vault.is_some(),
+            ErrorCode::InvalidCreatorLpLock
+        );
+        if let Some(creator_lp_lock_config) = &creator_lp_lock_config {
+            require!(creator_lp_lock_config.share_basis_points <= 10000, ErrorCode::InvalidCreatorLpLock);
+        }
+        let creator_locked_liquidity = creator_lp_lock_config.map_or(0, |config| (launch_payload.launch_liquidity() as u128 * config.share_basis_points as u128 / 10000) as u64);
 
-    ctx.accounts.cp_amm.launch(*launch_payload);
-    Ok(())
-}
+        let launch_liquidity_mint_instruction = Box::new(self.get_launch_liquidity_mint_instruction(launch_payload.launch_liquidity() - creator_locked_liquidity));
+        let initial_locked_liquidity_mint_instruction = Box::new(self.get_initial_locked_liquidity_mint_instruction(launch_payload.initial_locked_liquidity()));
+
+        provide_base_liquidity_instruction.execute(None)?;
+        provide_quote_liquidity_instruction.execute(None)?;
+
+        let cp_amm_seeds = self.cp_amm.seeds();
+        let mint_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+
+        launch_liquidity_mint_instruction.execute(Some(mint_instruction_seeds))?;
+        initial_locked_liquidity_mint_instruction.execute(Some(mint_instruction_seeds))?;
+        if creator_locked_liquidity > 0 {
+            self.get_creator_locked_liquidity_mint_instruction(creator_locked_liquidity)?.execute(Some(mint_instruction_seeds))?;
+        }
+
+        if let Some(creator_lp_lock_config) = creator_lp_lock_config {
+            self.initialize_creator_lp_lock(creator_locked_liquidity, creator_lp_lock_config)?;
+        }
+        self.cp_amm.launch(*launch_payload, open_time, launch_fee_schedule);
+        Ok(())
+    }
 
-impl<'info> LaunchCpAmm<'info>{
     #[inline(never)]
     fn get_provide_base_liquidity_transfer_instruction(&self, base_liquidity: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
-        TransferTokensInstruction::try_new(
+        TransferTokensInstruction::try_new_to_account_info(
             base_liquidity,
-            &self.base_mint,
-            &self.creator_base_account,
+            self.base_mint,
+            self.creator_base_account,
             self.creator.to_account_info(),
-            &self.cp_amm_base_vault,
-            &self.base_token_program
+            self.cp_amm_base_vault.clone(),
+            self.base_token_program
         )
     }
 
     #[inline(never)]
     fn get_provide_quote_liquidity_transfer_instruction(&self, quote_liquidity: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
-        TransferTokensInstruction::try_new(
+        TransferTokensInstruction::try_new_to_account_info(
             quote_liquidity,
-            &self.quote_mint,
-            &self.creator_quote_account,
+            self.quote_mint,
+            self.creator_quote_account,
             self.creator.to_account_info(),
-            &self.cp_amm_quote_vault,
-            &self.quote_token_program
+            self.cp_amm_quote_vault.clone(),
+            self.quote_token_program
         )
     }
 
@@ -139,21 +257,51 @@ impl<'info> LaunchCpAmm<'info>{
     fn get_launch_liquidity_mint_instruction(&self, launch_liquidity: u64) -> MintTokensInstructions<'_, '_, '_, 'info>{
         MintTokensInstructions::new(
             launch_liquidity,
-            &self.lp_mint,
+            self.lp_mint,
             self.cp_amm.to_account_info(),
-            self.creator_lp_account.to_account_info(),
-            &self.lp_token_program
+            self.lp_account.to_account_info(),
+            self.lp_token_program
         )
     }
 
+    #[inline(never)]
+    fn initialize_creator_lp_lock(&mut self, creator_locked_liquidity: u64, creator_lp_lock_config: CreatorLpLockConfig) -> Result<()> {
+        let (cp_amm, lp_mint, creator) = (self.cp_amm.key(), self.lp_mint.key(), *self.cp_amm.creator());
+        let creator_lp_lock = self.creator_lp_lock.as_mut().ok_or(ErrorCode::InvalidCreatorLpLock)?;
+        creator_lp_lock.initialize(
+            cp_amm,
+            lp_mint,
+            creator,
+            LpLock::LAUNCH_NONCE,
+            self.creator_lp_lock_bump.ok_or(ErrorCode::InvalidCreatorLpLock)?,
+            self.creator_lp_lock_vault_bump.ok_or(ErrorCode::InvalidCreatorLpLock)?
+        );
+        creator_lp_lock.lock(
+            LpLockTerms { beneficiary: creator, locked_amount: creator_locked_liquidity, schedule: creator_lp_lock_config.schedule },
+            Clock::get()?.unix_timestamp
+        )
+    }
+
+    #[inline(never)]
+    fn get_creator_locked_liquidity_mint_instruction(&self, creator_locked_liquidity: u64) -> Result<MintTokensInstructions<'_, '_, '_, 'info>>{
+        let creator_lp_lock_vault = self.creator_lp_lock_vault.ok_or(ErrorCode::InvalidCreatorLpLock)?;
+        Ok(MintTokensInstructions::new(
+            creator_locked_liquidity,
+            self.lp_mint,
+            self.cp_amm.to_account_info(),
+            creator_lp_lock_vault.to_account_info(),
+            self.lp_token_program
+        ))
+    }
+
     #[inline(never)]
     fn get_initial_locked_liquidity_mint_instruction(&self, initial_locked_liquidity: u64) -> MintTokensInstructions<'_, '_, '_, 'info>{
         MintTokensInstructions::new(
             initial_locked_liquidity,
-            &self.lp_mint,
+            self.lp_mint,
             self.cp_amm.to_account_info(),
-            self.cp_amm_locked_lp_vault.to_account_info(),
-            &self.lp_token_program
+            self.cp_amm_locked_lp_vault.clone(),
+            self.lp_token_program
         )
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{LpLock, LpLockSchedule, LpLockTerms, cp_amm::CpAmm};
+use crate::utils::token_instructions::TransferTokensInstruction;
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct LockLp<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub owner_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = lp_mint.key() == cp_amm.lp_mint,
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + LpLock::INIT_SPACE,
+        seeds = [LpLock::SEED, lp_mint.key().as_ref(), owner.key().as_ref(), nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub lp_lock: Box<Account<'info, LpLock>>,
+
+    #[account(
+        init,
+        payer = owner,
+        token::mint = lp_mint,
+        token::authority = lp_lock,
+        token::token_program = lp_token_program,
+        seeds = [LpLock::VAULT_SEED, lp_mint.key().as_ref(), owner.key().as_ref(), nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub lp_lock_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub lp_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<LockLp>, nonce: u64, amount: u64, beneficiary: Pubkey, schedule: LpLockSchedule) -> Result<()> {
+    ctx.accounts.lp_lock.initialize(
+        ctx.accounts.cp_amm.key(),
+        ctx.accounts.lp_mint.key(),
+        ctx.accounts.owner.key(),
+        nonce,
+        ctx.bumps.lp_lock,
+        ctx.bumps.lp_lock_vault
+    );
+    ctx.accounts.lp_lock.lock(LpLockTerms { beneficiary, locked_amount: amount, schedule }, Clock::get()?.unix_timestamp)?;
+
+    ctx.accounts.get_lock_transfer_instruction(amount)?.execute(None)?;
+    Ok(())
+}
+
+impl<'info> LockLp<'info> {
+    fn get_lock_transfer_instruction(&self, amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            amount,
+            &self.lp_mint,
+            &self.owner_lp_account,
+            self.owner.to_account_info(),
+            &self.lp_lock_vault,
+            &self.lp_token_program
+        )
+    }
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use crate::state::LegacyAmmsConfig;
+use crate::utils::migrate_legacy_account;
+
+#[derive(Accounts)]
+pub struct MigrateAmmsConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut, owner = crate::ID)]
+    /// CHECK: The legacy layout is read from the raw data, its discriminator and length are checked on migration
+    pub amms_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Anyone can migrate an `AmmsConfig` created before versioning, the payer funds the rent of the grown account.
+/// The other instructions read and write legacy accounts in their legacy layout, until they set state it can't hold.
+pub(crate) fn handler(ctx: Context<MigrateAmmsConfig>) -> Result<()> {
+    migrate_legacy_account::<LegacyAmmsConfig>(&ctx.accounts.amms_config, &ctx.accounts.payer, &ctx.accounts.system_program)
+}
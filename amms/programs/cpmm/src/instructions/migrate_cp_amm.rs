@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use crate::state::cp_amm::LegacyCpAmm;
+use crate::utils::migrate_legacy_account;
+
+#[derive(Accounts)]
+pub struct MigrateCpAmm<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut, owner = crate::ID)]
+    /// CHECK: The legacy layout is read from the raw data, its discriminator and length are checked on migration
+    pub cp_amm: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Anyone can migrate a `CpAmm` created before versioning, the payer funds the rent of the grown account.
+/// The other instructions read and write legacy accounts in their legacy layout, until they set state it can't hold.
+pub(crate) fn handler(ctx: Context<MigrateCpAmm>) -> Result<()> {
+    migrate_legacy_account::<LegacyCpAmm>(&ctx.accounts.cp_amm, &ctx.accounts.payer, &ctx.accounts.system_program)
+}
@@ -1,17 +1,49 @@
 #![allow(ambiguous_glob_reexports)]
 mod admin;
 pub use admin::*;
+mod farm;
+pub use farm::*;
 
 pub mod initialize_cp_amm;
 pub mod launch_cp_amm;
+pub mod initialize_and_launch_cp_amm;
+pub mod update_cp_amm_launch_authority;
 pub mod provide_to_cp_amm;
+pub mod donate_to_cp_amm;
+pub mod skim_cp_amm;
+pub mod verify_cp_amm;
+pub mod migrate_amms_configs_manager;
+pub mod migrate_amms_config;
+pub mod migrate_cp_amm;
 pub mod withdraw_from_cp_amm;
 pub mod swap_in_cp_amm;
+pub mod swap_v2;
 pub mod collect_fees_from_cp_amm;
+pub mod collect_creator_fees_from_cp_amm;
+pub mod cancel_cp_amm;
+pub mod lock_lp;
+pub mod claim_unlocked_lp;
+pub mod initialize_bonding_curve;
+pub mod swap_in_bonding_curve;
 
 pub use initialize_cp_amm::*;
 pub use launch_cp_amm::*;
+pub use initialize_and_launch_cp_amm::*;
+pub use update_cp_amm_launch_authority::*;
 pub use provide_to_cp_amm::*;
+pub use donate_to_cp_amm::*;
+pub use skim_cp_amm::*;
+pub use verify_cp_amm::*;
+pub use migrate_amms_configs_manager::*;
+pub use migrate_amms_config::*;
+pub use migrate_cp_amm::*;
 pub use withdraw_from_cp_amm::*;
 pub use swap_in_cp_amm::*;
-pub use collect_fees_from_cp_amm::*;
\ No newline at end of file
+pub use swap_v2::*;
+pub use collect_fees_from_cp_amm::*;
+pub use collect_creator_fees_from_cp_amm::*;
+pub use cancel_cp_amm::*;
+pub use lock_lp::*;
+pub use claim_unlocked_lp::*;
+pub use initialize_bonding_curve::*;
+pub use swap_in_bonding_curve::*;
\ No newline at end of file
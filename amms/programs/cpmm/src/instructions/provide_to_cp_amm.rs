@@ -3,7 +3,8 @@ use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token;
 use anchor_spl::token::Token;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
-use crate::state::{AmmsConfig, cp_amm::CpAmm};
+use crate::error::ErrorCode;
+use crate::state::{AmmsConfig, AmmsConfigsManager, cp_amm::CpAmm};
 use crate::utils::token_instructions::{MintTokensInstructions, TransferTokensInstruction};
 
 #[derive(Accounts)]
@@ -30,15 +31,29 @@ pub struct ProvideToCpAmm<'info>{
     )]
     pub signer_lp_account: Box<Account<'info, token::TokenAccount>>,
 
+    #[account(
+        mut,
+        token::mint = lp_mint,
+        token::token_program = lp_token_program
+    )]
+    // Receives the minted LP tokens instead of the signer's account, can be owned by any account or PDA
+    pub beneficiary_lp_account: Option<Box<Account<'info, token::TokenAccount>>>,
+
     #[account(
         seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
         bump = amms_config.bump()
     )]
     pub amms_config: Box<Account<'info, AmmsConfig>>,
 
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    pub amms_configs_manager: Box<Account<'info, AmmsConfigsManager>>,
+
     #[account(
         mut,
-        constraint = cp_amm.is_launched(),
+        constraint = cp_amm.status().is_launched(),
         constraint = amms_config.key() == cp_amm.amms_config().key(),
         constraint = lp_mint.key() == cp_amm.lp_mint,
         constraint = base_mint.key() == cp_amm.base_mint().key(),
@@ -64,6 +79,10 @@ pub struct ProvideToCpAmm<'info>{
     )]
     pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    #[account(mut)]
+    // Receives the protocol fee LP tokens, without it they stay accrued until a later liquidity event, token program will check mint via token_instructions instruction
+    pub fee_authority_lp_account: Option<Box<Account<'info, token::TokenAccount>>>,
+
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub lp_token_program: Program<'info, Token>,
     pub base_token_program: Interface<'info, TokenInterface>,
@@ -71,7 +90,13 @@ pub struct ProvideToCpAmm<'info>{
     pub system_program: Program<'info, System>,
 }
 
+/// `beneficiary_lp_account` receives the minted LP tokens instead of `signer_lp_account`, so a payer can fund a deposit on behalf of a beneficiary.
 pub(crate) fn handler(ctx: Context<ProvideToCpAmm>, base_liquidity: u64, quote_liquidity: u64) -> Result<()> {
+    require!(!ctx.accounts.amms_configs_manager.is_halted(), ErrorCode::AmmsConfigHalted);
+    require!(
+        ctx.accounts.cp_amm.is_open(Clock::get()?.unix_timestamp) || ctx.accounts.signer.key() == ctx.accounts.cp_amm.creator().key(),
+        ErrorCode::CpAmmNotOpen
+    );
 
     let provide_base_liquidity_instruction = Box::new(ctx.accounts.get_provide_base_liquidity_transfer_instruction(base_liquidity)?);
     let provide_quote_liquidity_instruction = Box::new(ctx.accounts.get_provide_quote_liquidity_transfer_instruction(quote_liquidity)?);
@@ -79,7 +104,12 @@ pub(crate) fn handler(ctx: Context<ProvideToCpAmm>, base_liquidity: u64, quote_l
     let base_liquidity_to_provide = provide_base_liquidity_instruction.get_amount_after_fee();
     let quote_liquidity_to_provide = provide_quote_liquidity_instruction.get_amount_after_fee();
 
-    let provide_payload = ctx.accounts.cp_amm.get_provide_payload(base_liquidity_to_provide, quote_liquidity_to_provide)?;
+    let provide_payload = ctx.accounts.cp_amm.get_provide_payload(
+        base_liquidity_to_provide,
+        quote_liquidity_to_provide,
+        ctx.accounts.amms_config.protocol_fee_mode(),
+        ctx.accounts.fee_authority_lp_account.is_some()
+    )?;
 
     provide_base_liquidity_instruction.execute(None)?;
     provide_quote_liquidity_instruction.execute(None)?;
@@ -90,6 +120,9 @@ pub(crate) fn handler(ctx: Context<ProvideToCpAmm>, base_liquidity: u64, quote_l
     let mint_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
 
     liquidity_mint_instruction.execute(Some(mint_instruction_seeds))?;
+    if provide_payload.protocol_fee_lp_tokens_to_mint() > 0 {
+        ctx.accounts.get_protocol_fee_liquidity_mint_instruction(provide_payload.protocol_fee_lp_tokens_to_mint())?.execute(Some(mint_instruction_seeds))?;
+    }
 
     ctx.accounts.cp_amm.provide(provide_payload);
 
@@ -122,8 +155,19 @@ impl<'info> ProvideToCpAmm<'info> {
             liquidity,
             &self.lp_mint,
             self.cp_amm.to_account_info(),
-            self.signer_lp_account.to_account_info(),
+            self.beneficiary_lp_account.as_ref().unwrap_or(&self.signer_lp_account).to_account_info(),
             &self.lp_token_program
         )
     }
+    fn get_protocol_fee_liquidity_mint_instruction(&self, protocol_fee_liquidity: u64) -> Result<MintTokensInstructions<'_, '_, '_, 'info>> {
+        let fee_authority_lp_account = self.fee_authority_lp_account.as_ref().ok_or(ErrorCode::InvalidFeeAuthorityLpAccount)?;
+        require!(fee_authority_lp_account.owner == self.amms_config.fee_authority().key(), ErrorCode::InvalidFeeAuthorityLpAccount);
+        Ok(MintTokensInstructions::new(
+            protocol_fee_liquidity,
+            &self.lp_mint,
+            self.cp_amm.to_account_info(),
+            fee_authority_lp_account.to_account_info(),
+            &self.lp_token_program
+        ))
+    }
 }
\ No newline at end of file
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::events::CpAmmReservesReconciled;
+use crate::state::{AmmsConfig, AmmsConfigsManager, cp_amm::CpAmm};
+use crate::utils::token_instructions::TransferTokensInstruction;
+
+#[derive(Accounts)]
+pub struct SkimCpAmm<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        constraint = (recipient.key() == amms_config.fee_authority().key() || signer.key() == amms_configs_manager.authority().key() || signer.key() == amms_configs_manager.head_authority().key())
+    )]
+    /// CHECK: Recipient of the surplus can be arbitrary type, only the fee authority unless the signer is a governance authority
+    pub recipient: AccountInfo<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = base_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = base_token_program
+    )]
+    pub recipient_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = quote_token_program
+    )]
+    pub recipient_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    pub amms_configs_manager: Box<Account<'info, AmmsConfigsManager>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        constraint = cp_amm.status().is_launched(),
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        constraint = base_mint.key() == cp_amm.base_mint().key(),
+        constraint = quote_mint.key() == cp_amm.quote_mint().key(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        constraint = cp_amm_quote_vault.key() == cp_amm.quote_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
+        bump = cp_amm.base_vault_bump()
+    )]
+    pub cp_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.quote_mint().as_ref()],
+        bump = cp_amm.quote_vault_bump()
+    )]
+    pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Anyone can skim the vaults surplus to the fee authority,
+/// the governance authorities can skim it to any recipient.
+pub(crate) fn handler(ctx: Context<SkimCpAmm>) -> Result<()> {
+    let reserves_discrepancy = ctx.accounts.cp_amm.get_reserves_discrepancy(ctx.accounts.cp_amm_base_vault.amount, ctx.accounts.cp_amm_quote_vault.amount)?;
+
+    let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
+    let skim_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+    if reserves_discrepancy.base_surplus() > 0 {
+        ctx.accounts.get_skim_base_transfer_instruction(reserves_discrepancy.base_surplus())?.execute(Some(skim_instruction_seeds))?;
+    }
+    if reserves_discrepancy.quote_surplus() > 0 {
+        ctx.accounts.get_skim_quote_transfer_instruction(reserves_discrepancy.quote_surplus())?.execute(Some(skim_instruction_seeds))?;
+    }
+
+    emit!(CpAmmReservesReconciled {
+        cp_amm: ctx.accounts.cp_amm.key(),
+        base_surplus: reserves_discrepancy.base_surplus(),
+        quote_surplus: reserves_discrepancy.quote_surplus(),
+        base_shortfall: reserves_discrepancy.base_shortfall(),
+        quote_shortfall: reserves_discrepancy.quote_shortfall(),
+        is_synced: false,
+    });
+    Ok(())
+}
+
+impl<'info> SkimCpAmm<'info> {
+    fn get_skim_base_transfer_instruction(&self, amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            amount,
+            &self.base_mint,
+            &self.cp_amm_base_vault,
+            self.cp_amm.to_account_info(),
+            &self.recipient_base_account,
+            &self.base_token_program
+        )
+    }
+    fn get_skim_quote_transfer_instruction(&self, amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            amount,
+            &self.quote_mint,
+            &self.cp_amm_quote_vault,
+            self.cp_amm.to_account_info(),
+            &self.recipient_quote_account,
+            &self.quote_token_program
+        )
+    }
+}
@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::error::ErrorCode;
+use crate::state::{AmmsConfig, AmmsConfigsManager, BondingCurve, BondingCurveSwapPayload, cp_amm::{CpAmm, PoolStatus}};
+use crate::utils::token_instructions::{MintTokensInstructions, TransferTokensInstruction};
+
+#[derive(Accounts)]
+pub struct SwapInBondingCurve<'info>{
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub lp_mint: Box<Account<'info, token::Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = base_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = base_token_program
+    )]
+    pub signer_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = quote_token_program
+    )]
+    pub signer_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    pub amms_configs_manager: Box<Account<'info, AmmsConfigsManager>>,
+
+    #[account(
+        mut,
+        constraint = cp_amm.status() == PoolStatus::BondingCurve,
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        constraint = lp_mint.key() == cp_amm.lp_mint,
+        constraint = base_mint.key() == cp_amm.base_mint().key(),
+        constraint = quote_mint.key() == cp_amm.quote_mint().key(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        constraint = cp_amm_quote_vault.key() == cp_amm.quote_vault().key(),
+        constraint = cp_amm_locked_lp_vault.key() == cp_amm.locked_lp_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED, cp_amm.key().as_ref()],
+        bump = bonding_curve.bump()
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
+        bump = cp_amm.base_vault_bump()
+    )]
+    pub cp_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.quote_mint().as_ref()],
+        bump = cp_amm.quote_vault_bump()
+    )]
+    pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.locked_lp_vault_bump()
+    )]
+    pub cp_amm_locked_lp_vault: Box<Account<'info, token::TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub lp_token_program: Program<'info, Token>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The bonding curve charges the protocol fee rate of `AmmsConfig` in quote tokens, redeemable as `CpAmm` protocol fees.
+/// The swap raising `graduation_quote_threshold` launches `CpAmm` with the real reserves and locks all its LP tokens.
+pub(crate) fn handler(ctx: Context<SwapInBondingCurve>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool) -> Result<()> {
+    require!(!ctx.accounts.amms_configs_manager.is_halted(), ErrorCode::AmmsConfigHalted);
+    require!(ctx.accounts.cp_amm.is_open(Clock::get()?.unix_timestamp), ErrorCode::CpAmmNotOpen);
+    require!(!ctx.accounts.cp_amm.is_swap_paused(), ErrorCode::CpAmmSwapPaused);
+    let in_transfer_instruction = Box::new(ctx.accounts.get_in_transfer_instruction(swap_amount, is_in_out)?);
+    let swap_payload = ctx.accounts.bonding_curve.get_swap_payload(
+        in_transfer_instruction.get_amount_after_fee(),
+        estimated_result,
+        allowed_slippage,
+        ctx.accounts.cp_amm.protocol_fee_rate_basis_points(&ctx.accounts.amms_config),
+        is_in_out
+    )?;
+
+    let out_transfer_instruction = Box::new(ctx.accounts.get_out_transfer_instruction(swap_payload.amount_to_withdraw(), is_in_out)?);
+    in_transfer_instruction.execute(None)?;
+    let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
+    let out_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+    out_transfer_instruction.execute(Some(out_instruction_seeds))?;
+
+    ctx.accounts.cp_amm.add_bonding_curve_fee(swap_payload.fee_amount())?;
+    ctx.accounts.bonding_curve.swap(&swap_payload);
+
+    if swap_payload.is_graduating() {
+        ctx.accounts.graduate(&swap_payload)?;
+    }
+
+    Ok(())
+}
+
+impl<'info> SwapInBondingCurve<'info>{
+    #[inline(never)]
+    fn graduate(&mut self, swap_payload: &BondingCurveSwapPayload) -> Result<()> {
+        let launch_payload = Box::new(self.cp_amm.get_launch_payload(
+            swap_payload.base_reserves(),
+            swap_payload.quote_reserves(),
+            self.amms_config.protocol_fee_mode()
+        )?);
+        let locked_liquidity = launch_payload.launch_liquidity().checked_add(launch_payload.initial_locked_liquidity()).ok_or(ErrorCode::LiquidityMintOverflow)?;
+
+        let cp_amm_seeds = self.cp_amm.seeds();
+        let mint_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+        self.get_locked_liquidity_mint_instruction(locked_liquidity).execute(Some(mint_instruction_seeds))?;
+
+        self.cp_amm.launch(*launch_payload, Clock::get()?.unix_timestamp, None);
+        Ok(())
+    }
+    fn get_locked_liquidity_mint_instruction(&self, locked_liquidity: u64) -> MintTokensInstructions<'_, '_, '_, 'info>{
+        MintTokensInstructions::new(
+            locked_liquidity,
+            &self.lp_mint,
+            self.cp_amm.to_account_info(),
+            self.cp_amm_locked_lp_vault.to_account_info(),
+            &self.lp_token_program
+        )
+    }
+    fn get_in_transfer_instruction(&self, in_amount: u64, is_in_out: bool) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        if is_in_out{
+            TransferTokensInstruction::try_new(
+                in_amount,
+                &self.base_mint,
+                &self.signer_base_account,
+                self.signer.to_account_info(),
+                &self.cp_amm_base_vault,
+                &self.base_token_program
+            )
+        }
+        else{
+            TransferTokensInstruction::try_new(
+                in_amount,
+                &self.quote_mint,
+                &self.signer_quote_account,
+                self.signer.to_account_info(),
+                &self.cp_amm_quote_vault,
+                &self.quote_token_program
+            )
+        }
+    }
+    fn get_out_transfer_instruction(&self, out_amount: u64, is_in_out: bool) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        if is_in_out{
+            TransferTokensInstruction::try_new(
+                out_amount,
+                &self.quote_mint,
+                &self.cp_amm_quote_vault,
+                self.cp_amm.to_account_info(),
+                &self.signer_quote_account,
+                &self.quote_token_program
+            )
+        }
+        else{
+            TransferTokensInstruction::try_new(
+                out_amount,
+                &self.base_mint,
+                &self.cp_amm_base_vault,
+                self.cp_amm.to_account_info(),
+                &self.signer_base_account,
+                &self.base_token_program
+            )
+        }
+    }
+}
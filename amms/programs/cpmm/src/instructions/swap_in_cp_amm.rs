@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
-use crate::state::{AmmsConfig, cp_amm::CpAmm};
+use crate::error::ErrorCode;
+use crate::state::{AmmsConfig, AmmsConfigsManager, cp_amm::CpAmm};
 use crate::utils::token_instructions::{TransferTokensInstruction};
 
 #[derive(Accounts)]
+#[instruction(swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool)]
 pub struct SwapInCpAmm<'info>{
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -35,9 +37,15 @@ pub struct SwapInCpAmm<'info>{
     )]
     pub amms_config: Box<Account<'info, AmmsConfig>>,
 
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    pub amms_configs_manager: Box<Account<'info, AmmsConfigsManager>>,
+
     #[account(
         mut,
-        constraint = cp_amm.is_launched(),
+        constraint = cp_amm.status().is_launched(),
         constraint = amms_config.key() == cp_amm.amms_config().key(),
         constraint = base_mint.key() == cp_amm.base_mint().key(),
         constraint = quote_mint.key() == cp_amm.quote_mint().key(),
@@ -62,6 +70,30 @@ pub struct SwapInCpAmm<'info>{
     )]
     pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    #[account(
+        mut,
+        constraint = referrer_account.mint == if is_in_out { base_mint.key() } else { quote_mint.key() } @ ErrorCode::InvalidReferrerAccount,
+        constraint = referrer_account.owner != signer.key() @ ErrorCode::InvalidReferrerAccount
+    )]
+    // Receives the referral share of the protocol fee in the swap input mint, can't be owned by the signer
+    pub referrer_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::token_program = base_token_program
+    )]
+    // Receives the swapped base tokens instead of the signer's account
+    pub recipient_base_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::token_program = quote_token_program
+    )]
+    // Receives the swapped quote tokens instead of the signer's account
+    pub recipient_quote_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub base_token_program: Interface<'info, TokenInterface>,
     pub quote_token_program: Interface<'info, TokenInterface>,
@@ -69,71 +101,121 @@ pub struct SwapInCpAmm<'info>{
 }
 
 pub(crate) fn handler(ctx: Context<SwapInCpAmm>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool) -> Result<()> {
-    let in_transfer_instruction = Box::new(ctx.accounts.get_in_transfer_instruction(swap_amount, is_in_out)?);
-    let swap_payload = ctx.accounts.cp_amm.get_swap_payload(
-        in_transfer_instruction.get_amount_after_fee(),
-        estimated_result,
-        allowed_slippage,
-        ctx.accounts.amms_config.providers_fee_rate_basis_points(),
-        ctx.accounts.amms_config.protocol_fee_rate_basis_points(),
-        is_in_out
-    )?;
-
-    let out_transfer_instruction = Box::new(ctx.accounts.get_out_transfer_instruction(swap_payload.amount_to_withdraw(), is_in_out)?);
-    in_transfer_instruction.execute(None)?;
-    let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
-    let out_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
-    out_transfer_instruction.execute(Some(out_instruction_seeds))?;
-
-    ctx.accounts.cp_amm.swap(swap_payload);
-
-    Ok(())
+    let accounts = &mut *ctx.accounts;
+    let swap_accounts = if is_in_out {
+        SwapAccounts {
+            signer: &accounts.signer,
+            amms_config: &accounts.amms_config,
+            amms_configs_manager: &accounts.amms_configs_manager,
+            cp_amm: &mut accounts.cp_amm,
+            input_mint: &accounts.base_mint,
+            source_account: &accounts.signer_base_account,
+            cp_amm_input_vault: &accounts.cp_amm_base_vault,
+            input_token_program: &accounts.base_token_program,
+            output_mint: &accounts.quote_mint,
+            destination_account: accounts.recipient_quote_account.as_deref().unwrap_or(&accounts.signer_quote_account),
+            cp_amm_output_vault: &accounts.cp_amm_quote_vault,
+            output_token_program: &accounts.quote_token_program,
+            referrer_account: accounts.referrer_account.as_deref(),
+        }
+    }
+    else {
+        SwapAccounts {
+            signer: &accounts.signer,
+            amms_config: &accounts.amms_config,
+            amms_configs_manager: &accounts.amms_configs_manager,
+            cp_amm: &mut accounts.cp_amm,
+            input_mint: &accounts.quote_mint,
+            source_account: &accounts.signer_quote_account,
+            cp_amm_input_vault: &accounts.cp_amm_quote_vault,
+            input_token_program: &accounts.quote_token_program,
+            output_mint: &accounts.base_mint,
+            destination_account: accounts.recipient_base_account.as_deref().unwrap_or(&accounts.signer_base_account),
+            cp_amm_output_vault: &accounts.cp_amm_base_vault,
+            output_token_program: &accounts.base_token_program,
+            referrer_account: accounts.referrer_account.as_deref(),
+        }
+    };
+    swap_accounts.swap(swap_amount, estimated_result, allowed_slippage, is_in_out)
 }
 
-impl<'info> SwapInCpAmm<'info>{
-    fn get_in_transfer_instruction(&self, in_amount: u64, is_in_out: bool) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
-        if is_in_out{
-            TransferTokensInstruction::try_new(
-                in_amount,
-                &self.base_mint,
-                &self.signer_base_account,
-                self.signer.to_account_info(),
-                &self.cp_amm_base_vault,
-                &self.base_token_program
-            )
-        }
-        else{
-            TransferTokensInstruction::try_new(
-                in_amount,
-                &self.quote_mint,
-                &self.signer_quote_account,
-                self.signer.to_account_info(),
-                &self.cp_amm_quote_vault,
-                &self.quote_token_program
-            )
+/// Accounts of a swap in `CpAmm`, resolved to its input and output sides by each swap instruction.
+pub(crate) struct SwapAccounts<'a, 'info> {
+    pub signer: &'a Signer<'info>,
+    pub amms_config: &'a AmmsConfig,
+    pub amms_configs_manager: &'a AmmsConfigsManager,
+    pub cp_amm: &'a mut Account<'info, CpAmm>,
+    pub input_mint: &'a InterfaceAccount<'info, Mint>,
+    pub source_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub cp_amm_input_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub input_token_program: &'a Interface<'info, TokenInterface>,
+    pub output_mint: &'a InterfaceAccount<'info, Mint>,
+    pub destination_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub cp_amm_output_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub output_token_program: &'a Interface<'info, TokenInterface>,
+    pub referrer_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+}
+
+impl<'info> SwapAccounts<'_, 'info> {
+    /// Swaps the input tokens from the source account for the output tokens sent to the destination account,
+    /// paying the referral fee in the input mint to the referrer account if present.
+    pub(crate) fn swap(self, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool) -> Result<()> {
+        require!(!self.amms_configs_manager.is_halted(), ErrorCode::AmmsConfigHalted);
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        require!(self.cp_amm.is_open(current_timestamp), ErrorCode::CpAmmNotOpen);
+
+        let in_transfer_instruction = Box::new(self.get_in_transfer_instruction(swap_amount)?);
+        let swap_payload = self.cp_amm.get_swap_payload(
+            in_transfer_instruction.get_amount_after_fee(),
+            estimated_result,
+            allowed_slippage,
+            self.cp_amm.fee_rates_at(self.amms_config, current_timestamp, self.referrer_account.is_some()),
+            is_in_out
+        )?;
+
+        let out_transfer_instruction = Box::new(self.get_out_transfer_instruction(swap_payload.amount_to_withdraw())?);
+        in_transfer_instruction.execute(None)?;
+        let cp_amm_seeds = self.cp_amm.seeds();
+        let out_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+        out_transfer_instruction.execute(Some(out_instruction_seeds))?;
+
+        if swap_payload.referral_fee_amount() > 0 {
+            self.get_referral_fee_transfer_instruction(swap_payload.referral_fee_amount())?.execute(Some(out_instruction_seeds))?;
         }
+
+        self.cp_amm.swap(swap_payload);
+
+        Ok(())
     }
-    fn get_out_transfer_instruction(&self, in_amount: u64, is_in_out: bool) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
-        if is_in_out{
-            TransferTokensInstruction::try_new(
-                in_amount,
-                &self.quote_mint,
-                &self.cp_amm_quote_vault,
-                self.cp_amm.to_account_info(),
-                &self.signer_quote_account,
-                &self.quote_token_program
-            )
 
-        }
-        else{
-            TransferTokensInstruction::try_new(
-                in_amount,
-                &self.base_mint,
-                &self.cp_amm_base_vault,
-                self.cp_amm.to_account_info(),
-                &self.signer_base_account,
-                &self.base_token_program
-            )
-        }
+    fn get_referral_fee_transfer_instruction(&self, referral_fee: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            referral_fee,
+            self.input_mint,
+            self.cp_amm_input_vault,
+            self.cp_amm.to_account_info(),
+            self.referrer_account.unwrap(),
+            self.input_token_program
+        )
     }
-}
\ No newline at end of file
+    fn get_in_transfer_instruction(&self, in_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            in_amount,
+            self.input_mint,
+            self.source_account,
+            self.signer.to_account_info(),
+            self.cp_amm_input_vault,
+            self.input_token_program
+        )
+    }
+    fn get_out_transfer_instruction(&self, out_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            out_amount,
+            self.output_mint,
+            self.cp_amm_output_vault,
+            self.cp_amm.to_account_info(),
+            self.destination_account,
+            self.output_token_program
+        )
+    }
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::error::ErrorCode;
+use crate::state::{AmmsConfig, AmmsConfigsManager, cp_amm::CpAmm};
+use super::swap_in_cp_amm::SwapAccounts;
+
+#[derive(Accounts)]
+pub struct SwapV2<'info>{
+    pub signer: Signer<'info>,
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub output_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub source_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    // Token program will check mint via token_instructions instruction, can be owned by anyone
+    pub destination_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    pub amms_configs_manager: Box<Account<'info, AmmsConfigsManager>>,
+
+    #[account(
+        mut,
+        constraint = cp_amm.status().is_launched(),
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        constraint = (
+            input_mint.key() == cp_amm.base_mint().key() && output_mint.key() == cp_amm.quote_mint().key() &&
+            cp_amm_input_vault.key() == cp_amm.base_vault().key() && cp_amm_output_vault.key() == cp_amm.quote_vault().key()
+        ) || (
+            input_mint.key() == cp_amm.quote_mint().key() && output_mint.key() == cp_amm.base_mint().key() &&
+            cp_amm_input_vault.key() == cp_amm.quote_vault().key() && cp_amm_output_vault.key() == cp_amm.base_vault().key()
+        ),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(mut)]
+    pub cp_amm_input_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub cp_amm_output_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = input_mint,
+        constraint = referrer_account.owner != signer.key() @ ErrorCode::InvalidReferrerAccount
+    )]
+    // Receives the referral share of the protocol fee in the input mint, can't be owned by the signer
+    pub referrer_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub input_token_program: Interface<'info, TokenInterface>,
+    pub output_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Same swap as `swap_in_cp_amm` with the minimal account set, the direction is taken from the input mint.
+pub(crate) fn handler(ctx: Context<SwapV2>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    let is_in_out = accounts.input_mint.key() == accounts.cp_amm.base_mint().key();
+    let swap_accounts = SwapAccounts {
+        signer: &accounts.signer,
+        amms_config: &accounts.amms_config,
+        amms_configs_manager: &accounts.amms_configs_manager,
+        cp_amm: &mut accounts.cp_amm,
+        input_mint: &accounts.input_mint,
+        source_account: &accounts.source_account,
+        cp_amm_input_vault: &accounts.cp_amm_input_vault,
+        input_token_program: &accounts.input_token_program,
+        output_mint: &accounts.output_mint,
+        destination_account: &accounts.destination_account,
+        cp_amm_output_vault: &accounts.cp_amm_output_vault,
+        output_token_program: &accounts.output_token_program,
+        referrer_account: accounts.referrer_account.as_deref(),
+    };
+    swap_accounts.swap(swap_amount, estimated_result, allowed_slippage, is_in_out)
+}
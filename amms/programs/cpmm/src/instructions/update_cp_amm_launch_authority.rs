@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use crate::state::cp_amm::CpAmm;
+
+#[derive(Accounts)]
+pub struct UpdateCpAmmLaunchAuthority<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = creator.key() == cp_amm.creator().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+}
+
+/// The creator can delegate the launch to a launch authority, e.g. a launchpad PDA or a bot key, until the AMM is launched.
+/// `None` revokes the launch authority.
+pub(crate) fn handler(ctx: Context<UpdateCpAmmLaunchAuthority>, launch_authority: Option<Pubkey>) -> Result<()> {
+    ctx.accounts.cp_amm.update_launch_authority(launch_authority)
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use anchor_spl::token_interface::TokenAccount as InterfaceTokenAccount;
+use crate::error::ErrorCode;
+use crate::state::cp_amm::{CpAmm, PoolStatus};
+
+#[derive(Accounts)]
+pub struct VerifyCpAmm<'info> {
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        constraint = lp_mint.key() == cp_amm.lp_mint,
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    // The vaults are required unless the CpAmm is closed, which closes them
+    #[account(address = cp_amm.base_vault().key())]
+    pub cp_amm_base_vault: Option<Box<InterfaceAccount<'info, InterfaceTokenAccount>>>,
+    #[account(address = cp_amm.quote_vault().key())]
+    pub cp_amm_quote_vault: Option<Box<InterfaceAccount<'info, InterfaceTokenAccount>>>,
+    #[account(address = cp_amm.locked_lp_vault().key())]
+    pub cp_amm_locked_lp_vault: Option<Box<Account<'info, TokenAccount>>>,
+}
+
+/// Permissionless health check, fails with the error of the first violated invariant of the `CpAmm`.
+pub(crate) fn handler(ctx: Context<VerifyCpAmm>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    if accounts.cp_amm.status() == PoolStatus::Closed {
+        return accounts.cp_amm.verify_closed(accounts.lp_mint.supply);
+    }
+    let (Some(cp_amm_base_vault), Some(cp_amm_quote_vault), Some(cp_amm_locked_lp_vault)) =
+        (&accounts.cp_amm_base_vault, &accounts.cp_amm_quote_vault, &accounts.cp_amm_locked_lp_vault) else {
+        return err!(ErrorCode::InvalidCpAmmVaultAddress);
+    };
+    accounts.cp_amm.verify(
+        cp_amm_base_vault.amount,
+        cp_amm_quote_vault.amount,
+        accounts.lp_mint.supply,
+        cp_amm_locked_lp_vault.amount
+    )
+}
@@ -3,8 +3,9 @@ use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token;
 use anchor_spl::token::Token;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::error::ErrorCode;
 use crate::state::{AmmsConfig, cp_amm::CpAmm};
-use crate::utils::token_instructions::{BurnTokensInstructions, TransferTokensInstruction};
+use crate::utils::token_instructions::{BurnTokensInstructions, MintTokensInstructions, TransferTokensInstruction};
 
 #[derive(Accounts)]
 pub struct WithdrawFromCpAmm<'info>{
@@ -37,6 +38,22 @@ pub struct WithdrawFromCpAmm<'info>{
     // Token program will check mint and authority via token_instructions instruction
     pub signer_lp_account: Box<Account<'info, token::TokenAccount>>,
 
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::token_program = base_token_program
+    )]
+    // Receives the withdrawn base liquidity instead of the signer's account
+    pub recipient_base_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::token_program = quote_token_program
+    )]
+    // Receives the withdrawn quote liquidity instead of the signer's account
+    pub recipient_quote_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     #[account(
         seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
         bump = amms_config.bump()
@@ -45,7 +62,7 @@ pub struct WithdrawFromCpAmm<'info>{
 
     #[account(
         mut,
-        constraint = cp_amm.is_launched(),
+        constraint = cp_amm.status().is_launched(),
         constraint = amms_config.key() == cp_amm.amms_config().key(),
         constraint = lp_mint.key() == cp_amm.lp_mint,
         constraint = base_mint.key() == cp_amm.base_mint().key(),
@@ -71,6 +88,10 @@ pub struct WithdrawFromCpAmm<'info>{
     )]
     pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    #[account(mut)]
+    // Receives the protocol fee LP tokens, without it they stay accrued until a later liquidity event, token program will check mint via token_instructions instruction
+    pub fee_authority_lp_account: Option<Box<Account<'info, token::TokenAccount>>>,
+
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub lp_token_program: Program<'info, Token>,
     pub base_token_program: Interface<'info, TokenInterface>,
@@ -81,7 +102,11 @@ pub struct WithdrawFromCpAmm<'info>{
 pub(crate) fn handler(ctx: Context<WithdrawFromCpAmm>, lp_tokens: u64) -> Result<()> {
     let liquidity_burn_instruction = Box::new(ctx.accounts.get_liquidity_burn_instruction(lp_tokens)?);
     
-    let withdraw_payload = ctx.accounts.cp_amm.get_withdraw_payload(lp_tokens)?;
+    let withdraw_payload = ctx.accounts.cp_amm.get_withdraw_payload(
+        lp_tokens,
+        ctx.accounts.amms_config.protocol_fee_mode(),
+        ctx.accounts.fee_authority_lp_account.is_some()
+    )?;
 
     let withdraw_base_liquidity_instruction = Box::new(ctx.accounts.get_withdraw_base_liquidity_transfer_instruction(withdraw_payload.base_withdraw_amount())?);
     let withdraw_quote_liquidity_instruction = Box::new(ctx.accounts.get_withdraw_quote_liquidity_transfer_instruction(withdraw_payload.quote_withdraw_amount())?);
@@ -93,7 +118,9 @@ pub(crate) fn handler(ctx: Context<WithdrawFromCpAmm>, lp_tokens: u64) -> Result
 
     withdraw_base_liquidity_instruction.execute(Some(withdraw_instruction_seeds))?;
     withdraw_quote_liquidity_instruction.execute(Some(withdraw_instruction_seeds))?;
-
+    if withdraw_payload.protocol_fee_lp_tokens_to_mint() > 0 {
+        ctx.accounts.get_protocol_fee_liquidity_mint_instruction(withdraw_payload.protocol_fee_lp_tokens_to_mint())?.execute(Some(withdraw_instruction_seeds))?;
+    }
 
     ctx.accounts.cp_amm.withdraw(withdraw_payload);
 
@@ -107,7 +134,7 @@ impl<'info> WithdrawFromCpAmm<'info>{
             &self.base_mint,
             &self.cp_amm_base_vault,
             self.cp_amm.to_account_info(),
-            &self.signer_base_account,
+            self.recipient_base_account.as_ref().unwrap_or(&self.signer_base_account),
             &self.base_token_program
         )
     }
@@ -117,7 +144,7 @@ impl<'info> WithdrawFromCpAmm<'info>{
             &self.quote_mint,
             &self.cp_amm_quote_vault,
             self.cp_amm.to_account_info(),
-            &self.signer_quote_account,
+            self.recipient_quote_account.as_ref().unwrap_or(&self.signer_quote_account),
             &self.quote_token_program
         )
     }
@@ -130,4 +157,15 @@ impl<'info> WithdrawFromCpAmm<'info>{
             &self.lp_token_program
         )
     }
+    fn get_protocol_fee_liquidity_mint_instruction(&self, protocol_fee_liquidity: u64) -> Result<MintTokensInstructions<'_, '_, '_, 'info>> {
+        let fee_authority_lp_account = self.fee_authority_lp_account.as_ref().ok_or(ErrorCode::InvalidFeeAuthorityLpAccount)?;
+        require!(fee_authority_lp_account.owner == self.amms_config.fee_authority().key(), ErrorCode::InvalidFeeAuthorityLpAccount);
+        Ok(MintTokensInstructions::new(
+            protocol_fee_liquidity,
+            &self.lp_mint,
+            self.cp_amm.to_account_info(),
+            fee_authority_lp_account.to_account_info(),
+            &self.lp_token_program
+        ))
+    }
 }
\ No newline at end of file
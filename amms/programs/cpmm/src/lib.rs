@@ -4,11 +4,13 @@ declare_id!("EFta43Um9GJB1ABsMh18srSEzsq1EbE3k9uU9aL3dsR3");
 
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
 
 pub use instructions::*;
+use state::{CreatorLpLockConfig, DonationRatioPolicy, FeeRecipient, LpLockSchedule, ProtocolFeeMode, cp_amm::LaunchFeeSchedule};
 
 #[program]
 pub mod cpmm {
@@ -26,6 +28,14 @@ pub mod cpmm {
         update_amms_configs_manager_head_authority::handler(ctx)
     }
 
+    pub fn update_amms_configs_manager_pauser(ctx: Context<UpdateAmmsConfigsManagerPauser>) -> Result<()>{
+        update_amms_configs_manager_pauser::handler(ctx)
+    }
+
+    pub fn update_amms_configs_manager_halt(ctx: Context<UpdateAmmsConfigsManagerHalt>, is_halted: bool) -> Result<()>{
+        update_amms_configs_manager_halt::handler(ctx, is_halted)
+    }
+
 
     pub fn initialize_amms_config(ctx: Context<InitializeAmmsConfig>, protocol_fee_rate_basis_points: u16, providers_fee_rate_basis_points: u16) -> Result<()>{
         initialize_amms_config::handler(ctx, protocol_fee_rate_basis_points, providers_fee_rate_basis_points)
@@ -43,23 +53,138 @@ pub mod cpmm {
         update_amms_config_protocol_fee_rate::handler(ctx, new_protocol_fee_rate_basis_points)
     }
 
+    pub fn update_amms_config_max_fee_rate(ctx: Context<UpdateAmmsConfigMaxFeeRate>, new_max_fee_rate_basis_points: u16) -> Result<()>{
+        update_amms_config_max_fee_rate::handler(ctx, new_max_fee_rate_basis_points)
+    }
+
+    pub fn update_amms_config_max_creator_fee_share(ctx: Context<UpdateAmmsConfigMaxCreatorFeeShare>, new_max_creator_fee_share_basis_points: u16) -> Result<()>{
+        update_amms_config_max_creator_fee_share::handler(ctx, new_max_creator_fee_share_basis_points)
+    }
+
+    pub fn update_amms_config_referral_fee_share(ctx: Context<UpdateAmmsConfigReferralFeeShare>, new_referral_fee_share_basis_points: u16) -> Result<()>{
+        update_amms_config_referral_fee_share::handler(ctx, new_referral_fee_share_basis_points)
+    }
+
+    pub fn update_amms_config_fee_recipients(ctx: Context<UpdateAmmsConfigFeeRecipients>, new_fee_recipients: Vec<FeeRecipient>) -> Result<()>{
+        update_amms_config_fee_recipients::handler(ctx, new_fee_recipients)
+    }
+
+    pub fn update_amms_config_protocol_fee_mode(ctx: Context<UpdateAmmsConfigProtocolFeeMode>, new_protocol_fee_mode: ProtocolFeeMode) -> Result<()>{
+        update_amms_config_protocol_fee_mode::handler(ctx, new_protocol_fee_mode)
+    }
+
+    pub fn update_amms_config_donation_ratio_policy(ctx: Context<UpdateAmmsConfigDonationRatioPolicy>, new_donation_ratio_policy: DonationRatioPolicy) -> Result<()>{
+        update_amms_config_donation_ratio_policy::handler(ctx, new_donation_ratio_policy)
+    }
+
+    pub fn sync_amms_config_halt(ctx: Context<SyncAmmsConfigHalt>) -> Result<()>{
+        sync_amms_config_halt::handler(ctx)
+    }
+
+    pub fn update_cp_amm_fee_rates_override(ctx: Context<UpdateCpAmmFeeRatesOverride>, providers_fee_rate_basis_points_override: Option<u16>, protocol_fee_rate_basis_points_override: Option<u16>) -> Result<()>{
+        update_cp_amm_fee_rates_override::handler(ctx, providers_fee_rate_basis_points_override, protocol_fee_rate_basis_points_override)
+    }
+
+    pub fn update_cp_amm_pause_flags(ctx: Context<UpdateCpAmmPauseFlags>, is_swap_paused: bool, is_provide_paused: bool, is_withdraw_paused: bool) -> Result<()>{
+        update_cp_amm_pause_flags::handler(ctx, is_swap_paused, is_provide_paused, is_withdraw_paused)
+    }
+
+    pub fn close_cp_amm(ctx: Context<CloseCpAmm>) -> Result<()>{
+        close_cp_amm::handler(ctx)
+    }
+
+    pub fn sync_cp_amm(ctx: Context<SyncCpAmm>) -> Result<()>{
+        sync_cp_amm::handler(ctx)
+    }
+
     
-    pub fn initialize_cp_amm(ctx: Context<InitializeCpAmm>) -> Result<()>{
-        initialize_cp_amm::handler(ctx)
+    pub fn initialize_cp_amm(ctx: Context<InitializeCpAmm>, creator_fee_share_basis_points: u16) -> Result<()>{
+        initialize_cp_amm::handler(ctx, creator_fee_share_basis_points)
     }
-    pub fn launch_cp_amm(ctx: Context<LaunchCpAmm>, base_liquidity: u64, quote_liquidity: u64) -> Result<()>{
-        launch_cp_amm::handler(ctx, base_liquidity, quote_liquidity)
+    pub fn initialize_and_launch_cp_amm(ctx: Context<InitializeAndLaunchCpAmm>, creator_fee_share_basis_points: u16, base_liquidity: u64, quote_liquidity: u64, open_time: i64, launch_fee_schedule: Option<LaunchFeeSchedule>, creator_lp_lock_config: Option<CreatorLpLockConfig>) -> Result<()>{
+        initialize_and_launch_cp_amm::handler(ctx, creator_fee_share_basis_points, base_liquidity, quote_liquidity, open_time, launch_fee_schedule, creator_lp_lock_config)
+    }
+    pub fn update_cp_amm_launch_authority(ctx: Context<UpdateCpAmmLaunchAuthority>, launch_authority: Option<Pubkey>) -> Result<()>{
+        update_cp_amm_launch_authority::handler(ctx, launch_authority)
+    }
+    pub fn launch_cp_amm(ctx: Context<LaunchCpAmm>, base_liquidity: u64, quote_liquidity: u64, open_time: i64, launch_fee_schedule: Option<LaunchFeeSchedule>, creator_lp_lock_config: Option<CreatorLpLockConfig>) -> Result<()>{
+        launch_cp_amm::handler(ctx, base_liquidity, quote_liquidity, open_time, launch_fee_schedule, creator_lp_lock_config)
     }
     pub fn provide_to_cp_amm(ctx: Context<ProvideToCpAmm>, base_liquidity: u64, quote_liquidity: u64) -> Result<()>{
         provide_to_cp_amm::handler(ctx, base_liquidity, quote_liquidity)
     }
+    pub fn donate_to_cp_amm(ctx: Context<DonateToCpAmm>, base_liquidity: u64, quote_liquidity: u64) -> Result<()>{
+        donate_to_cp_amm::handler(ctx, base_liquidity, quote_liquidity)
+    }
+    pub fn skim_cp_amm(ctx: Context<SkimCpAmm>) -> Result<()>{
+        skim_cp_amm::handler(ctx)
+    }
+    pub fn verify_cp_amm(ctx: Context<VerifyCpAmm>) -> Result<()>{
+        verify_cp_amm::handler(ctx)
+    }
+    pub fn migrate_amms_configs_manager(ctx: Context<MigrateAmmsConfigsManager>) -> Result<()>{
+        migrate_amms_configs_manager::handler(ctx)
+    }
+    pub fn migrate_amms_config(ctx: Context<MigrateAmmsConfig>) -> Result<()>{
+        migrate_amms_config::handler(ctx)
+    }
+    pub fn migrate_cp_amm(ctx: Context<MigrateCpAmm>) -> Result<()>{
+        migrate_cp_amm::handler(ctx)
+    }
     pub fn withdraw_from_cp_amm(ctx: Context<WithdrawFromCpAmm>, lp_tokens: u64) -> Result<()>{
         withdraw_from_cp_amm::handler(ctx, lp_tokens)
     }
     pub fn swap_in_cp_amm(ctx: Context<SwapInCpAmm>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool) -> Result<()>{
         swap_in_cp_amm::handler(ctx, swap_amount, estimated_result, allowed_slippage, is_in_out)
     }
-    pub fn collect_fees_from_cp_amm(ctx: Context<CollectFeesFromCpAmm>) -> Result<()>{
+    pub fn swap_v2(ctx: Context<SwapV2>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64) -> Result<()>{
+        swap_v2::handler(ctx, swap_amount, estimated_result, allowed_slippage)
+    }
+    pub fn collect_fees_from_cp_amm<'info>(ctx: Context<'_, '_, 'info, 'info, CollectFeesFromCpAmm<'info>>) -> Result<()>{
         collect_fees_from_cp_amm::handler(ctx)
     }
+    pub fn collect_creator_fees_from_cp_amm(ctx: Context<CollectCreatorFeesFromCpAmm>) -> Result<()>{
+        collect_creator_fees_from_cp_amm::handler(ctx)
+    }
+    pub fn cancel_cp_amm(ctx: Context<CancelCpAmm>) -> Result<()>{
+        cancel_cp_amm::handler(ctx)
+    }
+    pub fn lock_lp(ctx: Context<LockLp>, nonce: u64, amount: u64, beneficiary: Pubkey, schedule: LpLockSchedule) -> Result<()>{
+        lock_lp::handler(ctx, nonce, amount, beneficiary, schedule)
+    }
+    pub fn claim_unlocked_lp(ctx: Context<ClaimUnlockedLp>) -> Result<()>{
+        claim_unlocked_lp::handler(ctx)
+    }
+
+    pub fn initialize_bonding_curve(ctx: Context<InitializeBondingCurve>, base_amount: u64, virtual_base_reserves: u64, virtual_quote_reserves: u64, graduation_quote_threshold: u64) -> Result<()>{
+        initialize_bonding_curve::handler(ctx, base_amount, virtual_base_reserves, virtual_quote_reserves, graduation_quote_threshold)
+    }
+    pub fn swap_in_bonding_curve(ctx: Context<SwapInBondingCurve>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool) -> Result<()>{
+        swap_in_bonding_curve::handler(ctx, swap_amount, estimated_result, allowed_slippage, is_in_out)
+    }
+
+    pub fn initialize_farm(ctx: Context<InitializeFarm>) -> Result<()>{
+        initialize_farm::handler(ctx)
+    }
+    pub fn add_farm_reward(ctx: Context<AddFarmReward>, emission_rate_per_second: u64, start_timestamp: i64, end_timestamp: i64) -> Result<()>{
+        add_farm_reward::handler(ctx, emission_rate_per_second, start_timestamp, end_timestamp)
+    }
+    pub fn fund_farm_reward(ctx: Context<FundFarmReward>, reward_index: u8, amount: u64) -> Result<()>{
+        fund_farm_reward::handler(ctx, reward_index, amount)
+    }
+    pub fn extend_farm_reward(ctx: Context<ExtendFarmReward>, reward_index: u8, end_timestamp: i64, emission_rate_per_second: u64) -> Result<()>{
+        extend_farm_reward::handler(ctx, reward_index, end_timestamp, emission_rate_per_second)
+    }
+    pub fn reclaim_farm_reward(ctx: Context<ReclaimFarmReward>, reward_index: u8) -> Result<()>{
+        reclaim_farm_reward::handler(ctx, reward_index)
+    }
+    pub fn stake_lp(ctx: Context<StakeLp>, amount: u64) -> Result<()>{
+        stake_lp::handler(ctx, amount)
+    }
+    pub fn unstake_lp(ctx: Context<UnstakeLp>, amount: u64) -> Result<()>{
+        unstake_lp::handler(ctx, amount)
+    }
+    pub fn claim_farm_rewards(ctx: Context<ClaimFarmRewards>, reward_index: u8) -> Result<()>{
+        claim_farm_rewards::handler(ctx, reward_index)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,219 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::constants::ANCHOR_DISCRIMINATOR;
+use crate::error::ErrorCode;
+
+/// Represents the version of an account layout and the space reserved for its future fields.
+///
+/// It follows the fields of versioned accounts. Accounts created before versioning have the `LegacyLayout`
+/// of their type, which is detected by the account data length. They are read as the current type with
+/// the legacy version and written back in the legacy layout until they are migrated.
+/// Fields added later are carved out of the reserved space and placed after it as `VersionedField`s.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AccountVersion<const RESERVED: usize> {
+    /// The version of the account layout.
+    version: u8, // 1 byte
+
+    /// The space reserved for future fields.
+    reserved: [u8; RESERVED], // RESERVED bytes
+}
+
+impl<const RESERVED: usize> AccountVersion<RESERVED> {
+    /// The version implied by the legacy layout of accounts created before versioning.
+    pub const LEGACY_VERSION: u8 = 0;
+
+    /// The version of the current account layouts.
+    pub const CURRENT_VERSION: u8 = 1;
+
+    /// Creates the `AccountVersion` of a new account.
+    pub fn current() -> Self {
+        Self {
+            version: Self::CURRENT_VERSION,
+            reserved: [0; RESERVED],
+        }
+    }
+
+    /// Retrieves the version of the account layout.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl<const RESERVED: usize> Default for AccountVersion<RESERVED> {
+    fn default() -> Self {
+        Self {
+            version: Self::LEGACY_VERSION,
+            reserved: [0; RESERVED],
+        }
+    }
+}
+
+impl<const RESERVED: usize> Space for AccountVersion<RESERVED> {
+    const INIT_SPACE: usize = 1 + RESERVED;
+}
+
+/// Represents a field added to an account layout after versioning, carved out of the reserved space of its `AccountVersion`.
+///
+/// The reserved space of current accounts is zeroed, so the field must be encoded as zero bytes by default.
+#[derive(AnchorSerialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VersionedField<T: AnchorSerialize>(T);
+
+impl<T: AnchorSerialize> VersionedField<T> {
+    /// Creates the `VersionedField` with the provided value.
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Retrieves the value of the field.
+    #[inline]
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: AnchorSerialize + AnchorDeserialize> AnchorDeserialize for VersionedField<T> {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        T::deserialize_reader(reader).map(Self)
+    }
+}
+
+impl Space for VersionedField<Option<Pubkey>> {
+    const INIT_SPACE: usize = 1 + 32;
+}
+
+/// Represents the layout of an account type deployed before versioning.
+///
+/// Legacy accounts keep the discriminator of their type, but their fixed data length differs from the current one,
+/// since the fields added with versioning are inserted before its end. The layout is read from the raw account data
+/// and migrated to the current type, which is then written to the grown account.
+pub trait LegacyLayout: AnchorSerialize + AnchorDeserialize + Space {
+    /// The current type of the account.
+    type Current: AccountSerialize + Discriminator + Space;
+
+    /// Upgrades the legacy layout to the current type, filling the added fields with their defaults.
+    ///
+    /// # Parameters
+    /// - `current_timestamp`: Unix timestamp of the migration.
+    fn migrate(self, current_timestamp: i64) -> Self::Current;
+
+    /// Reads the legacy layout as the current type with the legacy version, without migrating the account.
+    ///
+    /// # Parameters
+    /// - `current_timestamp`: Unix timestamp of the read.
+    fn read(self, current_timestamp: i64) -> Self::Current;
+
+    /// Retrieves the legacy layout of a state read from a legacy account, to write it back to the account.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::AccountNotMigrated` if the state sets fields that the legacy layout doesn't have.
+    fn try_from_current(current: &Self::Current) -> Result<Self>;
+
+    /// Reads the legacy layout from the raw account data, including its discriminator.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::AccountAlreadyMigrated` if the data length matches the current layout.
+    /// - Returns `ErrorCode::InvalidLegacyAccount` if the data length or discriminator doesn't match the legacy layout.
+    fn try_from_account_data(data: &[u8]) -> Result<Self> {
+        require!(data.len() != ANCHOR_DISCRIMINATOR + Self::Current::INIT_SPACE, ErrorCode::AccountAlreadyMigrated);
+        require!(data.len() == ANCHOR_DISCRIMINATOR + Self::INIT_SPACE, ErrorCode::InvalidLegacyAccount);
+        require!(data[..ANCHOR_DISCRIMINATOR] == Self::Current::DISCRIMINATOR, ErrorCode::InvalidLegacyAccount);
+        Self::deserialize(&mut &data[ANCHOR_DISCRIMINATOR..]).map_err(|_| ErrorCode::InvalidLegacyAccount.into())
+    }
+}
+
+/// Represents an account type whose accounts may still have the `LegacyLayout` of the type.
+///
+/// Its `AccountSerialize` and `AccountDeserialize` implementations accept both layouts, so the instructions
+/// keep working with legacy accounts until the `migrate_*` instruction of the type grows them.
+pub trait VersionedAccount: AnchorSerialize + AnchorDeserialize + Discriminator + Space {
+    /// The layout of the type accounts created before versioning.
+    type Legacy: LegacyLayout<Current = Self>;
+
+    /// Checks if the state was read from an account with the legacy layout.
+    fn is_legacy(&self) -> bool;
+
+    /// Reads the account from its data, including its discriminator, in either layout.
+    ///
+    /// # Errors
+    /// - Returns an error if the discriminator doesn't match the type or the data doesn't match any layout.
+    fn try_deserialize_versioned(buf: &[u8]) -> Result<Self> {
+        if buf.len() < ANCHOR_DISCRIMINATOR {
+            return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into());
+        }
+        if buf[..ANCHOR_DISCRIMINATOR] != Self::DISCRIMINATOR {
+            return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        Self::try_deserialize_versioned_unchecked(buf)
+    }
+
+    /// Reads the account from its data, including its discriminator, in either layout without checking the discriminator.
+    /// The legacy layout is detected by the data length.
+    fn try_deserialize_versioned_unchecked(buf: &[u8]) -> Result<Self> {
+        let mut data = &buf[ANCHOR_DISCRIMINATOR..];
+        let account = if data.len() == Self::Legacy::INIT_SPACE {
+            let current_timestamp = Clock::get().map_or(0, |clock| clock.unix_timestamp);
+            Self::Legacy::deserialize(&mut data).map(|legacy| legacy.read(current_timestamp))
+        } else {
+            Self::deserialize(&mut data)
+        };
+        account.map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+
+    /// Writes the account with its discriminator, in the legacy layout if it was read from it.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::AccountNotMigrated` if a legacy account state doesn't fit the legacy layout.
+    /// - Returns `ErrorCode::AccountDidNotSerialize` if the writer is too short.
+    fn try_serialize_versioned<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+        if writer.write_all(&Self::DISCRIMINATOR).is_err() {
+            return Err(anchor_lang::error::ErrorCode::AccountDidNotSerialize.into());
+        }
+        let serialized = if self.is_legacy() {
+            Self::Legacy::try_from_current(self)?.serialize(writer)
+        } else {
+            self.serialize(writer)
+        };
+        serialized.map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize.into())
+    }
+}
+
+#[cfg(test)]
+mod account_version_tests {
+    use super::*;
+
+    /// Tests the layout of `AccountVersion`.
+    #[test]
+    fn test_account_version_layout() {
+        let data = [1u8, 7, 7, 7, 7];
+        let current = AccountVersion::<4>::deserialize(&mut data.as_ref()).unwrap();
+        assert_eq!(current.version(), AccountVersion::<4>::CURRENT_VERSION);
+        assert_eq!(current.reserved, [7; 4]);
+
+        let mut serialized = Vec::new();
+        current.serialize(&mut serialized).unwrap();
+        assert_eq!(serialized, data);
+        assert_eq!(AccountVersion::<4>::current().reserved, [0; 4]);
+
+        assert!(AccountVersion::<4>::deserialize(&mut [].as_ref()).is_err());
+        assert!(AccountVersion::<4>::deserialize(&mut [1u8, 0].as_ref()).is_err());
+    }
+
+    /// Tests reading the zeroed and set layouts of `VersionedField`.
+    #[test]
+    fn test_versioned_field_layouts() {
+        let zeroed = VersionedField::<Option<Pubkey>>::deserialize(&mut [0u8; 33].as_ref()).unwrap();
+        assert_eq!(*zeroed.get(), None);
+
+        let pubkey = Pubkey::new_unique();
+        let mut data = vec![1u8];
+        data.extend_from_slice(pubkey.as_ref());
+        let set = VersionedField::<Option<Pubkey>>::deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(*set.get(), Some(pubkey));
+
+        let mut serialized = Vec::new();
+        set.serialize(&mut serialized).unwrap();
+        assert_eq!(serialized, data);
+        assert_eq!(<VersionedField<Option<Pubkey>> as Space>::INIT_SPACE, 33);
+    }
+}
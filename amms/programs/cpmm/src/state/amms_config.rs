@@ -1,13 +1,14 @@
-use anchor_lang::{account, InitSpace};
+use anchor_lang::{Discriminator, InitSpace};
 use anchor_lang::prelude::*;
 use crate::error::ErrorCode;
+use crate::state::{AccountVersion, LegacyLayout, VersionedAccount};
 
 /// Represents a configuration object for managing fees and authorities in AMMs.
 ///
 /// This structure contains details such as fee rates, the authority responsible for fee collection,
 /// and a unique identifier to track the configuration within an `AmmsConfigsManager`.
-#[account]
-#[derive(InitSpace)]
+/// Configurations created before versioning are read and written as a `VersionedAccount` until migrated.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
 pub struct AmmsConfig {
     /// Canonical bump seed for the account's PDA.
     bump: u8,   // 1 byte
@@ -23,12 +24,178 @@ pub struct AmmsConfig {
 
     /// The protocol's fee rate, measured in basis points (1 basis point = 0.01%).
     protocol_fee_rate_basis_points: u16, // 2 bytes
+
+    /// The upper bound for the total fee rate of pools with overridden fee rates, measured in basis points.
+    max_fee_rate_basis_points: u16, // 2 bytes
+
+    /// The upper bound for the share of the protocol fee that pool creators may claim, measured in basis points.
+    max_creator_fee_share_basis_points: u16, // 2 bytes
+
+    /// The share of the protocol fee paid to swap referrers, measured in basis points.
+    referral_fee_share_basis_points: u16, // 2 bytes
+
+    /// The weighted recipients of collected protocol fees, the `fee_authority` receives all protocol fees if empty.
+    #[max_len(4)]
+    fee_recipients: Vec<FeeRecipient>, // 4 + 4 * 34 bytes
+
+    /// How the protocol fee of pools is accounted.
+    protocol_fee_mode: ProtocolFeeMode, // 1 byte
+
+    /// Whether the `AmmsConfigsManager` kill switch was on when this configuration was last synced.
+    /// Instructions check the manager itself, this copy is kept for clients reading the configuration.
+    is_halted: bool, // 1 byte
+
+    /// The `AmmsConfigsManager` halt epoch this configuration was last synced with.
+    halt_epoch: u64, // 8 bytes
+
+    /// Which donations to pools are accepted.
+    donation_ratio_policy: DonationRatioPolicy, // 1 byte
+
+    /// The version of the account layout and the space reserved for future fields.
+    version: AccountVersion<64>, // 1 + 64 bytes
+}
+
+/// Represents the layout of `AmmsConfig` accounts created before versioning.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LegacyAmmsConfig {
+    bump: u8,   // 1 byte
+    id: u64,    // 8 bytes
+    fee_authority: Pubkey,  // 32 bytes
+    providers_fee_rate_basis_points: u16, // 2 bytes
+    protocol_fee_rate_basis_points: u16, // 2 bytes
+}
+
+impl LegacyLayout for LegacyAmmsConfig {
+    type Current = AmmsConfig;
+
+    /// Keeps the fee rates and authority, the added fields get the defaults of a new configuration.
+    fn migrate(self, _current_timestamp: i64) -> AmmsConfig {
+        AmmsConfig {
+            bump: self.bump,
+            id: self.id,
+            fee_authority: self.fee_authority,
+            providers_fee_rate_basis_points: self.providers_fee_rate_basis_points,
+            protocol_fee_rate_basis_points: self.protocol_fee_rate_basis_points,
+            max_fee_rate_basis_points: 10000,
+            max_creator_fee_share_basis_points: 0,
+            referral_fee_share_basis_points: 0,
+            fee_recipients: Vec::new(),
+            protocol_fee_mode: ProtocolFeeMode::Accumulators,
+            is_halted: false,
+            halt_epoch: 0,
+            donation_ratio_policy: DonationRatioPolicy::Proportional,
+            version: AccountVersion::current(),
+        }
+    }
+
+    fn read(self, current_timestamp: i64) -> AmmsConfig {
+        AmmsConfig {
+            version: AccountVersion::default(),
+            ..self.migrate(current_timestamp)
+        }
+    }
+
+    fn try_from_current(amms_config: &AmmsConfig) -> Result<Self> {
+        require!(
+            amms_config.max_fee_rate_basis_points == 10000 &&
+                amms_config.max_creator_fee_share_basis_points == 0 &&
+                amms_config.referral_fee_share_basis_points == 0 &&
+                amms_config.fee_recipients.is_empty() &&
+                amms_config.protocol_fee_mode == ProtocolFeeMode::Accumulators &&
+                !amms_config.is_halted &&
+                amms_config.halt_epoch == 0 &&
+                amms_config.donation_ratio_policy == DonationRatioPolicy::Proportional,
+            ErrorCode::AccountNotMigrated
+        );
+        Ok(Self {
+            bump: amms_config.bump,
+            id: amms_config.id,
+            fee_authority: amms_config.fee_authority,
+            providers_fee_rate_basis_points: amms_config.providers_fee_rate_basis_points,
+            protocol_fee_rate_basis_points: amms_config.protocol_fee_rate_basis_points,
+        })
+    }
+}
+
+impl VersionedAccount for AmmsConfig {
+    type Legacy = LegacyAmmsConfig;
+
+    fn is_legacy(&self) -> bool {
+        self.version() == AccountVersion::<64>::LEGACY_VERSION
+    }
+}
+
+impl Discriminator for AmmsConfig {
+    /// The first 8 bytes of the SHA-256 hash of `account:AmmsConfig`, as `#[account]` would derive it.
+    const DISCRIMINATOR: [u8; 8] = [14, 184, 126, 68, 173, 213, 150, 0];
+}
+
+impl Owner for AmmsConfig {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
+}
+
+impl AccountSerialize for AmmsConfig {
+    fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+        self.try_serialize_versioned(writer)
+    }
+}
+
+impl AccountDeserialize for AmmsConfig {
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        Self::try_deserialize_versioned(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        Self::try_deserialize_versioned_unchecked(buf)
+    }
+}
+
+/// Represents how the protocol fee of pools is accounted.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProtocolFeeMode {
+    /// Protocol fees accumulate as base and quote tokens outside of the pool liquidity
+    /// and are sent out by `collect_fees_from_cp_amm`.
+    #[default]
+    Accumulators,
+
+    /// Protocol fees stay in the pool liquidity, and the protocol share of the constant product growth
+    /// is minted as LP tokens to the `fee_authority` on the next provide or withdraw.
+    LpTokens,
+}
+
+/// Represents which donations to pools are accepted.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DonationRatioPolicy {
+    /// Donations must contain both tokens in the current pool ratio, so they can't move the price.
+    #[default]
+    Proportional,
+
+    /// Donations may contain any amounts of either token and move the price.
+    AnyRatio,
+
+    /// Donations are rejected.
+    Disabled,
+}
+
+/// Represents a weighted recipient of collected protocol fees.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeRecipient {
+    /// The public key of the wallet owning the recipient's token accounts.
+    pub recipient: Pubkey, // 32 bytes
+
+    /// The weight of the recipient in the protocol fees distribution.
+    pub weight: u16, // 2 bytes
 }
 
 impl AmmsConfig {
     /// The seed used to derive the account's PDA.
     pub const SEED: &'static [u8] = b"amms_config";
 
+    /// The maximum number of protocol fee recipients.
+    pub const MAX_FEE_RECIPIENTS: usize = 4;
+
     /// Initializes the `AmmsConfig` with the provided parameters.
     ///
     /// # Parameters
@@ -38,6 +205,14 @@ impl AmmsConfig {
     /// - `id`: A unique identifier for this configuration.
     /// - `bump`: The bump seed for the account's PDA.
     ///
+    /// # Behavior
+    /// - Sets the initial `max_fee_rate_basis_points` to 10,000 (100%).
+    /// - Sets the initial `max_creator_fee_share_basis_points` to 0, disabling creator fees.
+    /// - Sets the initial `referral_fee_share_basis_points` to 0, disabling referral fees.
+    /// - Leaves `fee_recipients` empty, so all protocol fees go to the `fee_authority`.
+    /// - Sets the initial `protocol_fee_mode` to `ProtocolFeeMode::Accumulators`.
+    /// - Sets the initial `donation_ratio_policy` to `DonationRatioPolicy::Proportional`.
+    ///
     /// # Errors
     /// - Returns `ErrorCode::ConfigFeeRateExceeded` if the sum of `protocol_fee_rate_basis_points`
     ///   and `providers_fee_rate_basis_points` exceeds 10,000 (100%).
@@ -49,6 +224,13 @@ impl AmmsConfig {
         self.protocol_fee_rate_basis_points = protocol_fee_rate_basis_points;
         self.providers_fee_rate_basis_points = providers_fee_rate_basis_points;
         self.fee_authority = fee_authority;
+        self.max_fee_rate_basis_points = 10000;
+        self.max_creator_fee_share_basis_points = 0;
+        self.referral_fee_share_basis_points = 0;
+        self.fee_recipients = Vec::new();
+        self.protocol_fee_mode = ProtocolFeeMode::Accumulators;
+        self.donation_ratio_policy = DonationRatioPolicy::Proportional;
+        self.version = AccountVersion::current();
         
         Ok(())
     }
@@ -101,6 +283,113 @@ impl AmmsConfig {
         Ok(())
     }
 
+    /// Updates the upper bound for the total fee rate of pools with overridden fee rates.
+    ///
+    /// # Parameters
+    /// - `new_max_fee_rate_basis_points`: The updated maximum total fee rate, measured in basis points.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::ConfigFeeRateExceeded` if the maximum fee rate exceeds 100%.
+    pub(crate) fn update_max_fee_rate(&mut self, new_max_fee_rate_basis_points: u16) -> Result<()> {
+        require!(new_max_fee_rate_basis_points <= 10000, ErrorCode::ConfigFeeRateExceeded);
+        self.max_fee_rate_basis_points = new_max_fee_rate_basis_points;
+        Ok(())
+    }
+
+    /// Updates the upper bound for the share of the protocol fee that pool creators may claim.
+    ///
+    /// # Parameters
+    /// - `new_max_creator_fee_share_basis_points`: The updated maximum creator fee share, measured in basis points.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::ConfigFeeRateExceeded` if the maximum creator fee share exceeds 100%.
+    pub(crate) fn update_max_creator_fee_share(&mut self, new_max_creator_fee_share_basis_points: u16) -> Result<()> {
+        require!(new_max_creator_fee_share_basis_points <= 10000, ErrorCode::ConfigFeeRateExceeded);
+        self.max_creator_fee_share_basis_points = new_max_creator_fee_share_basis_points;
+        Ok(())
+    }
+
+    /// Updates the share of the protocol fee paid to swap referrers.
+    ///
+    /// # Parameters
+    /// - `new_referral_fee_share_basis_points`: The updated referral fee share, measured in basis points.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::ConfigFeeRateExceeded` if the referral fee share exceeds 100%.
+    pub(crate) fn update_referral_fee_share(&mut self, new_referral_fee_share_basis_points: u16) -> Result<()> {
+        require!(new_referral_fee_share_basis_points <= 10000, ErrorCode::ConfigFeeRateExceeded);
+        self.referral_fee_share_basis_points = new_referral_fee_share_basis_points;
+        Ok(())
+    }
+
+    /// Updates the weighted recipients of collected protocol fees.
+    ///
+    /// # Parameters
+    /// - `new_fee_recipients`: The updated list of recipients, an empty list restores collection to the `fee_authority`.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::InvalidFeeRecipients` if the list is longer than `MAX_FEE_RECIPIENTS`,
+    ///   contains a zero weight or contains duplicated recipients.
+    pub(crate) fn update_fee_recipients(&mut self, new_fee_recipients: Vec<FeeRecipient>) -> Result<()> {
+        require!(new_fee_recipients.len() <= Self::MAX_FEE_RECIPIENTS, ErrorCode::InvalidFeeRecipients);
+        for (index, fee_recipient) in new_fee_recipients.iter().enumerate() {
+            require!(fee_recipient.weight > 0, ErrorCode::InvalidFeeRecipients);
+            require!(new_fee_recipients[..index].iter().all(|other| other.recipient != fee_recipient.recipient), ErrorCode::InvalidFeeRecipients);
+        }
+        self.fee_recipients = new_fee_recipients;
+        Ok(())
+    }
+
+    /// Updates how the protocol fee of pools is accounted.
+    ///
+    /// # Parameters
+    /// - `new_protocol_fee_mode`: The updated protocol fee mode.
+    pub(crate) fn update_protocol_fee_mode(&mut self, new_protocol_fee_mode: ProtocolFeeMode) {
+        self.protocol_fee_mode = new_protocol_fee_mode;
+    }
+
+    /// Updates which donations to pools are accepted.
+    ///
+    /// # Parameters
+    /// - `new_donation_ratio_policy`: The updated donation ratio policy.
+    pub(crate) fn update_donation_ratio_policy(&mut self, new_donation_ratio_policy: DonationRatioPolicy) {
+        self.donation_ratio_policy = new_donation_ratio_policy;
+    }
+
+    /// Copies the `AmmsConfigsManager` kill switch state if it is newer than the synced one.
+    ///
+    /// # Parameters
+    /// - `is_halted`: Whether the manager's kill switch is on.
+    /// - `halt_epoch`: The manager's current halt epoch.
+    pub(crate) fn sync_halt(&mut self, is_halted: bool, halt_epoch: u64) {
+        if halt_epoch > self.halt_epoch {
+            self.is_halted = is_halted;
+            self.halt_epoch = halt_epoch;
+        }
+    }
+
+    /// Splits an amount of collected protocol fees between the fee recipients pro rata to their weights.
+    ///
+    /// Rounding dust is added to the first recipient's amount.
+    ///
+    /// # Parameters
+    /// - `amount`: The amount of protocol fees to distribute.
+    ///
+    /// # Returns
+    /// - A `Vec<u64>` with an amount for each of `fee_recipients` in the same order, empty if there are no recipients.
+    pub fn calculate_fee_recipients_amounts(&self, amount: u64) -> Vec<u64> {
+        let total_weight = self.fee_recipients.iter().map(|fee_recipient| fee_recipient.weight as u128).sum::<u128>();
+        if total_weight == 0 {
+            return Vec::new();
+        }
+        let mut amounts = self.fee_recipients.iter()
+            .map(|fee_recipient| (amount as u128 * fee_recipient.weight as u128 / total_weight) as u64)
+            .collect::<Vec<u64>>();
+        let dust = amount - amounts.iter().sum::<u64>();
+        amounts[0] += dust;
+        amounts
+    }
+
     /// Retrieves the public key of the current fee authority.
     ///
     /// # Returns
@@ -136,6 +425,81 @@ impl AmmsConfig {
     pub fn protocol_fee_rate_basis_points(&self) -> u16 {
         self.protocol_fee_rate_basis_points
     }
+
+    /// Retrieves the upper bound for the total fee rate of pools with overridden fee rates.
+    ///
+    /// # Returns
+    /// - The `u16` maximum total fee rate, measured in basis points.
+    #[inline]
+    pub fn max_fee_rate_basis_points(&self) -> u16 {
+        self.max_fee_rate_basis_points
+    }
+
+    /// Retrieves the upper bound for the share of the protocol fee that pool creators may claim.
+    ///
+    /// # Returns
+    /// - The `u16` maximum creator fee share, measured in basis points.
+    #[inline]
+    pub fn max_creator_fee_share_basis_points(&self) -> u16 {
+        self.max_creator_fee_share_basis_points
+    }
+
+    /// Retrieves the share of the protocol fee paid to swap referrers.
+    ///
+    /// # Returns
+    /// - The `u16` referral fee share, measured in basis points.
+    #[inline]
+    pub fn referral_fee_share_basis_points(&self) -> u16 {
+        self.referral_fee_share_basis_points
+    }
+
+    /// Retrieves the weighted recipients of collected protocol fees.
+    ///
+    /// # Returns
+    /// - A slice of `FeeRecipient`, empty if all protocol fees go to the `fee_authority`.
+    #[inline]
+    pub fn fee_recipients(&self) -> &[FeeRecipient] {
+        &self.fee_recipients
+    }
+
+    /// Retrieves how the protocol fee of pools is accounted.
+    ///
+    /// # Returns
+    /// - The `ProtocolFeeMode` of this configuration.
+    #[inline]
+    pub fn protocol_fee_mode(&self) -> ProtocolFeeMode {
+        self.protocol_fee_mode
+    }
+
+    /// Checks if the protocol-wide kill switch was on when this configuration was last synced.
+    #[inline]
+    pub fn is_halted(&self) -> bool {
+        self.is_halted
+    }
+
+    /// Retrieves the `AmmsConfigsManager` halt epoch this configuration was last synced with.
+    ///
+    /// # Returns
+    /// - The `u64` synced halt epoch.
+    #[inline]
+    pub fn halt_epoch(&self) -> u64 {
+        self.halt_epoch
+    }
+
+    /// Retrieves which donations to pools are accepted.
+    ///
+    /// # Returns
+    /// - The `DonationRatioPolicy` of this configuration.
+    #[inline]
+    pub fn donation_ratio_policy(&self) -> DonationRatioPolicy {
+        self.donation_ratio_policy
+    }
+
+    /// Retrieves the version of the account layout.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.version.version()
+    }
 }
 
 #[cfg(test)]
@@ -153,6 +517,15 @@ mod amms_config_tests {
             fee_authority: Pubkey::default(),
             providers_fee_rate_basis_points: 0,
             protocol_fee_rate_basis_points: 0,
+            max_fee_rate_basis_points: 0,
+            max_creator_fee_share_basis_points: 0,
+            referral_fee_share_basis_points: 0,
+            fee_recipients: Vec::new(),
+            protocol_fee_mode: ProtocolFeeMode::Accumulators,
+            is_halted: false,
+            halt_epoch: 0,
+            donation_ratio_policy: DonationRatioPolicy::Proportional,
+            version: AccountVersion::default(),
         };
 
         let fee_authority = Pubkey::new_unique();
@@ -168,12 +541,19 @@ mod amms_config_tests {
         assert_eq!(amms_config.fee_authority, fee_authority);
         assert_eq!(amms_config.protocol_fee_rate_basis_points, protocol_fee_rate_basis_points);
         assert_eq!(amms_config.providers_fee_rate_basis_points, providers_fee_rate_basis_points);
+        assert_eq!(amms_config.max_fee_rate_basis_points, 10000);
 
         assert_eq!(amms_config.bump(), bump);
         assert_eq!(amms_config.fee_authority().key(), fee_authority);
         assert_eq!(amms_config.protocol_fee_rate_basis_points(), protocol_fee_rate_basis_points);
         assert_eq!(amms_config.providers_fee_rate_basis_points(), providers_fee_rate_basis_points);
-
+        assert_eq!(amms_config.max_fee_rate_basis_points(), 10000);
+        assert_eq!(amms_config.max_creator_fee_share_basis_points(), 0);
+        assert_eq!(amms_config.referral_fee_share_basis_points(), 0);
+        assert!(amms_config.fee_recipients().is_empty());
+        assert_eq!(amms_config.protocol_fee_mode(), ProtocolFeeMode::Accumulators);
+        assert_eq!(amms_config.donation_ratio_policy(), DonationRatioPolicy::Proportional);
+        assert_eq!(amms_config.version(), AccountVersion::<64>::CURRENT_VERSION);
     }
 
 
@@ -186,6 +566,15 @@ mod amms_config_tests {
             fee_authority: Pubkey::default(),
             providers_fee_rate_basis_points: 300,
             protocol_fee_rate_basis_points: 200,
+            max_fee_rate_basis_points: 10000,
+            max_creator_fee_share_basis_points: 0,
+            referral_fee_share_basis_points: 0,
+            fee_recipients: Vec::new(),
+            protocol_fee_mode: ProtocolFeeMode::Accumulators,
+            is_halted: false,
+            halt_epoch: 0,
+            donation_ratio_policy: DonationRatioPolicy::Proportional,
+            version: AccountVersion::default(),
         };
 
         let new_fee_authority = Pubkey::new_unique();
@@ -202,6 +591,15 @@ mod amms_config_tests {
             fee_authority: Pubkey::default(),
             providers_fee_rate_basis_points: 300,
             protocol_fee_rate_basis_points: 200,
+            max_fee_rate_basis_points: 10000,
+            max_creator_fee_share_basis_points: 0,
+            referral_fee_share_basis_points: 0,
+            fee_recipients: Vec::new(),
+            protocol_fee_mode: ProtocolFeeMode::Accumulators,
+            is_halted: false,
+            halt_epoch: 0,
+            donation_ratio_policy: DonationRatioPolicy::Proportional,
+            version: AccountVersion::default(),
         };
 
         let new_providers_fee_rate = 234;
@@ -219,6 +617,15 @@ mod amms_config_tests {
             fee_authority: Pubkey::default(),
             providers_fee_rate_basis_points: 300,
             protocol_fee_rate_basis_points: 200,
+            max_fee_rate_basis_points: 10000,
+            max_creator_fee_share_basis_points: 0,
+            referral_fee_share_basis_points: 0,
+            fee_recipients: Vec::new(),
+            protocol_fee_mode: ProtocolFeeMode::Accumulators,
+            is_halted: false,
+            halt_epoch: 0,
+            donation_ratio_policy: DonationRatioPolicy::Proportional,
+            version: AccountVersion::default(),
         };
 
         let new_protocol_fee_rate = 234;
@@ -227,16 +634,255 @@ mod amms_config_tests {
         assert_eq!(amms_config.update_protocol_fee_rate(9701).ok(), None);
     }
 
+    /// Tests the `update_max_fee_rate` method of the `AmmsConfig` struct.
+    #[test]
+    fn test_amms_config_update_max_fee_rate() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 300,
+            protocol_fee_rate_basis_points: 200,
+            max_fee_rate_basis_points: 10000,
+            max_creator_fee_share_basis_points: 0,
+            referral_fee_share_basis_points: 0,
+            fee_recipients: Vec::new(),
+            protocol_fee_mode: ProtocolFeeMode::Accumulators,
+            is_halted: false,
+            halt_epoch: 0,
+            donation_ratio_policy: DonationRatioPolicy::Proportional,
+            version: AccountVersion::default(),
+        };
+
+        let new_max_fee_rate = 1500;
+        amms_config.update_max_fee_rate(new_max_fee_rate).unwrap();
+        assert_eq!(amms_config.max_fee_rate_basis_points, new_max_fee_rate);
+        assert_eq!(amms_config.update_max_fee_rate(10001).ok(), None);
+    }
+
+    /// Tests the `update_max_creator_fee_share` method of the `AmmsConfig` struct.
+    #[test]
+    fn test_amms_config_update_max_creator_fee_share() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 300,
+            protocol_fee_rate_basis_points: 200,
+            max_fee_rate_basis_points: 10000,
+            max_creator_fee_share_basis_points: 0,
+            referral_fee_share_basis_points: 0,
+            fee_recipients: Vec::new(),
+            protocol_fee_mode: ProtocolFeeMode::Accumulators,
+            is_halted: false,
+            halt_epoch: 0,
+            donation_ratio_policy: DonationRatioPolicy::Proportional,
+            version: AccountVersion::default(),
+        };
+
+        let new_max_creator_fee_share = 2500;
+        amms_config.update_max_creator_fee_share(new_max_creator_fee_share).unwrap();
+        assert_eq!(amms_config.max_creator_fee_share_basis_points, new_max_creator_fee_share);
+        assert_eq!(amms_config.update_max_creator_fee_share(10001).ok(), None);
+    }
+
+    /// Tests the `update_referral_fee_share` method of the `AmmsConfig` struct.
+    #[test]
+    fn test_amms_config_update_referral_fee_share() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 300,
+            protocol_fee_rate_basis_points: 200,
+            max_fee_rate_basis_points: 10000,
+            max_creator_fee_share_basis_points: 0,
+            referral_fee_share_basis_points: 0,
+            fee_recipients: Vec::new(),
+            protocol_fee_mode: ProtocolFeeMode::Accumulators,
+            is_halted: false,
+            halt_epoch: 0,
+            donation_ratio_policy: DonationRatioPolicy::Proportional,
+            version: AccountVersion::default(),
+        };
+
+        let new_referral_fee_share = 3000;
+        amms_config.update_referral_fee_share(new_referral_fee_share).unwrap();
+        assert_eq!(amms_config.referral_fee_share_basis_points, new_referral_fee_share);
+        assert_eq!(amms_config.update_referral_fee_share(10001).ok(), None);
+    }
+
+    /// Tests the `update_fee_recipients` method of the `AmmsConfig` struct.
+    #[test]
+    fn test_amms_config_update_fee_recipients() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 300,
+            protocol_fee_rate_basis_points: 200,
+            max_fee_rate_basis_points: 10000,
+            max_creator_fee_share_basis_points: 0,
+            referral_fee_share_basis_points: 0,
+            fee_recipients: Vec::new(),
+            protocol_fee_mode: ProtocolFeeMode::Accumulators,
+            is_halted: false,
+            halt_epoch: 0,
+            donation_ratio_policy: DonationRatioPolicy::Proportional,
+            version: AccountVersion::default(),
+        };
+        let treasury = FeeRecipient { recipient: Pubkey::new_unique(), weight: 5 };
+        let insurance = FeeRecipient { recipient: Pubkey::new_unique(), weight: 3 };
+
+        amms_config.update_fee_recipients(vec![treasury, insurance]).unwrap();
+        assert_eq!(amms_config.fee_recipients, vec![treasury, insurance]);
+
+        assert!(amms_config.update_fee_recipients(vec![treasury, treasury]).is_err());
+        assert!(amms_config.update_fee_recipients(vec![FeeRecipient { recipient: Pubkey::new_unique(), weight: 0 }]).is_err());
+        assert!(amms_config.update_fee_recipients((0..5).map(|_| FeeRecipient { recipient: Pubkey::new_unique(), weight: 1 }).collect()).is_err());
+        assert_eq!(amms_config.fee_recipients, vec![treasury, insurance]);
+
+        amms_config.update_fee_recipients(Vec::new()).unwrap();
+        assert!(amms_config.fee_recipients.is_empty());
+    }
+
+    /// Tests the `update_protocol_fee_mode` method of the `AmmsConfig` struct.
+    #[test]
+    fn test_amms_config_update_protocol_fee_mode() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 300,
+            protocol_fee_rate_basis_points: 200,
+            max_fee_rate_basis_points: 10000,
+            max_creator_fee_share_basis_points: 0,
+            referral_fee_share_basis_points: 0,
+            fee_recipients: Vec::new(),
+            protocol_fee_mode: ProtocolFeeMode::Accumulators,
+            is_halted: false,
+            halt_epoch: 0,
+            donation_ratio_policy: DonationRatioPolicy::Proportional,
+            version: AccountVersion::default(),
+        };
+
+        amms_config.update_protocol_fee_mode(ProtocolFeeMode::LpTokens);
+        assert_eq!(amms_config.protocol_fee_mode, ProtocolFeeMode::LpTokens);
+    }
+
+    /// Tests the `update_donation_ratio_policy` method of the `AmmsConfig` struct.
+    #[test]
+    fn test_amms_config_update_donation_ratio_policy() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 300,
+            protocol_fee_rate_basis_points: 200,
+            max_fee_rate_basis_points: 10000,
+            max_creator_fee_share_basis_points: 0,
+            referral_fee_share_basis_points: 0,
+            fee_recipients: Vec::new(),
+            protocol_fee_mode: ProtocolFeeMode::Accumulators,
+            is_halted: false,
+            halt_epoch: 0,
+            donation_ratio_policy: DonationRatioPolicy::Proportional,
+            version: AccountVersion::default(),
+        };
+
+        amms_config.update_donation_ratio_policy(DonationRatioPolicy::Disabled);
+        assert_eq!(amms_config.donation_ratio_policy, DonationRatioPolicy::Disabled);
+        assert_eq!(amms_config.donation_ratio_policy(), DonationRatioPolicy::Disabled);
+    }
+
+    /// Tests the `sync_halt` method of the `AmmsConfig` struct.
+    #[test]
+    fn test_amms_config_sync_halt() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 300,
+            protocol_fee_rate_basis_points: 200,
+            max_fee_rate_basis_points: 10000,
+            max_creator_fee_share_basis_points: 0,
+            referral_fee_share_basis_points: 0,
+            fee_recipients: Vec::new(),
+            protocol_fee_mode: ProtocolFeeMode::Accumulators,
+            is_halted: false,
+            halt_epoch: 0,
+            donation_ratio_policy: DonationRatioPolicy::Proportional,
+            version: AccountVersion::default(),
+        };
+
+        amms_config.sync_halt(true, 1);
+        assert!(amms_config.is_halted());
+        assert_eq!(amms_config.halt_epoch(), 1);
+
+        // A stale epoch is ignored
+        amms_config.sync_halt(false, 1);
+        assert!(amms_config.is_halted());
+
+        amms_config.sync_halt(false, 2);
+        assert!(!amms_config.is_halted());
+        assert_eq!(amms_config.halt_epoch(), 2);
+    }
+
+    /// Tests the `calculate_fee_recipients_amounts` method of the `AmmsConfig` struct.
+    #[test]
+    fn test_amms_config_calculate_fee_recipients_amounts() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 300,
+            protocol_fee_rate_basis_points: 200,
+            max_fee_rate_basis_points: 10000,
+            max_creator_fee_share_basis_points: 0,
+            referral_fee_share_basis_points: 0,
+            fee_recipients: Vec::new(),
+            protocol_fee_mode: ProtocolFeeMode::Accumulators,
+            is_halted: false,
+            halt_epoch: 0,
+            donation_ratio_policy: DonationRatioPolicy::Proportional,
+            version: AccountVersion::default(),
+        };
+        assert!(amms_config.calculate_fee_recipients_amounts(1000).is_empty());
+
+        amms_config.fee_recipients = vec![
+            FeeRecipient { recipient: Pubkey::new_unique(), weight: 1 },
+            FeeRecipient { recipient: Pubkey::new_unique(), weight: 1 },
+            FeeRecipient { recipient: Pubkey::new_unique(), weight: 1 },
+        ];
+        assert_eq!(amms_config.calculate_fee_recipients_amounts(1000), vec![334, 333, 333]);
+        assert_eq!(amms_config.calculate_fee_recipients_amounts(0), vec![0, 0, 0]);
+        assert_eq!(amms_config.calculate_fee_recipients_amounts(u64::MAX).iter().map(|amount| *amount as u128).sum::<u128>(), u64::MAX as u128);
+
+        amms_config.fee_recipients[1].weight = 2;
+        assert_eq!(amms_config.calculate_fee_recipients_amounts(100), vec![25, 50, 25]);
+    }
+
     /// Tests `AmmsConfig` account data layout.
     #[test]
     fn test_amms_config_data_layout() {
+        assert_eq!(AmmsConfig::DISCRIMINATOR, anchor_lang::solana_program::hash::hash(b"account:AmmsConfig").to_bytes()[..8]);
+
         let fee_authority = Pubkey::new_unique();
         let bump = 42u8;
         let id = 42u64;
         let providers_fee_rate_basis_points: u16 = 200;
         let protocol_fee_rate_basis_points: u16 = 300;
+        let max_fee_rate_basis_points: u16 = 1000;
+        let max_creator_fee_share_basis_points: u16 = 2500;
+        let referral_fee_share_basis_points: u16 = 1500;
+        let fee_recipients = (0..AmmsConfig::MAX_FEE_RECIPIENTS as u16).map(|weight| FeeRecipient { recipient: Pubkey::new_unique(), weight: weight + 1 }).collect::<Vec<FeeRecipient>>();
 
-        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 45];
+        let protocol_fee_mode = ProtocolFeeMode::LpTokens;
+        let is_halted = true;
+        let halt_epoch = 7u64;
+        let donation_ratio_policy = DonationRatioPolicy::AnyRatio;
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 267];
         let mut offset = 0;
 
         data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&AmmsConfig::discriminator()); offset += ANCHOR_DISCRIMINATOR;
@@ -245,9 +891,23 @@ mod amms_config_tests {
         data[offset..offset + 32].copy_from_slice(fee_authority.as_ref()); offset += 32;
         data[offset..offset + 2].copy_from_slice(&providers_fee_rate_basis_points.to_le_bytes()); offset += 2;
         data[offset..offset + 2].copy_from_slice(&protocol_fee_rate_basis_points.to_le_bytes()); offset += 2;
+        data[offset..offset + 2].copy_from_slice(&max_fee_rate_basis_points.to_le_bytes()); offset += 2;
+        data[offset..offset + 2].copy_from_slice(&max_creator_fee_share_basis_points.to_le_bytes()); offset += 2;
+        data[offset..offset + 2].copy_from_slice(&referral_fee_share_basis_points.to_le_bytes()); offset += 2;
+        data[offset..offset + 4].copy_from_slice(&(fee_recipients.len() as u32).to_le_bytes()); offset += 4;
+        for fee_recipient in fee_recipients.iter() {
+            data[offset..offset + 32].copy_from_slice(fee_recipient.recipient.as_ref()); offset += 32;
+            data[offset..offset + 2].copy_from_slice(&fee_recipient.weight.to_le_bytes()); offset += 2;
+        }
+        data[offset] = protocol_fee_mode as u8; offset += 1;
+        data[offset] = is_halted as u8; offset += 1;
+        data[offset..offset + 8].copy_from_slice(&halt_epoch.to_le_bytes()); offset += 8;
+        data[offset] = donation_ratio_policy as u8; offset += 1;
+        data[offset] = AccountVersion::<64>::CURRENT_VERSION; offset += 1;
+        offset += 64;
 
         assert_eq!(ANCHOR_DISCRIMINATOR + AmmsConfig::INIT_SPACE, offset);
-        
+
         let deserialized_amms_config = AmmsConfig::try_deserialize(&mut data.as_slice()).unwrap();
 
         assert_eq!(deserialized_amms_config.bump, bump);
@@ -255,9 +915,93 @@ mod amms_config_tests {
         assert_eq!(deserialized_amms_config.fee_authority, fee_authority);
         assert_eq!(deserialized_amms_config.providers_fee_rate_basis_points, providers_fee_rate_basis_points);
         assert_eq!(deserialized_amms_config.protocol_fee_rate_basis_points, protocol_fee_rate_basis_points);
+        assert_eq!(deserialized_amms_config.max_fee_rate_basis_points, max_fee_rate_basis_points);
+        assert_eq!(deserialized_amms_config.max_creator_fee_share_basis_points, max_creator_fee_share_basis_points);
+        assert_eq!(deserialized_amms_config.referral_fee_share_basis_points, referral_fee_share_basis_points);
+        assert_eq!(deserialized_amms_config.fee_recipients, fee_recipients);
+        assert_eq!(deserialized_amms_config.protocol_fee_mode, protocol_fee_mode);
+        assert_eq!(deserialized_amms_config.is_halted, is_halted);
+        assert_eq!(deserialized_amms_config.halt_epoch, halt_epoch);
+        assert_eq!(deserialized_amms_config.donation_ratio_policy, donation_ratio_policy);
+        assert_eq!(deserialized_amms_config.version(), AccountVersion::<64>::CURRENT_VERSION);
 
         let mut serialized_amms_config = Vec::new();
         deserialized_amms_config.try_serialize(&mut serialized_amms_config).unwrap();
         assert_eq!(serialized_amms_config.as_slice(), data.as_ref());
+
+        // Without fee recipients the version moves forward, and the rest of the account stays zero-padded
+        let mut padded_data = [0u8; ANCHOR_DISCRIMINATOR + 267];
+        AmmsConfig { fee_recipients: Vec::new(), ..deserialized_amms_config }.try_serialize(&mut padded_data.as_mut_slice()).unwrap();
+        let padded_amms_config = AmmsConfig::try_deserialize(&mut padded_data.as_ref()).unwrap();
+        assert!(padded_amms_config.fee_recipients.is_empty());
+        assert_eq!(padded_amms_config.donation_ratio_policy, donation_ratio_policy);
+        assert_eq!(padded_amms_config.version(), AccountVersion::<64>::CURRENT_VERSION);
+    }
+
+    /// Tests reading and migrating the `AmmsConfig` layout deployed before versioning.
+    #[test]
+    fn test_legacy_amms_config_migration() {
+        let fee_authority = Pubkey::new_unique();
+        let bump = 42u8;
+        let id = 42u64;
+        let providers_fee_rate_basis_points: u16 = 200;
+        let protocol_fee_rate_basis_points: u16 = 300;
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 45];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&AmmsConfig::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset] = bump; offset += 1;
+        data[offset..offset + 8].copy_from_slice(&id.to_le_bytes()); offset += 8;
+        data[offset..offset + 32].copy_from_slice(fee_authority.as_ref()); offset += 32;
+        data[offset..offset + 2].copy_from_slice(&providers_fee_rate_basis_points.to_le_bytes()); offset += 2;
+        data[offset..offset + 2].copy_from_slice(&protocol_fee_rate_basis_points.to_le_bytes()); offset += 2;
+
+        assert_eq!(ANCHOR_DISCRIMINATOR + LegacyAmmsConfig::INIT_SPACE, offset);
+
+        let mut legacy_amms_config = AmmsConfig::try_deserialize(&mut data.as_ref()).unwrap();
+        assert!(legacy_amms_config.is_legacy());
+        assert_eq!(legacy_amms_config.version(), AccountVersion::<64>::LEGACY_VERSION);
+        assert_eq!(legacy_amms_config.fee_authority, fee_authority);
+        assert_eq!(legacy_amms_config.providers_fee_rate_basis_points, providers_fee_rate_basis_points);
+        assert_eq!(legacy_amms_config.protocol_fee_rate_basis_points, protocol_fee_rate_basis_points);
+
+        let mut legacy_data = [0u8; ANCHOR_DISCRIMINATOR + 45];
+        legacy_amms_config.update_protocol_fee_rate(protocol_fee_rate_basis_points + 1).unwrap();
+        legacy_amms_config.try_serialize(&mut legacy_data.as_mut_slice()).unwrap();
+        assert_eq!(AmmsConfig::try_deserialize(&mut legacy_data.as_ref()).unwrap().protocol_fee_rate_basis_points, protocol_fee_rate_basis_points + 1);
+        legacy_amms_config.update_protocol_fee_rate(protocol_fee_rate_basis_points).unwrap();
+        legacy_amms_config.try_serialize(&mut legacy_data.as_mut_slice()).unwrap();
+        assert_eq!(legacy_data, data);
+
+        legacy_amms_config.donation_ratio_policy = DonationRatioPolicy::Disabled;
+        assert_eq!(legacy_amms_config.try_serialize(&mut legacy_data.as_mut_slice()).unwrap_err(), ErrorCode::AccountNotMigrated.into());
+
+        let migrated_amms_config = LegacyAmmsConfig::try_from_account_data(&data).unwrap().migrate(0);
+
+        assert_eq!(migrated_amms_config.bump, bump);
+        assert_eq!(migrated_amms_config.id, id);
+        assert_eq!(migrated_amms_config.fee_authority, fee_authority);
+        assert_eq!(migrated_amms_config.providers_fee_rate_basis_points, providers_fee_rate_basis_points);
+        assert_eq!(migrated_amms_config.protocol_fee_rate_basis_points, protocol_fee_rate_basis_points);
+        assert_eq!(migrated_amms_config.max_fee_rate_basis_points, 10000);
+        assert_eq!(migrated_amms_config.max_creator_fee_share_basis_points, 0);
+        assert_eq!(migrated_amms_config.referral_fee_share_basis_points, 0);
+        assert!(migrated_amms_config.fee_recipients.is_empty());
+        assert_eq!(migrated_amms_config.protocol_fee_mode, ProtocolFeeMode::Accumulators);
+        assert!(!migrated_amms_config.is_halted);
+        assert_eq!(migrated_amms_config.halt_epoch, 0);
+        assert_eq!(migrated_amms_config.donation_ratio_policy, DonationRatioPolicy::Proportional);
+        assert_eq!(migrated_amms_config.version(), AccountVersion::<64>::CURRENT_VERSION);
+
+        let mut migrated_data = [0u8; ANCHOR_DISCRIMINATOR + AmmsConfig::INIT_SPACE];
+        migrated_amms_config.try_serialize(&mut migrated_data.as_mut_slice()).unwrap();
+        let deserialized_amms_config = AmmsConfig::try_deserialize(&mut migrated_data.as_ref()).unwrap();
+        assert_eq!(deserialized_amms_config.fee_authority, fee_authority);
+        assert_eq!(LegacyAmmsConfig::try_from_account_data(&migrated_data).unwrap_err(), ErrorCode::AccountAlreadyMigrated.into());
+
+        assert_eq!(LegacyAmmsConfig::try_from_account_data(&data[..offset - 1]).unwrap_err(), ErrorCode::InvalidLegacyAccount.into());
+        data[0] ^= 1;
+        assert_eq!(LegacyAmmsConfig::try_from_account_data(&data).unwrap_err(), ErrorCode::InvalidLegacyAccount.into());
     }
 }
\ No newline at end of file
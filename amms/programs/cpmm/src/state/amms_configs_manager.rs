@@ -1,13 +1,15 @@
-use anchor_lang::{account, InitSpace};
+use anchor_lang::{Discriminator, InitSpace};
 use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+use crate::state::{AccountVersion, LegacyLayout, VersionedAccount};
 
 /// Represents the manager for AMM configurations.
 ///
 /// This account manages multiple AMM configurations and tracks the authority
 /// responsible for their governance. It also maintains a count of configurations
 /// and provides utility methods for updating key parameters.
-#[account]
-#[derive(InitSpace)]
+/// The manager created before versioning is read and written as a `VersionedAccount` until migrated.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
 pub struct AmmsConfigsManager {
     /// The public key of the authority responsible for managing configurations.
     authority: Pubkey,  // 32 bytes
@@ -20,6 +22,100 @@ pub struct AmmsConfigsManager {
 
     /// The canonical bump seed used for the account's PDA.
     bump: u8,   // 1 byte
+
+    /// The public key of the pauser, which may only pause and unpause CpAmms.
+    pauser: Pubkey, // 32 bytes
+
+    /// Whether the protocol-wide kill switch is on.
+    is_halted: bool, // 1 byte
+
+    /// The number of times the kill switch has been toggled, used to sync `AmmsConfig` accounts.
+    halt_epoch: u64, // 8 bytes
+
+    /// The version of the account layout and the space reserved for future fields.
+    version: AccountVersion<64>, // 1 + 64 bytes
+}
+
+/// Represents the layout of the `AmmsConfigsManager` account created before versioning.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LegacyAmmsConfigsManager {
+    authority: Pubkey,  // 32 bytes
+    head_authority: Pubkey, // 32 bytes
+    configs_count: u64, // 8 bytes
+    bump: u8,   // 1 byte
+}
+
+impl LegacyLayout for LegacyAmmsConfigsManager {
+    type Current = AmmsConfigsManager;
+
+    /// Keeps the authorities and the configurations count, no pauser is set and the kill switch is off.
+    fn migrate(self, _current_timestamp: i64) -> AmmsConfigsManager {
+        AmmsConfigsManager {
+            authority: self.authority,
+            head_authority: self.head_authority,
+            configs_count: self.configs_count,
+            bump: self.bump,
+            pauser: Pubkey::default(),
+            is_halted: false,
+            halt_epoch: 0,
+            version: AccountVersion::current(),
+        }
+    }
+
+    fn read(self, current_timestamp: i64) -> AmmsConfigsManager {
+        AmmsConfigsManager {
+            version: AccountVersion::default(),
+            ..self.migrate(current_timestamp)
+        }
+    }
+
+    fn try_from_current(manager: &AmmsConfigsManager) -> Result<Self> {
+        require!(
+            manager.pauser == Pubkey::default() && !manager.is_halted && manager.halt_epoch == 0,
+            ErrorCode::AccountNotMigrated
+        );
+        Ok(Self {
+            authority: manager.authority,
+            head_authority: manager.head_authority,
+            configs_count: manager.configs_count,
+            bump: manager.bump,
+        })
+    }
+}
+
+impl VersionedAccount for AmmsConfigsManager {
+    type Legacy = LegacyAmmsConfigsManager;
+
+    fn is_legacy(&self) -> bool {
+        self.version() == AccountVersion::<64>::LEGACY_VERSION
+    }
+}
+
+impl Discriminator for AmmsConfigsManager {
+    /// The first 8 bytes of the SHA-256 hash of `account:AmmsConfigsManager`, as `#[account]` would derive it.
+    const DISCRIMINATOR: [u8; 8] = [139, 250, 201, 66, 232, 224, 136, 144];
+}
+
+impl Owner for AmmsConfigsManager {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
+}
+
+impl AccountSerialize for AmmsConfigsManager {
+    fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+        self.try_serialize_versioned(writer)
+    }
+}
+
+impl AccountDeserialize for AmmsConfigsManager {
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        Self::try_deserialize_versioned(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        Self::try_deserialize_versioned_unchecked(buf)
+    }
 }
 
 impl AmmsConfigsManager {
@@ -35,10 +131,12 @@ impl AmmsConfigsManager {
     ///
     /// # Behavior
     /// - Sets the initial `configs_count` to 0.
+    /// - Sets the account layout version to the current one.
     /// - Updates the authority and head authority fields with the provided values.
     pub(crate) fn initialize(&mut self, authority: Pubkey, head_authority: Pubkey, bump: u8) {
         self.bump = bump;
         self.configs_count = 0;
+        self.version = AccountVersion::current();
         self.update_authority(authority);
         self.update_head_authority(head_authority);
     }
@@ -60,6 +158,30 @@ impl AmmsConfigsManager {
         self.head_authority = head_authority;
     }
 
+    /// Updates the `pauser` field with a new public key.
+    ///
+    /// # Parameters
+    /// - `pauser`: The new public key for the pauser of CpAmms.
+    pub(crate) fn update_pauser(&mut self, pauser: Pubkey) {
+        self.pauser = pauser;
+    }
+
+    /// Turns the protocol-wide kill switch on or off and starts a new halt epoch.
+    ///
+    /// The new state takes effect immediately, since the halted instructions read it from the manager.
+    /// Each `AmmsConfig` mirrors it once it is synced with the manager.
+    ///
+    /// # Parameters
+    /// - `is_halted`: Whether the kill switch is on.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::HaltEpochOverflow` if the halt epoch can't be incremented.
+    pub(crate) fn update_halt(&mut self, is_halted: bool) -> Result<()> {
+        self.halt_epoch = self.halt_epoch.checked_add(1).ok_or(ErrorCode::HaltEpochOverflow)?;
+        self.is_halted = is_halted;
+        Ok(())
+    }
+
     /// Increments the `configs_count` field by 1.
     ///
     /// # Behavior
@@ -87,6 +209,36 @@ impl AmmsConfigsManager {
         &self.authority
     }
 
+    /// Retrieves the public key of the pauser of CpAmms.
+    ///
+    /// # Returns
+    /// - A reference to the `Pubkey` representing the pauser.
+    #[inline]
+    pub fn pauser(&self) -> &Pubkey {
+        &self.pauser
+    }
+
+    /// Checks if the protocol-wide kill switch is on.
+    #[inline]
+    pub fn is_halted(&self) -> bool {
+        self.is_halted
+    }
+
+    /// Retrieves the number of times the kill switch has been toggled.
+    ///
+    /// # Returns
+    /// - A `u64` value representing the current halt epoch.
+    #[inline]
+    pub fn halt_epoch(&self) -> u64 {
+        self.halt_epoch
+    }
+
+    /// Retrieves the version of the account layout.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.version.version()
+    }
+
     /// Retrieves the total number of AMM configurations managed by this account.
     ///
     /// # Returns
@@ -110,6 +262,7 @@ impl AmmsConfigsManager {
 mod amms_configs_manager_tests {
     use anchor_lang::Discriminator;
     use crate::constants::ANCHOR_DISCRIMINATOR;
+    use crate::error::ErrorCode;
     use super::*;
 
     /// Tests the correct initialization of the `AmmsConfigsManager` struct.
@@ -120,6 +273,10 @@ mod amms_configs_manager_tests {
             head_authority: Pubkey::default(),
             configs_count: 0,
             bump: 0,
+            pauser: Pubkey::default(),
+            is_halted: false,
+            halt_epoch: 0,
+            version: AccountVersion::default(),
         };
 
         let authority = Pubkey::new_unique();
@@ -137,6 +294,7 @@ mod amms_configs_manager_tests {
         assert_eq!(manager.head_authority().key(), head_authority);
         assert_eq!(manager.configs_count(), 0);
         assert_eq!(manager.bump(), bump);
+        assert_eq!(manager.version(), AccountVersion::<64>::CURRENT_VERSION);
     }
     
     /// Tests the `update_authority` method of the `AmmsConfigsManager` struct.
@@ -147,6 +305,10 @@ mod amms_configs_manager_tests {
             head_authority: Pubkey::new_unique(),
             configs_count: 10,
             bump: 42,
+            pauser: Pubkey::default(),
+            is_halted: false,
+            halt_epoch: 0,
+            version: AccountVersion::default(),
         };
 
         let new_authority = Pubkey::new_unique();
@@ -163,6 +325,10 @@ mod amms_configs_manager_tests {
             head_authority: Pubkey::default(),
             configs_count: 10,
             bump: 42,
+            pauser: Pubkey::default(),
+            is_halted: false,
+            halt_epoch: 0,
+            version: AccountVersion::default(),
         };
 
         let new_head_authority = Pubkey::new_unique();
@@ -179,22 +345,80 @@ mod amms_configs_manager_tests {
             head_authority: Pubkey::new_unique(),
             configs_count: 5,
             bump: 42,
+            pauser: Pubkey::default(),
+            is_halted: false,
+            halt_epoch: 0,
+            version: AccountVersion::default(),
         };
 
         manager.increment_configs_count();
 
         assert_eq!(manager.configs_count, 6);
     }
+
+    /// Tests the `update_pauser` method of the `AmmsConfigsManager` struct.
+    #[test]
+    fn test_amms_configs_manager_update_pauser(){
+        let mut manager = AmmsConfigsManager {
+            authority: Pubkey::new_unique(),
+            head_authority: Pubkey::new_unique(),
+            configs_count: 10,
+            bump: 42,
+            pauser: Pubkey::default(),
+            is_halted: false,
+            halt_epoch: 0,
+            version: AccountVersion::default(),
+        };
+
+        let new_pauser = Pubkey::new_unique();
+        manager.update_pauser(new_pauser);
+
+        assert_eq!(manager.pauser, new_pauser);
+        assert_eq!(manager.pauser().key(), new_pauser);
+    }
+
+    /// Tests the `update_halt` method of the `AmmsConfigsManager` struct.
+    #[test]
+    fn test_amms_configs_manager_update_halt(){
+        let mut manager = AmmsConfigsManager {
+            authority: Pubkey::new_unique(),
+            head_authority: Pubkey::new_unique(),
+            configs_count: 10,
+            bump: 42,
+            pauser: Pubkey::default(),
+            is_halted: false,
+            halt_epoch: 0,
+            version: AccountVersion::default(),
+        };
+
+        manager.update_halt(true).unwrap();
+        assert!(manager.is_halted());
+        assert_eq!(manager.halt_epoch(), 1);
+
+        manager.update_halt(false).unwrap();
+        assert!(!manager.is_halted());
+        assert_eq!(manager.halt_epoch(), 2);
+
+        manager.halt_epoch = u64::MAX;
+        assert_eq!(manager.update_halt(true).unwrap_err(), ErrorCode::HaltEpochOverflow.into());
+        assert!(!manager.is_halted());
+        assert_eq!(manager.halt_epoch(), u64::MAX);
+    }
     
     /// Tests the `update_head_authority` method of the `AmmsConfigsManager` struct.
     #[test]
     fn test_amms_configs_manager_data_layout() {
+        assert_eq!(AmmsConfigsManager::DISCRIMINATOR, anchor_lang::solana_program::hash::hash(b"account:AmmsConfigsManager").to_bytes()[..8]);
+
         let authority = Pubkey::new_unique();
         let head_authority = Pubkey::new_unique();
         let configs_count = 42u64;
         let bump = 42u8;
+        let pauser = Pubkey::new_unique();
+        let is_halted = true;
+        let halt_epoch = 3u64;
 
-        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 73];
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 179];
         let mut offset = 0;
 
         data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&AmmsConfigsManager::discriminator()); offset += ANCHOR_DISCRIMINATOR;
@@ -202,18 +426,78 @@ mod amms_configs_manager_tests {
         data[offset..offset + 32].copy_from_slice(head_authority.as_ref()); offset += 32;
         data[offset..offset + 8].copy_from_slice(&configs_count.to_le_bytes()); offset += 8;
         data[offset] = bump; offset += 1;
+        data[offset..offset + 32].copy_from_slice(pauser.as_ref()); offset += 32;
+        data[offset] = is_halted as u8; offset += 1;
+        data[offset..offset + 8].copy_from_slice(&halt_epoch.to_le_bytes()); offset += 8;
+        data[offset] = AccountVersion::<64>::CURRENT_VERSION; offset += 1;
+        offset += 64;
+
+        assert_eq!(offset, ANCHOR_DISCRIMINATOR + 179);
+        assert_eq!(offset, ANCHOR_DISCRIMINATOR + AmmsConfigsManager::INIT_SPACE);
 
-        assert_eq!(offset, ANCHOR_DISCRIMINATOR + 73);
-        
         let deserialized_manager = AmmsConfigsManager::try_deserialize(&mut data.as_ref()).unwrap();
 
         assert_eq!(deserialized_manager.authority, authority);
         assert_eq!(deserialized_manager.head_authority, head_authority);
         assert_eq!(deserialized_manager.configs_count, configs_count);
         assert_eq!(deserialized_manager.bump, bump);
+        assert_eq!(deserialized_manager.pauser, pauser);
+        assert_eq!(deserialized_manager.is_halted, is_halted);
+        assert_eq!(deserialized_manager.halt_epoch, halt_epoch);
+        assert_eq!(deserialized_manager.version(), AccountVersion::<64>::CURRENT_VERSION);
 
         let mut serialized_data = Vec::new();
         deserialized_manager.try_serialize(&mut serialized_data).unwrap();
         assert_eq!(serialized_data.as_slice(), data.as_ref());
     }
+
+    /// Tests reading and migrating the `AmmsConfigsManager` layout deployed before versioning.
+    #[test]
+    fn test_legacy_amms_configs_manager_migration() {
+        let authority = Pubkey::new_unique();
+        let head_authority = Pubkey::new_unique();
+        let configs_count = 42u64;
+        let bump = 42u8;
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 73];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&AmmsConfigsManager::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset..offset + 32].copy_from_slice(authority.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(head_authority.as_ref()); offset += 32;
+        data[offset..offset + 8].copy_from_slice(&configs_count.to_le_bytes()); offset += 8;
+        data[offset] = bump; offset += 1;
+
+        assert_eq!(ANCHOR_DISCRIMINATOR + LegacyAmmsConfigsManager::INIT_SPACE, offset);
+
+        let mut legacy_manager = AmmsConfigsManager::try_deserialize(&mut data.as_ref()).unwrap();
+        assert!(legacy_manager.is_legacy());
+        assert_eq!(legacy_manager.version(), AccountVersion::<64>::LEGACY_VERSION);
+        assert_eq!(legacy_manager.authority, authority);
+        assert_eq!(legacy_manager.configs_count, configs_count);
+
+        let mut legacy_data = [0u8; ANCHOR_DISCRIMINATOR + 73];
+        legacy_manager.try_serialize(&mut legacy_data.as_mut_slice()).unwrap();
+        assert_eq!(legacy_data, data);
+
+        legacy_manager.update_pauser(Pubkey::new_unique());
+        assert_eq!(legacy_manager.try_serialize(&mut legacy_data.as_mut_slice()).unwrap_err(), ErrorCode::AccountNotMigrated.into());
+
+        let migrated_manager = LegacyAmmsConfigsManager::try_from_account_data(&data).unwrap().migrate(0);
+
+        assert_eq!(migrated_manager.authority, authority);
+        assert_eq!(migrated_manager.head_authority, head_authority);
+        assert_eq!(migrated_manager.configs_count, configs_count);
+        assert_eq!(migrated_manager.bump, bump);
+        assert_eq!(migrated_manager.pauser, Pubkey::default());
+        assert!(!migrated_manager.is_halted);
+        assert_eq!(migrated_manager.halt_epoch, 0);
+        assert_eq!(migrated_manager.version(), AccountVersion::<64>::CURRENT_VERSION);
+
+        let mut migrated_data = [0u8; ANCHOR_DISCRIMINATOR + AmmsConfigsManager::INIT_SPACE];
+        migrated_manager.try_serialize(&mut migrated_data.as_mut_slice()).unwrap();
+        let deserialized_manager = AmmsConfigsManager::try_deserialize(&mut migrated_data.as_ref()).unwrap();
+        assert_eq!(deserialized_manager.configs_count, configs_count);
+        assert_eq!(LegacyAmmsConfigsManager::try_from_account_data(&migrated_data).unwrap_err(), ErrorCode::AccountAlreadyMigrated.into());
+    }
 }
\ No newline at end of file
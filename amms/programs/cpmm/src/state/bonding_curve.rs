@@ -0,0 +1,387 @@
+use anchor_lang::{account, InitSpace};
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+use crate::state::cp_amm::{CpAmmCalculate, CpAmmCore};
+use crate::utils::math::Q64_128;
+
+/// Represents the pre-launch bonding curve of a `CpAmm`, trading its base tokens against virtual reserves.
+///
+/// The curve prices swaps by the constant product of its real reserves plus the virtual offsets, without LP tokens.
+/// The real reserves are held in the `CpAmm` vaults, and once `graduation_quote_threshold` of quote is raised
+/// they launch the `CpAmm` with all its LP tokens locked.
+#[account]
+#[derive(InitSpace)]
+pub struct BondingCurve {
+    /// The canonical bump seed used for the account's PDA.
+    bump: [u8; 1], // 1 byte
+
+    /// The public key of the `CpAmm` launched by the curve.
+    cp_amm: Pubkey, // 32 bytes
+
+    /// The virtual base reserves added to the real base reserves when pricing swaps.
+    virtual_base_reserves: u64, // 8 bytes
+
+    /// The virtual quote reserves added to the real quote reserves when pricing swaps.
+    virtual_quote_reserves: u64, // 8 bytes
+
+    /// The real base tokens left for sale in the curve.
+    base_reserves: u64, // 8 bytes
+
+    /// The real quote tokens raised by the curve.
+    quote_reserves: u64, // 8 bytes
+
+    /// The real quote reserves at which the curve graduates into the launched `CpAmm`.
+    graduation_quote_threshold: u64, // 8 bytes
+}
+
+impl BondingCurve {
+    /// The seed used to derive the account's PDA.
+    pub const SEED: &'static [u8] = b"bonding_curve";
+
+    /// The allowed difference between the curve price at graduation and the `CpAmm` launch price, in basis points.
+    const GRADUATION_PRICE_TOLERANCE_BASIS_POINTS: u128 = 1;
+
+    /// Initializes the `BondingCurve` with the provided parameters.
+    ///
+    /// # Parameters
+    /// - `cp_amm`: The public key of the `CpAmm` launched by the curve.
+    /// - `base_reserves`: The real base tokens deposited for sale.
+    /// - `virtual_base_reserves`: The virtual base reserves offset.
+    /// - `virtual_quote_reserves`: The virtual quote reserves offset, it sets the starting price with no quote raised.
+    /// - `graduation_quote_threshold`: The real quote reserves at which the curve graduates.
+    /// - `bump`: The bump seed for the account's PDA.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::InvalidBondingCurveParameters` if the base reserves, virtual quote reserves or threshold are zero.
+    /// - Returns `ErrorCode::BondingCurveGraduationPriceMismatch` if the curve price at graduation differs
+    ///   from the launch price of `CpAmm`.
+    pub(crate) fn initialize(
+        &mut self,
+        cp_amm: Pubkey,
+        base_reserves: u64,
+        virtual_base_reserves: u64,
+        virtual_quote_reserves: u64,
+        graduation_quote_threshold: u64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            base_reserves > 0 && virtual_quote_reserves > 0 && graduation_quote_threshold > 0 &&
+                base_reserves.checked_add(virtual_base_reserves).is_some(),
+            ErrorCode::InvalidBondingCurveParameters
+        );
+        require!(
+            Self::is_graduation_price_continuous(base_reserves, virtual_base_reserves, virtual_quote_reserves, graduation_quote_threshold),
+            ErrorCode::BondingCurveGraduationPriceMismatch
+        );
+        self.bump = [bump];
+        self.cp_amm = cp_amm;
+        self.virtual_base_reserves = virtual_base_reserves;
+        self.virtual_quote_reserves = virtual_quote_reserves;
+        self.base_reserves = base_reserves;
+        self.quote_reserves = 0;
+        self.graduation_quote_threshold = graduation_quote_threshold;
+        Ok(())
+    }
+
+    /// Checks that the curve price at graduation matches the price `CpAmm` is launched at.
+    ///
+    /// The curve reaches `graduation_quote_threshold` of real quote with `k / (threshold + virtual quote)` base liquidity,
+    /// and `CpAmm` is launched with the real reserves only, so both prices match only if the virtual reserves
+    /// have the same ratio as the real reserves at graduation.
+    fn is_graduation_price_continuous(base_reserves: u64, virtual_base_reserves: u64, virtual_quote_reserves: u64, graduation_quote_threshold: u64) -> bool {
+        let constant_product = (base_reserves as u128 + virtual_base_reserves as u128) * virtual_quote_reserves as u128;
+        let graduation_quote_liquidity = graduation_quote_threshold as u128 + virtual_quote_reserves as u128;
+        let Some(graduation_base_reserves) = (constant_product / graduation_quote_liquidity)
+            .checked_sub(virtual_base_reserves as u128)
+            .filter(|graduation_base_reserves| *graduation_base_reserves > 0) else {
+            return false;
+        };
+        // Both prices are cross multiplied by the real and the curve base liquidity
+        let launch_price = graduation_quote_threshold as u128 * (graduation_base_reserves + virtual_base_reserves as u128);
+        let Some(curve_price) = graduation_quote_liquidity.checked_mul(graduation_base_reserves) else {
+            return false;
+        };
+        launch_price.abs_diff(curve_price) <= launch_price / 10000 * Self::GRADUATION_PRICE_TOLERANCE_BASIS_POINTS
+    }
+
+    /// Returns the canonical bump value for the PDA.
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    /// Returns the public key of the `CpAmm` launched by the curve.
+    #[inline]
+    pub fn cp_amm(&self) -> &Pubkey {
+        &self.cp_amm
+    }
+
+    /// Returns the real base tokens left for sale in the curve.
+    #[inline]
+    pub fn base_reserves(&self) -> u64 {
+        self.base_reserves
+    }
+
+    /// Returns the real quote tokens raised by the curve.
+    #[inline]
+    pub fn quote_reserves(&self) -> u64 {
+        self.quote_reserves
+    }
+
+    /// Returns the real quote reserves at which the curve graduates.
+    #[inline]
+    pub fn graduation_quote_threshold(&self) -> u64 {
+        self.graduation_quote_threshold
+    }
+
+    /// Prepares the payload for a swap in the bonding curve.
+    ///
+    /// The fee is always taken in quote tokens: from the input when buying base, from the output when selling it.
+    ///
+    /// # Parameters
+    /// - `swap_amount`: The amount of tokens being swapped (either base or quote).
+    /// - `estimated_result`: Expected amount of tokens to receive after the swap.
+    /// - `allowed_slippage`: Maximum permissible deviation from `estimated_result`.
+    /// - `fee_rate_basis_points`: The fee rate in basis points.
+    /// - `is_in_out`: `true` if selling **base → quote**, `false` if buying **quote → base**.
+    ///
+    /// # Returns
+    /// - `Ok(BondingCurveSwapPayload)`: Contains the updated real reserves and the quote fee.
+    /// - `Err(ErrorCode)`: If the swap exceeds the real reserves, overflows or the slippage is exceeded.
+    #[inline(never)]
+    pub fn get_swap_payload(&self, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, fee_rate_basis_points: u16, is_in_out: bool) -> Result<BondingCurveSwapPayload> {
+        require!(swap_amount > 0, ErrorCode::SwapAmountIsZero);
+        require!(estimated_result > 0, ErrorCode::EstimatedResultIsZero);
+        require!(fee_rate_basis_points <= 10000, ErrorCode::ConfigFeeRateExceeded);
+
+        let (new_base_liquidity, new_quote_liquidity, base_reserves, quote_reserves, fee_amount, amount_to_withdraw);
+        if is_in_out {
+            (new_base_liquidity, new_quote_liquidity) = self.calculate_afterswap_liquidity(swap_amount, true).ok_or(ErrorCode::AfterswapCalculationFailed)?;
+            let quote_amount = self.quote_liquidity().checked_sub(new_quote_liquidity).ok_or(ErrorCode::SwapOverflowError)?;
+            base_reserves = self.base_reserves.checked_add(swap_amount).ok_or(ErrorCode::SwapOverflowError)?;
+            quote_reserves = self.quote_reserves.checked_sub(quote_amount).ok_or(ErrorCode::BondingCurveReservesExceeded)?;
+            fee_amount = Self::calculate_fee_amount(quote_amount, fee_rate_basis_points);
+            amount_to_withdraw = quote_amount - fee_amount;
+        }
+        else {
+            fee_amount = Self::calculate_fee_amount(swap_amount, fee_rate_basis_points);
+            let quote_amount_after_fee = swap_amount - fee_amount;
+            (new_base_liquidity, new_quote_liquidity) = self.calculate_afterswap_liquidity(quote_amount_after_fee, false).ok_or(ErrorCode::AfterswapCalculationFailed)?;
+            amount_to_withdraw = self.base_liquidity().checked_sub(new_base_liquidity).ok_or(ErrorCode::SwapOverflowError)?;
+            // The curve keeps some base tokens to launch the CpAmm with
+            base_reserves = self.base_reserves.checked_sub(amount_to_withdraw).filter(|base_reserves| *base_reserves > 0).ok_or(ErrorCode::BondingCurveReservesExceeded)?;
+            quote_reserves = self.quote_reserves.checked_add(quote_amount_after_fee).ok_or(ErrorCode::SwapOverflowError)?;
+        }
+
+        self.validate_swap_constant_product(new_base_liquidity, new_quote_liquidity)?;
+        Self::check_swap_result(amount_to_withdraw, estimated_result, allowed_slippage)?;
+
+        Ok(BondingCurveSwapPayload {
+            base_reserves,
+            quote_reserves,
+            fee_amount,
+            amount_to_withdraw,
+            is_graduating: quote_reserves >= self.graduation_quote_threshold,
+        })
+    }
+
+    /// Updates the real reserves of the curve after a swap.
+    ///
+    /// # Parameters
+    /// - `swap_payload`: Contains the new real reserves.
+    pub(crate) fn swap(&mut self, swap_payload: &BondingCurveSwapPayload) {
+        self.base_reserves = swap_payload.base_reserves;
+        self.quote_reserves = swap_payload.quote_reserves;
+    }
+}
+
+/// The curve reuses the `CpAmm` constant product math over its real reserves plus the virtual offsets.
+impl CpAmmCore for BondingCurve {
+    fn constant_product_sqrt(&self) -> Q64_128 {
+        Self::calculate_constant_product_sqrt(self.base_liquidity(), self.quote_liquidity()).unwrap_or(Q64_128::from_u64(0))
+    }
+
+    fn base_quote_ratio_sqrt(&self) -> Q64_128 {
+        Self::calculate_base_quote_ratio_sqrt(self.base_liquidity(), self.quote_liquidity()).unwrap_or(Q64_128::from_u64(0))
+    }
+
+    fn base_liquidity(&self) -> u64 {
+        self.base_reserves.saturating_add(self.virtual_base_reserves)
+    }
+
+    fn quote_liquidity(&self) -> u64 {
+        self.quote_reserves.saturating_add(self.virtual_quote_reserves)
+    }
+
+    fn lp_tokens_supply(&self) -> u64 {
+        0
+    }
+}
+
+/// Represents the result of a swap in the bonding curve.
+#[derive(Debug)]
+pub struct BondingCurveSwapPayload {
+    base_reserves: u64,
+    quote_reserves: u64,
+    fee_amount: u64,
+    amount_to_withdraw: u64,
+    is_graduating: bool,
+}
+
+impl BondingCurveSwapPayload {
+    /// Returns the real base reserves after the swap.
+    #[inline]
+    pub fn base_reserves(&self) -> u64 {
+        self.base_reserves
+    }
+
+    /// Returns the real quote reserves after the swap.
+    #[inline]
+    pub fn quote_reserves(&self) -> u64 {
+        self.quote_reserves
+    }
+
+    /// Returns the quote fee of the swap, accumulated as the `CpAmm` protocol fee.
+    #[inline]
+    pub fn fee_amount(&self) -> u64 {
+        self.fee_amount
+    }
+
+    /// Returns the amount of tokens the signer receives.
+    #[inline]
+    pub fn amount_to_withdraw(&self) -> u64 {
+        self.amount_to_withdraw
+    }
+
+    /// Checks if the swap raises enough quote to graduate the curve into the launched `CpAmm`.
+    #[inline]
+    pub fn is_graduating(&self) -> bool {
+        self.is_graduating
+    }
+}
+
+#[cfg(test)]
+mod bonding_curve_tests {
+    use anchor_lang::Discriminator;
+    use crate::constants::ANCHOR_DISCRIMINATOR;
+    use super::*;
+
+    fn build_bonding_curve(base_reserves: u64, virtual_base_reserves: u64, virtual_quote_reserves: u64, graduation_quote_threshold: u64) -> BondingCurve {
+        let data = [0u8; ANCHOR_DISCRIMINATOR + BondingCurve::INIT_SPACE];
+        let mut bonding_curve = BondingCurve::try_deserialize_unchecked(&mut data.as_ref()).unwrap();
+        bonding_curve.initialize(Pubkey::new_unique(), base_reserves, virtual_base_reserves, virtual_quote_reserves, graduation_quote_threshold, 254).unwrap();
+        bonding_curve
+    }
+
+    /// Tests the validation of the `initialize` method of `BondingCurve`.
+    #[test]
+    fn test_bonding_curve_initialize() {
+        let data = [0u8; ANCHOR_DISCRIMINATOR + BondingCurve::INIT_SPACE];
+        let mut bonding_curve = BondingCurve::try_deserialize_unchecked(&mut data.as_ref()).unwrap();
+        let cp_amm = Pubkey::new_unique();
+
+        assert_eq!(bonding_curve.initialize(cp_amm, 0, 0, 1_000, 1_000, 254).unwrap_err(), ErrorCode::InvalidBondingCurveParameters.into());
+        assert_eq!(bonding_curve.initialize(cp_amm, 1_000, 0, 0, 1_000, 254).unwrap_err(), ErrorCode::InvalidBondingCurveParameters.into());
+        assert_eq!(bonding_curve.initialize(cp_amm, 1_000, 0, 1_000, 0, 254).unwrap_err(), ErrorCode::InvalidBondingCurveParameters.into());
+        assert_eq!(bonding_curve.initialize(cp_amm, 1_000, u64::MAX, 1_000, 1_000, 254).unwrap_err(), ErrorCode::InvalidBondingCurveParameters.into());
+
+        // The virtual reserves must have the ratio of the real reserves at graduation
+        assert_eq!(bonding_curve.initialize(cp_amm, 1_000_000, 500_000, 30_000, 80_000, 254).unwrap_err(), ErrorCode::BondingCurveGraduationPriceMismatch.into());
+        assert_eq!(bonding_curve.initialize(cp_amm, 1_500_000, 500_000, 30_000, 30_100, 254).unwrap_err(), ErrorCode::BondingCurveGraduationPriceMismatch.into());
+        // No base tokens left to launch with at graduation
+        assert_eq!(bonding_curve.initialize(cp_amm, 1_000, 1_000_000, 30_000, 30_000_000, 254).unwrap_err(), ErrorCode::BondingCurveGraduationPriceMismatch.into());
+
+        bonding_curve.initialize(cp_amm, 1_500_000, 500_000, 30_000, 30_000, 254).unwrap();
+        assert_eq!(bonding_curve.bump(), 254);
+        assert_eq!(*bonding_curve.cp_amm(), cp_amm);
+        assert_eq!(bonding_curve.base_reserves(), 1_500_000);
+        assert_eq!(bonding_curve.quote_reserves(), 0);
+        assert_eq!(bonding_curve.base_liquidity(), 2_000_000);
+        assert_eq!(bonding_curve.quote_liquidity(), 30_000);
+        assert_eq!(bonding_curve.lp_tokens_supply(), 0);
+    }
+
+    /// Tests buys, sells and the graduation of `BondingCurve`.
+    #[test]
+    fn test_bonding_curve_swaps() {
+        let mut bonding_curve = build_bonding_curve(1_500_000_000, 500_000_000, 30_000_000, 30_000_000);
+
+        // Buy with a 1% fee: 10_000_000 quote in, 9_900_000 after the fee
+        let buy_payload = bonding_curve.get_swap_payload(10_000_000, 496_000_000, 1_000_000, 100, false).unwrap();
+        assert_eq!(buy_payload.fee_amount(), 100_000);
+        assert_eq!(buy_payload.quote_reserves(), 9_900_000);
+        assert_eq!(buy_payload.amount_to_withdraw(), 2_000_000_000 - 1_503_759_398);
+        assert_eq!(buy_payload.base_reserves(), 1_500_000_000 - buy_payload.amount_to_withdraw());
+        assert!(!buy_payload.is_graduating());
+        bonding_curve.swap(&buy_payload);
+
+        // Selling the bought base back returns the raised quote minus the fee
+        let base_to_sell = buy_payload.amount_to_withdraw();
+        let sell_payload = bonding_curve.get_swap_payload(base_to_sell, 9_801_000, 1_000, 100, true).unwrap();
+        assert!(sell_payload.quote_reserves() <= 1);
+        assert_eq!(sell_payload.base_reserves(), 1_500_000_000);
+        assert_eq!(sell_payload.fee_amount() + sell_payload.amount_to_withdraw(), 9_900_000 - sell_payload.quote_reserves());
+        bonding_curve.swap(&sell_payload);
+
+        // Selling more base than bought exceeds the real quote reserves
+        assert_eq!(bonding_curve.get_swap_payload(1_000_000, 1, 1_000_000, 100, true).unwrap_err(), ErrorCode::BondingCurveReservesExceeded.into());
+
+        // Buying out all the real base tokens is not allowed
+        assert_eq!(bonding_curve.get_swap_payload(10_000_000_000, 1, u64::MAX, 0, false).unwrap_err(), ErrorCode::BondingCurveReservesExceeded.into());
+
+        // Raising the threshold graduates the curve at the price CpAmm is launched at
+        assert!(!bonding_curve.get_swap_payload(29_999_999, 1, u64::MAX, 0, false).unwrap().is_graduating());
+        let graduation_payload = bonding_curve.get_swap_payload(30_000_000, 1, u64::MAX, 0, false).unwrap();
+        assert!(graduation_payload.is_graduating());
+        assert_eq!(graduation_payload.base_reserves(), 500_000_000);
+        assert_eq!(graduation_payload.quote_reserves(), 30_000_000);
+        bonding_curve.swap(&graduation_payload);
+        assert_eq!(
+            bonding_curve.quote_liquidity() as u128 * bonding_curve.base_reserves() as u128,
+            bonding_curve.quote_reserves() as u128 * bonding_curve.base_liquidity() as u128
+        );
+
+        assert_eq!(bonding_curve.get_swap_payload(0, 1, 0, 0, false).unwrap_err(), ErrorCode::SwapAmountIsZero.into());
+        assert_eq!(bonding_curve.get_swap_payload(1, 0, 0, 0, false).unwrap_err(), ErrorCode::EstimatedResultIsZero.into());
+    }
+
+    /// Tests the data layout of `BondingCurve`.
+    #[test]
+    fn test_bonding_curve_data_layout() {
+        let bump = 254u8;
+        let cp_amm = Pubkey::new_unique();
+        let virtual_base_reserves = 500_000u64;
+        let virtual_quote_reserves = 30_000u64;
+        let base_reserves = 900_000u64;
+        let quote_reserves = 12_000u64;
+        let graduation_quote_threshold = 80_000u64;
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 73];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&BondingCurve::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset] = bump; offset += 1;
+        data[offset..offset + 32].copy_from_slice(cp_amm.as_ref()); offset += 32;
+        data[offset..offset + 8].copy_from_slice(&virtual_base_reserves.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&virtual_quote_reserves.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&base_reserves.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&quote_reserves.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&graduation_quote_threshold.to_le_bytes()); offset += 8;
+
+        assert_eq!(offset, ANCHOR_DISCRIMINATOR + BondingCurve::INIT_SPACE);
+
+        let deserialized_bonding_curve = BondingCurve::try_deserialize(&mut data.as_ref()).unwrap();
+
+        assert_eq!(deserialized_bonding_curve.bump(), bump);
+        assert_eq!(deserialized_bonding_curve.cp_amm, cp_amm);
+        assert_eq!(deserialized_bonding_curve.virtual_base_reserves, virtual_base_reserves);
+        assert_eq!(deserialized_bonding_curve.virtual_quote_reserves, virtual_quote_reserves);
+        assert_eq!(deserialized_bonding_curve.base_reserves, base_reserves);
+        assert_eq!(deserialized_bonding_curve.quote_reserves, quote_reserves);
+        assert_eq!(deserialized_bonding_curve.graduation_quote_threshold, graduation_quote_threshold);
+
+        let mut serialized_bonding_curve = Vec::new();
+        deserialized_bonding_curve.try_serialize(&mut serialized_bonding_curve).unwrap();
+        assert_eq!(serialized_bonding_curve.as_slice(), data.as_ref());
+    }
+}
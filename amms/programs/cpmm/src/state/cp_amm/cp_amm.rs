@@ -1,10 +1,10 @@
-use anchor_lang::{account, InitSpace};
+use anchor_lang::{Discriminator, InitSpace};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint};
 use anchor_spl::token_interface;
 use crate::utils::math::Q64_128;
 use crate::error::ErrorCode;
-use crate::state::AmmsConfig;
+use crate::state::{AccountVersion, AmmsConfig, LegacyLayout, VersionedAccount, VersionedField, DonationRatioPolicy, ProtocolFeeMode};
 use super::{CpAmmCalculate, CpAmmCore};
 
 /// Represents a Constant Product Automated Market Maker (AMM) pool.
@@ -14,15 +14,19 @@ use super::{CpAmmCalculate, CpAmmCore};
 ///
 /// The AMM maintains the constant product invariant, which ensures that
 /// the product of the pool's base and quote liquidity remains constant during swaps.
+///
+/// Implements the account traits as a `VersionedAccount`, so pools created before versioning keep working until migrated.
 
-#[account]
-#[derive(InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
 pub struct CpAmm {
-    /// Whether the AMM has been initialized.
+    /// Whether the AMM account has been initialized.
+    /// Kept apart from `status`, since `PoolStatus::Initialized` shares its zero encoding with fresh account data.
     is_initialized: bool, // 1 byte
 
-    /// Whether the AMM has been launched and is active.
-    is_launched: bool, // 1 byte
+    /// Lifecycle status of the AMM, stored in place of the former `is_launched` flag
+    /// which `LegacyCpAmm` migrates to `Initialized` or `Launched`.
+    /// Never holds `SwapsPaused` or `WithdrawOnly`, which are derived from the pause flags.
+    status: PoolStatus, // 1 byte
 
     /// Canonical bump seed for the account's PDA.
     bump: [u8; 1], // 1 byte
@@ -62,6 +66,12 @@ pub struct CpAmm {
     /// Accumulated quote token fees that can be redeemed by the `AmmsConfig` account's authority.
     protocol_quote_fees_to_redeem: u64, // 8 bytes
 
+    /// Accumulated base token fees that can be redeemed by the CpAmm creator.
+    creator_base_fees_to_redeem: u64, // 8 bytes
+
+    /// Accumulated quote token fees that can be redeemed by the CpAmm creator.
+    creator_quote_fees_to_redeem: u64, // 8 bytes
+
     /// Public key of the base token's mint.
     base_mint: Pubkey, // 32 bytes
 
@@ -84,7 +94,381 @@ pub struct CpAmm {
     amms_config: Pubkey, // 32 bytes
 
     /// Public key of the CpAmm creator account.
-    creator: Pubkey // 32 bytes
+    creator: Pubkey, // 32 bytes
+
+    /// Providers fee rate that overrides the `AmmsConfig` rate for this pool, measured in basis points.
+    providers_fee_rate_basis_points_override: Option<u16>, // 3 bytes
+
+    /// Protocol fee rate that overrides the `AmmsConfig` rate for this pool, measured in basis points.
+    protocol_fee_rate_basis_points_override: Option<u16>, // 3 bytes
+
+    /// Share of the protocol fee paid to the CpAmm creator, measured in basis points.
+    creator_fee_share_basis_points: u16, // 2 bytes
+
+    /// Square root of the constant product after the last liquidity event, stored as a Q64.128 fixed-point number.
+    /// Zero unless the protocol fee is taken as LP tokens.
+    last_constant_product_sqrt: Q64_128, // 24 bytes
+
+    /// Whether swaps in the AMM are paused.
+    is_swap_paused: bool, // 1 byte
+
+    /// Whether providing liquidity to the AMM is paused.
+    is_provide_paused: bool, // 1 byte
+
+    /// Whether withdrawing liquidity from the AMM is paused.
+    is_withdraw_paused: bool, // 1 byte
+
+    /// Unix timestamp of the AMM initialization.
+    initialized_at: i64, // 8 bytes
+
+    /// Unix timestamp from which swaps are open, only the creator can provide liquidity before it.
+    open_time: i64, // 8 bytes
+
+    /// Optional schedule of the launch fee, which decays from its start rate to the normal fee rate after `open_time`.
+    launch_fee_schedule: Option<LaunchFeeSchedule>, // 1 + 7 bytes
+
+    /// The version of the account layout and the space reserved for future fields.
+    version: AccountVersion<31>, // 1 + 31 bytes
+
+    /// Optional account allowed to launch the AMM besides the creator, set by the creator before the launch.
+    launch_authority: VersionedField<Option<Pubkey>>, // 1 + 32 bytes
+}
+
+/// Represents the layout of `CpAmm` accounts created before versioning.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq)]
+pub struct LegacyCpAmm {
+    is_initialized: bool, // 1 byte
+    is_launched: bool, // 1 byte
+    bump: [u8; 1], // 1 byte
+    base_vault_bump: [u8; 1], // 1 byte
+    quote_vault_bump: [u8; 1], // 1 byte
+    locked_lp_vault_bump: [u8; 1], // 1 byte
+    initial_locked_liquidity: u64, // 8 bytes
+    constant_product_sqrt: Q64_128, // 24 bytes
+    base_quote_ratio_sqrt: Q64_128, // 24 bytes
+    base_liquidity: u64, // 8 bytes
+    quote_liquidity: u64, // 8 bytes
+    lp_tokens_supply: u64, // 8 bytes
+    protocol_base_fees_to_redeem: u64, // 8 bytes
+    protocol_quote_fees_to_redeem: u64, // 8 bytes
+    base_mint: Pubkey, // 32 bytes
+    quote_mint: Pubkey, // 32 bytes
+    lp_mint: Pubkey, // 32 bytes
+    base_vault: Pubkey, // 32 bytes
+    quote_vault: Pubkey, // 32 bytes
+    locked_lp_vault: Pubkey, // 32 bytes
+    amms_config: Pubkey, // 32 bytes
+    creator: Pubkey, // 32 bytes
+}
+
+impl LegacyLayout for LegacyCpAmm {
+    type Current = CpAmm;
+
+    /// Keeps the pool state, the added fields get the defaults of a pool without overrides, pauses or launch options.
+    /// The launch deadline of a pool awaiting its launch starts at the migration,
+    /// and the protocol share of the constant product growth is accounted from it.
+    fn migrate(self, current_timestamp: i64) -> CpAmm {
+        CpAmm {
+            is_initialized: self.is_initialized,
+            status: if self.is_launched { PoolStatus::Launched } else { PoolStatus::Initialized },
+            bump: self.bump,
+            base_vault_bump: self.base_vault_bump,
+            quote_vault_bump: self.quote_vault_bump,
+            locked_lp_vault_bump: self.locked_lp_vault_bump,
+            initial_locked_liquidity: self.initial_locked_liquidity,
+            constant_product_sqrt: self.constant_product_sqrt,
+            base_quote_ratio_sqrt: self.base_quote_ratio_sqrt,
+            base_liquidity: self.base_liquidity,
+            quote_liquidity: self.quote_liquidity,
+            lp_tokens_supply: self.lp_tokens_supply,
+            protocol_base_fees_to_redeem: self.protocol_base_fees_to_redeem,
+            protocol_quote_fees_to_redeem: self.protocol_quote_fees_to_redeem,
+            creator_base_fees_to_redeem: 0,
+            creator_quote_fees_to_redeem: 0,
+            base_mint: self.base_mint,
+            quote_mint: self.quote_mint,
+            lp_mint: self.lp_mint,
+            base_vault: self.base_vault,
+            quote_vault: self.quote_vault,
+            locked_lp_vault: self.locked_lp_vault,
+            amms_config: self.amms_config,
+            creator: self.creator,
+            providers_fee_rate_basis_points_override: None,
+            protocol_fee_rate_basis_points_override: None,
+            creator_fee_share_basis_points: 0,
+            last_constant_product_sqrt: self.constant_product_sqrt,
+            is_swap_paused: false,
+            is_provide_paused: false,
+            is_withdraw_paused: false,
+            initialized_at: current_timestamp,
+            open_time: 0,
+            launch_fee_schedule: None,
+            version: AccountVersion::current(),
+            launch_authority: VersionedField::default(),
+        }
+    }
+
+    fn read(self, current_timestamp: i64) -> CpAmm {
+        CpAmm {
+            version: AccountVersion::default(),
+            ..self.migrate(current_timestamp)
+        }
+    }
+
+    /// `initialized_at` and `last_constant_product_sqrt` aren't stored, they restart at each read as at the migration.
+    fn try_from_current(cp_amm: &CpAmm) -> Result<Self> {
+        require!(
+            matches!(cp_amm.status, PoolStatus::Initialized | PoolStatus::Launched) &&
+                cp_amm.creator_base_fees_to_redeem == 0 &&
+                cp_amm.creator_quote_fees_to_redeem == 0 &&
+                cp_amm.providers_fee_rate_basis_points_override.is_none() &&
+                cp_amm.protocol_fee_rate_basis_points_override.is_none() &&
+                cp_amm.creator_fee_share_basis_points == 0 &&
+                !cp_amm.is_swap_paused &&
+                !cp_amm.is_provide_paused &&
+                !cp_amm.is_withdraw_paused &&
+                cp_amm.open_time == 0 &&
+                cp_amm.launch_fee_schedule.is_none() &&
+                cp_amm.launch_authority().is_none(),
+            ErrorCode::AccountNotMigrated
+        );
+        Ok(Self {
+            is_initialized: cp_amm.is_initialized,
+            is_launched: cp_amm.status == PoolStatus::Launched,
+            bump: cp_amm.bump,
+            base_vault_bump: cp_amm.base_vault_bump,
+            quote_vault_bump: cp_amm.quote_vault_bump,
+            locked_lp_vault_bump: cp_amm.locked_lp_vault_bump,
+            initial_locked_liquidity: cp_amm.initial_locked_liquidity,
+            constant_product_sqrt: cp_amm.constant_product_sqrt,
+            base_quote_ratio_sqrt: cp_amm.base_quote_ratio_sqrt,
+            base_liquidity: cp_amm.base_liquidity,
+            quote_liquidity: cp_amm.quote_liquidity,
+            lp_tokens_supply: cp_amm.lp_tokens_supply,
+            protocol_base_fees_to_redeem: cp_amm.protocol_base_fees_to_redeem,
+            protocol_quote_fees_to_redeem: cp_amm.protocol_quote_fees_to_redeem,
+            base_mint: cp_amm.base_mint,
+            quote_mint: cp_amm.quote_mint,
+            lp_mint: cp_amm.lp_mint,
+            base_vault: cp_amm.base_vault,
+            quote_vault: cp_amm.quote_vault,
+            locked_lp_vault: cp_amm.locked_lp_vault,
+            amms_config: cp_amm.amms_config,
+            creator: cp_amm.creator,
+        })
+    }
+}
+
+impl VersionedAccount for CpAmm {
+    type Legacy = LegacyCpAmm;
+
+    fn is_legacy(&self) -> bool {
+        self.version() == AccountVersion::<31>::LEGACY_VERSION
+    }
+}
+
+impl Discriminator for CpAmm {
+    /// The first 8 bytes of the SHA-256 hash of `account:CpAmm`, as `#[account]` would derive it.
+    const DISCRIMINATOR: [u8; 8] = [105, 219, 233, 13, 147, 109, 73, 100];
+}
+
+impl Owner for CpAmm {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
+}
+
+impl AccountSerialize for CpAmm {
+    fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+        self.try_serialize_versioned(writer)
+    }
+}
+
+impl AccountDeserialize for CpAmm {
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        Self::try_deserialize_versioned(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        Self::try_deserialize_versioned_unchecked(buf)
+    }
+}
+
+/// Represents the lifecycle status of a `CpAmm`.
+///
+/// The variants order is a part of the account layout: `Initialized` and `Launched` match the encoding
+/// of the former `is_launched` flag.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PoolStatus {
+    /// The AMM is initialized and waits for its launch liquidity.
+    #[default]
+    Initialized,
+
+    /// The AMM is launched and all operations are allowed.
+    Launched,
+
+    /// Swaps are paused, liquidity can still be provided and withdrawn.
+    /// Derived from the `CpAmm` pause flags and never stored.
+    SwapsPaused,
+
+    /// Swaps and provides are paused, liquidity providers can only withdraw.
+    /// Derived from the `CpAmm` pause flags and never stored.
+    WithdrawOnly,
+
+    /// The AMM is closed and no operations are allowed.
+    Closed,
+
+    /// The AMM trades on its `BondingCurve` and launches once the curve graduates.
+    BondingCurve,
+}
+
+impl PoolStatus {
+    /// Checks if the AMM has been launched and is not closed.
+    #[inline]
+    pub fn is_launched(&self) -> bool {
+        matches!(self, Self::Launched | Self::SwapsPaused | Self::WithdrawOnly)
+    }
+
+    /// Checks if swaps are allowed in this status.
+    #[inline]
+    pub fn can_swap(&self) -> bool {
+        *self == Self::Launched
+    }
+
+    /// Checks if providing liquidity is allowed in this status.
+    #[inline]
+    pub fn can_provide(&self) -> bool {
+        matches!(self, Self::Launched | Self::SwapsPaused)
+    }
+
+    /// Checks if withdrawing liquidity is allowed in this status.
+    #[inline]
+    pub fn can_withdraw(&self) -> bool {
+        self.is_launched()
+    }
+}
+
+/// Represents how the launch fee decays to the normal fee rate.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LaunchFeeDecayMode {
+    /// The launch fee decreases by the same amount every second.
+    #[default]
+    Linear,
+
+    /// The launch fee excess over the normal rate halves every eighth of the duration,
+    /// the last step ends at the normal rate.
+    Exponential,
+}
+
+/// Represents a launch fee schedule, taxing swaps right after the launch to discourage sniping.
+///
+/// The extra fee over the normal fee rate goes to the liquidity providers.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LaunchFeeSchedule {
+    /// The total fee rate at `open_time`, measured in basis points.
+    pub start_fee_rate_basis_points: u16, // 2 bytes
+
+    /// The time after `open_time` over which the launch fee decays to the normal fee rate.
+    pub duration_in_seconds: u32, // 4 bytes
+
+    /// How the launch fee decays.
+    pub decay_mode: LaunchFeeDecayMode, // 1 byte
+}
+
+impl LaunchFeeSchedule {
+    /// The maximum start fee rate of a launch fee schedule (50%).
+    pub const MAX_START_FEE_RATE_BASIS_POINTS: u16 = 5000;
+
+    /// The number of halvings of the launch fee excess in the exponential mode.
+    const EXPONENTIAL_HALVINGS: u64 = 8;
+
+    /// Validates the schedule.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the schedule is valid.
+    /// - `Err(ErrorCode::InvalidLaunchFeeSchedule)` if the start fee rate exceeds `MAX_START_FEE_RATE_BASIS_POINTS`
+    ///   or the duration is zero.
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.start_fee_rate_basis_points <= Self::MAX_START_FEE_RATE_BASIS_POINTS && self.duration_in_seconds > 0,
+            ErrorCode::InvalidLaunchFeeSchedule
+        );
+        Ok(())
+    }
+
+    /// Calculates the launch fee rate after `elapsed_seconds` since `open_time`.
+    ///
+    /// # Parameters
+    /// - `elapsed_seconds`: The time passed since `open_time`.
+    /// - `normal_fee_rate_basis_points`: The total fee rate the launch fee decays to, measured in basis points.
+    ///
+    /// # Returns
+    /// - The total fee rate, never lower than `normal_fee_rate_basis_points`.
+    pub fn fee_rate_basis_points_at(&self, elapsed_seconds: i64, normal_fee_rate_basis_points: u16) -> u16 {
+        let duration = self.duration_in_seconds as u64;
+        let elapsed = elapsed_seconds.max(0) as u64;
+        if elapsed >= duration || self.start_fee_rate_basis_points <= normal_fee_rate_basis_points {
+            return normal_fee_rate_basis_points;
+        }
+        let excess = (self.start_fee_rate_basis_points - normal_fee_rate_basis_points) as u64;
+        let remaining_excess = match self.decay_mode {
+            LaunchFeeDecayMode::Linear => excess * (duration - elapsed) / duration,
+            LaunchFeeDecayMode::Exponential => {
+                // Halves the excess on every step and interpolates linearly within a step
+                let scaled_elapsed = elapsed * Self::EXPONENTIAL_HALVINGS;
+                let (step, step_progress) = (scaled_elapsed / duration, scaled_elapsed % duration);
+                let step_start_excess = excess >> step;
+                let step_end_excess = if step + 1 < Self::EXPONENTIAL_HALVINGS { excess >> (step + 1) } else { 0 };
+                step_start_excess - (step_start_excess - step_end_excess) * step_progress / duration
+            }
+        };
+        normal_fee_rate_basis_points + remaining_excess as u16
+    }
+}
+
+/// Represents the fee rates applied to a swap, resolved from the `AmmsConfig` and the pool overrides.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeRates {
+    /// The liquidity providers fee rate, measured in basis points.
+    pub providers_fee_rate_basis_points: u16,
+
+    /// The protocol fee rate, measured in basis points.
+    pub protocol_fee_rate_basis_points: u16,
+
+    /// The share of the protocol fee paid to the CpAmm creator, measured in basis points.
+    pub creator_fee_share_basis_points: u16,
+
+    /// The share of the protocol fee paid to the swap referrer, measured in basis points, `0` if the swap has no referrer.
+    pub referral_fee_share_basis_points: u16,
+
+    /// Whether the protocol fee accumulates for redemption or stays in the pool liquidity.
+    pub protocol_fee_mode: ProtocolFeeMode,
+}
+
+/// Represents the canonical bumps of the `CpAmm` PDA and its vaults.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CpAmmBumps {
+    /// The canonical bump of the `CpAmm` PDA.
+    pub cp_amm: u8,
+
+    /// The canonical bump of the base vault PDA.
+    pub base_vault: u8,
+
+    /// The canonical bump of the quote vault PDA.
+    pub quote_vault: u8,
+
+    /// The canonical bump of the locked LP vault PDA.
+    pub locked_lp_vault: u8,
+}
+
+/// Represents the creator options of a `CpAmm` initialization.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CpAmmInitializeOptions {
+    /// The share of the protocol fee paid to the creator, measured in basis points.
+    pub creator_fee_share_basis_points: u16,
+
+    /// The Unix timestamp of the initialization.
+    pub initialized_at: i64,
 }
 
 impl CpAmm {
@@ -94,6 +478,9 @@ impl CpAmm {
     /// Seed used for generating the vaults PDAs.
     pub const VAULT_SEED: &'static [u8] = b"vault";
 
+    /// Time after the initialization during which only the creator can cancel an unlaunched AMM.
+    pub const LAUNCH_DEADLINE_IN_SECONDS: i64 = 7 * 24 * 60 * 60;
+
     /// Returns the seeds for generating the PDA.
     ///
     /// The PDA is derived using the `SEED`, the `lp_mint`, and the `bump` value.
@@ -107,10 +494,16 @@ impl CpAmm {
         self.is_initialized
     }
 
-    /// Checks if the AMM has been launched and is active.
+    /// Returns the lifecycle status of the AMM.
+    ///
+    /// A launched AMM reports `SwapsPaused` or `WithdrawOnly` according to its swap and provide pause flags.
     #[inline]
-    pub fn is_launched(&self) -> bool {
-        self.is_launched
+    pub fn status(&self) -> PoolStatus {
+        match self.status {
+            PoolStatus::Launched if self.is_swap_paused && self.is_provide_paused => PoolStatus::WithdrawOnly,
+            PoolStatus::Launched if self.is_swap_paused => PoolStatus::SwapsPaused,
+            status => status,
+        }
     }
 
     /// Returns the canonical bump value for the PDA.
@@ -176,6 +569,155 @@ impl CpAmm {
     pub fn locked_lp_vault_bump(&self) -> u8 {
         self.locked_lp_vault_bump[0]
     }
+
+    /// Returns the providers fee rate override of the pool, if any.
+    #[inline]
+    pub fn providers_fee_rate_basis_points_override(&self) -> Option<u16> {
+        self.providers_fee_rate_basis_points_override
+    }
+
+    /// Returns the protocol fee rate override of the pool, if any.
+    #[inline]
+    pub fn protocol_fee_rate_basis_points_override(&self) -> Option<u16> {
+        self.protocol_fee_rate_basis_points_override
+    }
+
+    /// Returns the effective providers fee rate of the pool,
+    /// falling back to the `AmmsConfig` rate when there is no override.
+    ///
+    /// The override is capped by the current `AmmsConfig` maximum fee rate less the effective protocol fee rate.
+    #[inline]
+    pub fn providers_fee_rate_basis_points(&self, amms_config: &AmmsConfig) -> u16 {
+        match self.providers_fee_rate_basis_points_override {
+            Some(providers_fee_rate_basis_points) => providers_fee_rate_basis_points.min(
+                amms_config.max_fee_rate_basis_points().saturating_sub(self.protocol_fee_rate_basis_points(amms_config))
+            ),
+            None => amms_config.providers_fee_rate_basis_points(),
+        }
+    }
+
+    /// Returns the effective protocol fee rate of the pool,
+    /// falling back to the `AmmsConfig` rate when there is no override.
+    ///
+    /// The override is capped by the current `AmmsConfig` maximum fee rate,
+    /// less the `AmmsConfig` providers fee rate when the providers fee rate isn't overridden.
+    #[inline]
+    pub fn protocol_fee_rate_basis_points(&self, amms_config: &AmmsConfig) -> u16 {
+        match self.protocol_fee_rate_basis_points_override {
+            Some(protocol_fee_rate_basis_points) => {
+                let providers_fee_rate_basis_points = match self.providers_fee_rate_basis_points_override {
+                    Some(_) => 0,
+                    None => amms_config.providers_fee_rate_basis_points(),
+                };
+                protocol_fee_rate_basis_points.min(amms_config.max_fee_rate_basis_points().saturating_sub(providers_fee_rate_basis_points))
+            }
+            None => amms_config.protocol_fee_rate_basis_points(),
+        }
+    }
+
+    /// Returns the effective share of the protocol fee paid to the CpAmm creator,
+    /// capped by the current `AmmsConfig` maximum creator fee share.
+    #[inline]
+    pub fn creator_fee_share_basis_points(&self, amms_config: &AmmsConfig) -> u16 {
+        self.creator_fee_share_basis_points.min(amms_config.max_creator_fee_share_basis_points())
+    }
+
+    /// Returns the square root of the constant product recorded for protocol fee LP tokens,
+    /// the growth above it is minted to the fee authority on the next liquidity event.
+    #[inline]
+    pub fn last_constant_product_sqrt(&self) -> Q64_128 {
+        self.last_constant_product_sqrt
+    }
+
+    /// Checks if swaps in the AMM are paused.
+    #[inline]
+    pub fn is_swap_paused(&self) -> bool {
+        self.is_swap_paused
+    }
+
+    /// Checks if providing liquidity to the AMM is paused.
+    #[inline]
+    pub fn is_provide_paused(&self) -> bool {
+        self.is_provide_paused
+    }
+
+    /// Checks if withdrawing liquidity from the AMM is paused.
+    #[inline]
+    pub fn is_withdraw_paused(&self) -> bool {
+        self.is_withdraw_paused
+    }
+
+    /// Returns the Unix timestamp of the AMM initialization.
+    #[inline]
+    pub fn initialized_at(&self) -> i64 {
+        self.initialized_at
+    }
+
+    /// Returns the Unix timestamp from which swaps are open.
+    #[inline]
+    pub fn open_time(&self) -> i64 {
+        self.open_time
+    }
+
+    /// Returns the launch fee schedule of the AMM.
+    #[inline]
+    pub fn launch_fee_schedule(&self) -> Option<LaunchFeeSchedule> {
+        self.launch_fee_schedule
+    }
+
+    /// Retrieves the version of the account layout.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.version.version()
+    }
+
+    /// Returns the optional account allowed to launch the AMM besides the creator.
+    #[inline]
+    pub fn launch_authority(&self) -> Option<Pubkey> {
+        *self.launch_authority.get()
+    }
+
+    /// Checks if the account can launch the AMM, being its creator or launch authority.
+    #[inline]
+    pub fn can_launch(&self, signer: &Pubkey) -> bool {
+        *signer == self.creator || self.launch_authority() == Some(*signer)
+    }
+
+    /// Returns the effective providers fee rate at `current_timestamp`, measured in basis points.
+    ///
+    /// While the launch fee schedule is active, the extra fee over the normal total fee rate
+    /// is added to the providers fee rate.
+    pub fn providers_fee_rate_basis_points_at(&self, amms_config: &AmmsConfig, current_timestamp: i64) -> u16 {
+        let providers_fee_rate_basis_points = self.providers_fee_rate_basis_points(amms_config);
+        let Some(launch_fee_schedule) = self.launch_fee_schedule else {
+            return providers_fee_rate_basis_points;
+        };
+        let normal_fee_rate_basis_points = providers_fee_rate_basis_points.saturating_add(self.protocol_fee_rate_basis_points(amms_config));
+        let launch_fee_rate_basis_points = launch_fee_schedule.fee_rate_basis_points_at(current_timestamp.saturating_sub(self.open_time), normal_fee_rate_basis_points);
+        providers_fee_rate_basis_points + (launch_fee_rate_basis_points - normal_fee_rate_basis_points)
+    }
+
+    /// Resolves the fee rates of a swap at `current_timestamp`.
+    ///
+    /// # Parameters
+    /// - `amms_config`: The `AmmsConfig` of the AMM.
+    /// - `current_timestamp`: The Unix timestamp of the swap.
+    /// - `has_referrer`: Whether the swap pays a share of the protocol fee to a referrer.
+    pub fn fee_rates_at(&self, amms_config: &AmmsConfig, current_timestamp: i64, has_referrer: bool) -> FeeRates {
+        FeeRates {
+            providers_fee_rate_basis_points: self.providers_fee_rate_basis_points_at(amms_config, current_timestamp),
+            protocol_fee_rate_basis_points: self.protocol_fee_rate_basis_points(amms_config),
+            creator_fee_share_basis_points: self.creator_fee_share_basis_points(amms_config),
+            referral_fee_share_basis_points: if has_referrer { amms_config.referral_fee_share_basis_points() } else { 0 },
+            protocol_fee_mode: amms_config.protocol_fee_mode(),
+        }
+    }
+
+    /// Checks if the AMM is open for trading at `current_timestamp`.
+    #[inline]
+    pub fn is_open(&self, current_timestamp: i64) -> bool {
+        current_timestamp >= self.open_time
+    }
 }
 
 /// Implements the `CpAmmCore` trait for the `CpAmm` struct.
@@ -230,7 +772,7 @@ impl CpAmm {
     /// Validates the current state of the AMM to ensure it is ready for operations.
     ///
     /// This method ensures that:
-    /// - The AMM has been launched.
+    /// - The AMM has been launched and is not closed.
     /// - The pool has non-zero base and quote liquidity.
     /// - The pool has a positive supply of LP tokens.
     ///
@@ -239,13 +781,112 @@ impl CpAmm {
     /// - `Err(ErrorCode)` if any of the checks fail.
     #[inline]
     fn check_state(&self) -> Result<()> {
-        require!(self.is_launched, ErrorCode::CpAmmNotLaunched);
-        require!(self.quote_liquidity > 0, ErrorCode::BaseLiquidityIsZero);
-        require!(self.base_liquidity > 0, ErrorCode::QuoteLiquidityIsZero);
+        require!(self.status != PoolStatus::Closed, ErrorCode::CpAmmClosed);
+        require!(self.status.is_launched(), ErrorCode::CpAmmNotLaunched);
+        require!(self.base_liquidity > 0, ErrorCode::BaseLiquidityIsZero);
+        require!(self.quote_liquidity > 0, ErrorCode::QuoteLiquidityIsZero);
         require!(self.lp_tokens_supply > 0, ErrorCode::LpTokensSupplyIsZero);
         Ok(())
     }
+
+    /// Returns the constant product square root to record after a liquidity event,
+    /// zero if the protocol fee is not taken as LP tokens.
+    #[inline]
+    fn get_last_constant_product_sqrt(constant_product_sqrt: Q64_128, protocol_fee_mode: ProtocolFeeMode) -> Q64_128 {
+        if protocol_fee_mode == ProtocolFeeMode::LpTokens {
+            constant_product_sqrt
+        }
+        else {
+            Q64_128::from_u64(0)
+        }
+    }
+
+    /// Returns the protocol fee LP tokens accrued since the last liquidity event,
+    /// zero if the protocol fee is not taken as LP tokens.
+    #[inline]
+    fn get_protocol_fee_lp_tokens_to_mint(&self, protocol_fee_mode: ProtocolFeeMode) -> Result<u64> {
+        if protocol_fee_mode != ProtocolFeeMode::LpTokens {
+            return Ok(0);
+        }
+        self.calculate_protocol_fee_lp_tokens(self.last_constant_product_sqrt).ok_or(error!(ErrorCode::LpTokensCalculationFailed))
+    }
+
+    /// Prepares the protocol fee LP tokens of a provide or withdraw.
+    ///
+    /// When they are not minted, the recorded constant product is lowered so the protocol keeps
+    /// its accrued share of the pool until a later liquidity event mints it.
+    ///
+    /// # Parameters
+    /// - `lp_tokens_to_mint`: The protocol fee LP tokens accrued since the last liquidity event.
+    /// - `mint_protocol_fee_lp_tokens`: Whether the accrued LP tokens are minted to the fee authority.
+    /// - `new_constant_product_sqrt`: The constant product square root after the liquidity event.
+    /// - `new_lp_tokens_supply`: The LP tokens supply after the liquidity event, without the accrued LP tokens if they are not minted.
+    /// - `protocol_fee_mode`: How the `AmmsConfig` of the AMM collects protocol fees.
+    fn get_protocol_fee_lp_tokens(
+        lp_tokens_to_mint: u64,
+        mint_protocol_fee_lp_tokens: bool,
+        new_constant_product_sqrt: Q64_128,
+        new_lp_tokens_supply: u64,
+        protocol_fee_mode: ProtocolFeeMode
+    ) -> Result<ProtocolFeeLpTokens> {
+        if mint_protocol_fee_lp_tokens || lp_tokens_to_mint == 0 {
+            return Ok(ProtocolFeeLpTokens {
+                lp_tokens_to_mint: if mint_protocol_fee_lp_tokens { lp_tokens_to_mint } else { 0 },
+                last_constant_product_sqrt: Self::get_last_constant_product_sqrt(new_constant_product_sqrt, protocol_fee_mode),
+            });
+        }
+        // `√k_last = √k * S / (S + x)` leaves the same `x` to mint on the next liquidity event
+        let virtual_lp_tokens_supply = new_lp_tokens_supply.checked_add(lp_tokens_to_mint).ok_or(ErrorCode::LpTokensCalculationFailed)?;
+        let last_constant_product_sqrt = Q64_128::from_u64(new_lp_tokens_supply)
+            .checked_div(Q64_128::from_u64(virtual_lp_tokens_supply))
+            .and_then(|lp_tokens_share| new_constant_product_sqrt.checked_mul(lp_tokens_share))
+            .ok_or(ErrorCode::LpTokensCalculationFailed)?;
+        Ok(ProtocolFeeLpTokens {
+            lp_tokens_to_mint: 0,
+            last_constant_product_sqrt,
+        })
+    }
     
+    /// Validates that the AMM can be cancelled, closing its vaults and account.
+    ///
+    /// The creator can cancel the AMM at any time before the launch,
+    /// anyone else only after the launch deadline has expired.
+    ///
+    /// # Parameters
+    /// - `signer`: The public key of the account cancelling the AMM.
+    /// - `current_timestamp`: The current Unix timestamp.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the AMM can be cancelled.
+    /// - `Err(ErrorCode)` if the AMM is launched or the signer is not allowed to cancel it yet.
+    pub fn check_cancel(&self, signer: &Pubkey, current_timestamp: i64) -> Result<()> {
+        require!(self.is_initialized, ErrorCode::CpAmmNotInitialized);
+        require!(self.status == PoolStatus::Initialized, ErrorCode::CpAmmAlreadyLaunched);
+        let launch_deadline = self.initialized_at.saturating_add(Self::LAUNCH_DEADLINE_IN_SECONDS);
+        require!(*signer == self.creator || current_timestamp >= launch_deadline, ErrorCode::CpAmmLaunchDeadlineNotExpired);
+        Ok(())
+    }
+
+    /// Prepares the payload for closing the AMM, sweeping its remaining reserves and closing its vaults.
+    ///
+    /// Only a drained AMM can be closed: every LP token except the initial locked liquidity must be withdrawn,
+    /// and the protocol fees must be collected. The unredeemed creator fees are paid to the creator on close.
+    ///
+    /// # Returns
+    /// - `Ok(ClosePayload)` containing the creator fees to pay before the reserves are swept.
+    /// - `Err(ErrorCode)` if the AMM is not launched, already closed, still holds providers liquidity or protocol fees.
+    pub fn get_close_payload(&self) -> Result<ClosePayload> {
+        require!(self.is_initialized, ErrorCode::CpAmmNotInitialized);
+        require!(self.status != PoolStatus::Closed, ErrorCode::CpAmmClosed);
+        require!(self.status.is_launched(), ErrorCode::CpAmmNotLaunched);
+        require!(self.lp_tokens_supply == self.initial_locked_liquidity, ErrorCode::CpAmmNotDrained);
+        require!(self.protocol_base_fees_to_redeem == 0 && self.protocol_quote_fees_to_redeem == 0, ErrorCode::CpAmmProtocolFeesNotCollected);
+        Ok(ClosePayload::new(
+            self.creator_base_fees_to_redeem,
+            self.creator_quote_fees_to_redeem
+        ))
+    }
+
     /// Prepares the payload for launching the AMM with the provided base and quote liquidity.
     ///
     /// It calculates the initial constant product, liquidity ratios, and the total supply of LP tokens to mint.
@@ -253,20 +894,22 @@ impl CpAmm {
     /// # Parameters
     /// - `base_liquidity`: The amount of base liquidity to add during the launch.
     /// - `quote_liquidity`: The amount of quote liquidity to add during the launch.
+    /// - `protocol_fee_mode`: How the `AmmsConfig` of the AMM collects protocol fees.
     ///
     /// # Returns
     /// - `Ok(LaunchPayload)` containing the calculated launch details.
     /// - `Err(ErrorCode)` if any preconditions fail or calculations encounter errors.
     #[inline(never)]
-    pub fn get_launch_payload(&self, base_liquidity: u64, quote_liquidity: u64) -> Result<LaunchPayload> {
-        require!(!self.is_launched, ErrorCode::CpAmmAlreadyLaunched);
+    pub fn get_launch_payload(&self, base_liquidity: u64, quote_liquidity: u64, protocol_fee_mode: ProtocolFeeMode) -> Result<LaunchPayload> {
         require!(self.is_initialized, ErrorCode::CpAmmNotInitialized);
+        require!(matches!(self.status, PoolStatus::Initialized | PoolStatus::BondingCurve), ErrorCode::CpAmmAlreadyLaunched);
         require!(base_liquidity > 0, ErrorCode::ProvidedBaseLiquidityIsZero);
         require!(quote_liquidity > 0, ErrorCode::ProvidedQuoteLiquidityIsZero);
 
         let constant_product_sqrt = Self::calculate_constant_product_sqrt(base_liquidity, quote_liquidity).unwrap();
         let (lp_tokens_supply, initial_locked_liquidity) = Self::calculate_launch_lp_tokens(constant_product_sqrt)?;
         let base_quote_ratio_sqrt = Self::calculate_base_quote_ratio_sqrt(base_liquidity, quote_liquidity).unwrap();
+        let last_constant_product_sqrt = Self::get_last_constant_product_sqrt(constant_product_sqrt, protocol_fee_mode);
         
         Ok(LaunchPayload {
             initial_locked_liquidity,
@@ -275,6 +918,7 @@ impl CpAmm {
             constant_product_sqrt,
             base_quote_ratio_sqrt,
             lp_tokens_supply,
+            last_constant_product_sqrt,
         })
     }
 
@@ -285,13 +929,16 @@ impl CpAmm {
     /// # Parameters
     /// - `base_liquidity`: The amount of base liquidity to provide.
     /// - `quote_liquidity`: The amount of quote liquidity to provide.
+    /// - `protocol_fee_mode`: How the `AmmsConfig` of the AMM collects protocol fees.
+    /// - `mint_protocol_fee_lp_tokens`: Whether the accrued protocol fee LP tokens are minted to the fee authority.
     ///
     /// # Returns
-    /// - `Ok(ProvidePayload)` containing the updated pool state and LP tokens to mint.
+    /// - `Ok(ProvidePayload)` containing the updated pool state, LP tokens to mint and protocol fee LP tokens to mint.
     /// - `Err(ErrorCode)` if any checks fail or calculations encounter errors.
     #[inline(never)]
-    pub fn get_provide_payload(&self, base_liquidity: u64, quote_liquidity: u64) -> Result<ProvidePayload> {
+    pub fn get_provide_payload(&self, base_liquidity: u64, quote_liquidity: u64, protocol_fee_mode: ProtocolFeeMode, mint_protocol_fee_lp_tokens: bool) -> Result<ProvidePayload> {
         self.check_state()?;
+        require!(self.status.can_provide() && !self.is_provide_paused, ErrorCode::CpAmmProvidePaused);
         require!(base_liquidity > 0, ErrorCode::ProvidedBaseLiquidityIsZero);
         require!(quote_liquidity > 0, ErrorCode::ProvidedQuoteLiquidityIsZero);
 
@@ -300,10 +947,15 @@ impl CpAmm {
         let new_base_quote_ratio_sqrt =  self.validate_and_calculate_liquidity_ratio(new_base_liquidity, new_quote_liquidity)?;
 
         let new_constant_product_sqrt = Self::calculate_constant_product_sqrt(new_base_liquidity, new_quote_liquidity).unwrap();
+
+        // Protocol fee LP tokens are accounted before the provided liquidity is measured, even when they are not minted
+        let protocol_fee_lp_tokens_to_mint = self.get_protocol_fee_lp_tokens_to_mint(protocol_fee_mode)?;
+        let lp_tokens_supply = self.lp_tokens_supply.checked_add(protocol_fee_lp_tokens_to_mint).ok_or(ErrorCode::ProvideOverflowError)?;
         
-        let lp_tokens_to_mint = self.calculate_lp_mint_for_provided_liquidity(new_constant_product_sqrt).ok_or(ErrorCode::LpTokensCalculationFailed)?;
+        let lp_tokens_to_mint = self.calculate_lp_mint_for_provided_liquidity(new_constant_product_sqrt, lp_tokens_supply).ok_or(ErrorCode::LpTokensCalculationFailed)?;
 
-        let new_lp_tokens_supply = self.lp_tokens_supply.checked_add(lp_tokens_to_mint).ok_or(ErrorCode::ProvideOverflowError)?;
+        let minted_lp_tokens_supply = if mint_protocol_fee_lp_tokens { lp_tokens_supply } else { self.lp_tokens_supply };
+        let new_lp_tokens_supply = minted_lp_tokens_supply.checked_add(lp_tokens_to_mint).ok_or(ErrorCode::ProvideOverflowError)?;
         Ok(ProvidePayload {
             base_quote_ratio_sqrt: new_base_quote_ratio_sqrt,
             constant_product: new_constant_product_sqrt,
@@ -311,6 +963,13 @@ impl CpAmm {
             quote_liquidity: new_quote_liquidity,
             lp_tokens_supply: new_lp_tokens_supply,
             lp_tokens_to_mint,
+            protocol_fee_lp_tokens: Self::get_protocol_fee_lp_tokens(
+                protocol_fee_lp_tokens_to_mint,
+                mint_protocol_fee_lp_tokens,
+                new_constant_product_sqrt,
+                new_lp_tokens_supply,
+                protocol_fee_mode
+            )?,
         })
     }
 
@@ -320,18 +979,26 @@ impl CpAmm {
     ///
     /// # Parameters
     /// - `lp_tokens`: The number of LP tokens to redeem for liquidity withdrawal.
+    /// - `protocol_fee_mode`: How the `AmmsConfig` of the AMM collects protocol fees.
+    /// - `mint_protocol_fee_lp_tokens`: Whether the accrued protocol fee LP tokens are minted to the fee authority.
     ///
     /// # Returns
-    /// - `Ok(WithdrawPayload)` containing the updated pool state and withdrawn liquidity amounts.
+    /// - `Ok(WithdrawPayload)` containing the updated pool state, withdrawn liquidity amounts and protocol fee LP tokens to mint.
     /// - `Err(ErrorCode)` if any checks fail or calculations encounter errors.
     #[inline(never)]
-    pub fn get_withdraw_payload(&self, lp_tokens: u64) -> Result<WithdrawPayload> {
+    pub fn get_withdraw_payload(&self, lp_tokens: u64, protocol_fee_mode: ProtocolFeeMode, mint_protocol_fee_lp_tokens: bool) -> Result<WithdrawPayload> {
         self.check_state()?;
+        require!(self.status.can_withdraw() && !self.is_withdraw_paused, ErrorCode::CpAmmWithdrawPaused);
         require!(lp_tokens > 0, ErrorCode::ProvidedLpTokensIsZero);
 
-        let lp_tokens_left_supply = self.lp_tokens_supply.checked_sub(lp_tokens).ok_or(ErrorCode::WithdrawOverflowError)?;
+        // Protocol fee LP tokens are accounted before the withdrawn share is measured, even when they are not minted
+        let protocol_fee_lp_tokens_to_mint = self.get_protocol_fee_lp_tokens_to_mint(protocol_fee_mode)?;
+        let lp_tokens_supply = self.lp_tokens_supply.checked_add(protocol_fee_lp_tokens_to_mint).ok_or(ErrorCode::WithdrawOverflowError)?;
+
+        let minted_lp_tokens_supply = if mint_protocol_fee_lp_tokens { lp_tokens_supply } else { self.lp_tokens_supply };
+        let lp_tokens_left_supply = minted_lp_tokens_supply.checked_sub(lp_tokens).ok_or(ErrorCode::WithdrawOverflowError)?;
 
-        let (base_withdraw, quote_withdraw) = self.calculate_liquidity_from_share(lp_tokens).ok_or(ErrorCode::WithdrawLiquidityCalculationFailed)?;
+        let (base_withdraw, quote_withdraw) = self.calculate_liquidity_from_share(lp_tokens, lp_tokens_supply).ok_or(ErrorCode::WithdrawLiquidityCalculationFailed)?;
         
         let new_base_liquidity = self.base_liquidity.checked_sub(base_withdraw).ok_or(ErrorCode::WithdrawOverflowError)?;
         let new_quote_liquidity = self.quote_liquidity.checked_sub(quote_withdraw).ok_or(ErrorCode::WithdrawOverflowError)?;
@@ -346,6 +1013,63 @@ impl CpAmm {
             lp_tokens_supply: lp_tokens_left_supply,
             base_withdraw_amount: base_withdraw,
             quote_withdraw_amount: quote_withdraw,
+            protocol_fee_lp_tokens: Self::get_protocol_fee_lp_tokens(
+                protocol_fee_lp_tokens_to_mint,
+                mint_protocol_fee_lp_tokens,
+                Self::calculate_constant_product_sqrt(new_base_liquidity, new_quote_liquidity).ok_or(ErrorCode::ConstantProductCalculationFailed)?,
+                lp_tokens_left_supply,
+                protocol_fee_mode
+            )?,
+        })
+    }
+
+    /// Prepares the payload for donating liquidity to the AMM.
+    ///
+    /// Donated liquidity is added to the reserves without minting LP tokens, raising the value of every LP share.
+    /// The recorded constant product for protocol fee LP tokens grows with the donation,
+    /// so the protocol doesn't take a share of it.
+    ///
+    /// # Parameters
+    /// - `base_liquidity`: The amount of base tokens to donate.
+    /// - `quote_liquidity`: The amount of quote tokens to donate.
+    /// - `donation_ratio_policy`: Which donations the `AmmsConfig` of the AMM accepts.
+    ///
+    /// # Returns
+    /// - `Ok(DonatePayload)` containing the updated pool state.
+    /// - `Err(ErrorCode)` if donations are disabled, the donation is empty or it changes the ratio beyond the policy.
+    #[inline(never)]
+    pub fn get_donate_payload(&self, base_liquidity: u64, quote_liquidity: u64, donation_ratio_policy: DonationRatioPolicy) -> Result<DonatePayload> {
+        self.check_state()?;
+        require!(self.status.can_provide() && !self.is_provide_paused, ErrorCode::CpAmmProvidePaused);
+
+        let new_base_liquidity = self.base_liquidity.checked_add(base_liquidity).ok_or(ErrorCode::ProvideOverflowError)?;
+        let new_quote_liquidity = self.quote_liquidity.checked_add(quote_liquidity).ok_or(ErrorCode::ProvideOverflowError)?;
+
+        let new_base_quote_ratio_sqrt = match donation_ratio_policy {
+            DonationRatioPolicy::Proportional => {
+                require!(base_liquidity > 0, ErrorCode::ProvidedBaseLiquidityIsZero);
+                require!(quote_liquidity > 0, ErrorCode::ProvidedQuoteLiquidityIsZero);
+                self.validate_and_calculate_liquidity_ratio(new_base_liquidity, new_quote_liquidity)?
+            },
+            DonationRatioPolicy::AnyRatio => {
+                require!(base_liquidity > 0 || quote_liquidity > 0, ErrorCode::DonationIsZero);
+                Self::calculate_base_quote_ratio_sqrt(new_base_liquidity, new_quote_liquidity).ok_or(ErrorCode::BaseQuoteRatioCalculationFailed)?
+            },
+            DonationRatioPolicy::Disabled => return err!(ErrorCode::DonationsDisabled),
+        };
+
+        let new_constant_product_sqrt = Self::calculate_constant_product_sqrt(new_base_liquidity, new_quote_liquidity).ok_or(ErrorCode::ConstantProductCalculationFailed)?;
+        let new_last_constant_product_sqrt = self.last_constant_product_sqrt
+            .checked_mul(new_constant_product_sqrt)
+            .and_then(|value| value.checked_div(self.constant_product_sqrt))
+            .ok_or(ErrorCode::ProvideOverflowError)?;
+
+        Ok(DonatePayload {
+            base_quote_ratio_sqrt: new_base_quote_ratio_sqrt,
+            constant_product: new_constant_product_sqrt,
+            base_liquidity: new_base_liquidity,
+            quote_liquidity: new_quote_liquidity,
+            last_constant_product_sqrt: new_last_constant_product_sqrt,
         })
     }
 
@@ -359,16 +1083,23 @@ impl CpAmm {
     /// - `swap_amount`: The amount of tokens being swapped (either base or quote).
     /// - `estimated_result`: Expected amount of tokens to receive after the swap.
     /// - `allowed_slippage`: Maximum permissible deviation from `estimated_result`.
-    /// - `providers_fee_rate_basis_points`: The liquidity provider's fee rate in basis points.
-    /// - `protocol_fee_rate_basis_points`: The protocol fee rate in basis points.
+    /// - `fee_rates`: The providers and protocol fee rates with the creator and referral shares of the protocol fee.
     /// - `is_in_out`: `true` if swapping **base → quote**, `false` if swapping **quote → base**.
     ///
     /// # Returns
     /// - `Ok(SwapPayload)`: Contains the updated liquidity state and fees.
     /// - `Err(ErrorCode)`: If any validation fails (e.g., insufficient liquidity, overflow, or slippage exceeded).
     #[inline(never)]
-    pub fn get_swap_payload(&self, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, providers_fee_rate_basis_points: u16, protocol_fee_rate_basis_points: u16, is_in_out: bool) -> Result<SwapPayload> {
+    pub fn get_swap_payload(&self, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, fee_rates: FeeRates, is_in_out: bool) -> Result<SwapPayload> {
+        let FeeRates {
+            providers_fee_rate_basis_points,
+            protocol_fee_rate_basis_points,
+            creator_fee_share_basis_points,
+            referral_fee_share_basis_points,
+            protocol_fee_mode,
+        } = fee_rates;
         self.check_state()?;
+        require!(self.status.can_swap() && !self.is_swap_paused, ErrorCode::CpAmmSwapPaused);
         require!(swap_amount > 0, ErrorCode::SwapAmountIsZero);
         require!(estimated_result > 0, ErrorCode::EstimatedResultIsZero);
         require!(providers_fee_rate_basis_points + protocol_fee_rate_basis_points <= 10000, ErrorCode::ConfigFeeRateExceeded);
@@ -378,17 +1109,31 @@ impl CpAmm {
 
         let protocol_fee_amount = Self::calculate_fee_amount(swap_amount, protocol_fee_rate_basis_points);
         require!(protocol_fee_amount > 0 || protocol_fee_rate_basis_points == 0, ErrorCode::SwapFeesAreZero);
+
+        // The referrer's share is carved out of the protocol fee first, the creator's share out of the rest
+        let referral_fee_amount = Self::calculate_fee_amount(protocol_fee_amount, referral_fee_share_basis_points);
+        let protocol_fee_after_referral_amount = protocol_fee_amount.checked_sub(referral_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
+        let creator_fee_amount = Self::calculate_fee_amount(protocol_fee_after_referral_amount, creator_fee_share_basis_points);
+        let protocol_net_fee_amount = protocol_fee_after_referral_amount.checked_sub(creator_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
+
+        // In LP tokens mode the net protocol fee stays in the pool liquidity alongside the providers fee
+        let (protocol_fees_to_accumulate, liquidity_fee_amount) = match protocol_fee_mode {
+            ProtocolFeeMode::Accumulators => (protocol_net_fee_amount, providers_fee_amount),
+            ProtocolFeeMode::LpTokens => (0, providers_fee_amount.checked_add(protocol_net_fee_amount).ok_or(ErrorCode::SwapOverflowError)?),
+        };
         
-        let (new_base_liquidity, new_quote_liquidity, amount_to_withdraw, protocol_fees_to_redeem);
+        let (new_base_liquidity, new_quote_liquidity, amount_to_withdraw, protocol_fees_to_redeem, creator_fees_to_redeem);
         
         if is_in_out {
-            protocol_fees_to_redeem = self.protocol_base_fees_to_redeem.checked_add(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
+            protocol_fees_to_redeem = self.protocol_base_fees_to_redeem.checked_add(protocol_fees_to_accumulate).ok_or(ErrorCode::SwapOverflowError)?;
+            creator_fees_to_redeem = self.creator_base_fees_to_redeem.checked_add(creator_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
             let base_amount_after_fees = swap_amount.checked_sub(providers_fee_amount).unwrap().checked_sub(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
             (new_base_liquidity, new_quote_liquidity) = self.calculate_afterswap_liquidity(base_amount_after_fees, true).ok_or(ErrorCode::AfterswapCalculationFailed)?;
             amount_to_withdraw = self.quote_liquidity.checked_sub(new_quote_liquidity).ok_or(ErrorCode::SwapOverflowError)?;
         }
         else{
-            protocol_fees_to_redeem = self.protocol_quote_fees_to_redeem.checked_add(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
+            protocol_fees_to_redeem = self.protocol_quote_fees_to_redeem.checked_add(protocol_fees_to_accumulate).ok_or(ErrorCode::SwapOverflowError)?;
+            creator_fees_to_redeem = self.creator_quote_fees_to_redeem.checked_add(creator_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
             let quote_amount_after_fees = swap_amount.checked_sub(providers_fee_amount).unwrap().checked_sub(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
             (new_base_liquidity, new_quote_liquidity) = self.calculate_afterswap_liquidity(quote_amount_after_fees, false).ok_or(ErrorCode::AfterswapCalculationFailed)?;
             amount_to_withdraw = self.base_liquidity.checked_sub(new_base_liquidity).ok_or(ErrorCode::SwapOverflowError)?;
@@ -397,17 +1142,149 @@ impl CpAmm {
         // Check constant product change is in acceptable range
         self.validate_swap_constant_product(new_base_liquidity, new_quote_liquidity)?;
         Self::check_swap_result(amount_to_withdraw, estimated_result, allowed_slippage)?;
+
+        // Only the net protocol fee retained in the liquidity is left above the recorded constant product
+        let (base_liquidity_with_fee, quote_liquidity_with_fee) = if is_in_out {
+            (new_base_liquidity.checked_add(liquidity_fee_amount).ok_or(ErrorCode::SwapOverflowError)?, new_quote_liquidity)
+        }
+        else {
+            (new_base_liquidity, new_quote_liquidity.checked_add(liquidity_fee_amount).ok_or(ErrorCode::SwapOverflowError)?)
+        };
+        let protocol_liquidity_fee_amount = liquidity_fee_amount - providers_fee_amount;
+        let last_constant_product_sqrt = self.calculate_last_constant_product_sqrt_after_swap(
+            self.last_constant_product_sqrt,
+            Self::calculate_constant_product_sqrt(base_liquidity_with_fee, quote_liquidity_with_fee).ok_or(ErrorCode::ConstantProductCalculationFailed)?,
+            liquidity_fee_amount,
+            protocol_liquidity_fee_amount
+        ).ok_or(ErrorCode::ConstantProductCalculationFailed)?;
         
         Ok(SwapPayload::new(
             new_base_liquidity,
             new_quote_liquidity,
-            protocol_fees_to_redeem,
-            providers_fee_amount,
+            SwapFees {
+                protocol_fees_to_redeem,
+                creator_fees_to_redeem,
+                referral_fee_amount,
+                liquidity_fee_amount,
+            },
             amount_to_withdraw,
+            last_constant_product_sqrt,
             is_in_out,
         ))
     }
 
+    /// Compares the vault balances of the AMM with its recorded reserves plus the protocol and creator fees to redeem.
+    ///
+    /// # Parameters
+    /// - `base_vault_balance`: The amount of base tokens held by the base vault.
+    /// - `quote_vault_balance`: The amount of quote tokens held by the quote vault.
+    ///
+    /// # Returns
+    /// - `Ok(ReservesDiscrepancy)` with the surplus or shortfall of each vault.
+    /// - `Err(ErrorCode)` if the AMM is not launched or is closed.
+    pub fn get_reserves_discrepancy(&self, base_vault_balance: u64, quote_vault_balance: u64) -> Result<ReservesDiscrepancy> {
+        self.check_state()?;
+        let expected_base_balance = self.base_liquidity
+            .checked_add(self.protocol_base_fees_to_redeem)
+            .and_then(|value| value.checked_add(self.creator_base_fees_to_redeem))
+            .ok_or(ErrorCode::ReservesDiscrepancyCalculationFailed)?;
+        let expected_quote_balance = self.quote_liquidity
+            .checked_add(self.protocol_quote_fees_to_redeem)
+            .and_then(|value| value.checked_add(self.creator_quote_fees_to_redeem))
+            .ok_or(ErrorCode::ReservesDiscrepancyCalculationFailed)?;
+
+        Ok(ReservesDiscrepancy {
+            base_surplus: base_vault_balance.saturating_sub(expected_base_balance),
+            quote_surplus: quote_vault_balance.saturating_sub(expected_quote_balance),
+            base_shortfall: expected_base_balance.saturating_sub(base_vault_balance),
+            quote_shortfall: expected_quote_balance.saturating_sub(quote_vault_balance),
+        })
+    }
+
+    /// Recomputes the invariants of the AMM against its vaults and LP mint.
+    ///
+    /// # Parameters
+    /// - `base_vault_balance`: The amount of base tokens held by the base vault.
+    /// - `quote_vault_balance`: The amount of quote tokens held by the quote vault.
+    /// - `lp_mint_supply`: The supply of the LP mint.
+    /// - `locked_lp_vault_balance`: The amount of LP tokens held by the locked LP vault.
+    ///
+    /// # Returns
+    /// - `Ok(())` if every invariant holds.
+    /// - `Err(ErrorCode)` of the first violated invariant.
+    pub fn verify(&self, base_vault_balance: u64, quote_vault_balance: u64, lp_mint_supply: u64, locked_lp_vault_balance: u64) -> Result<()> {
+        let reserves_discrepancy = self.get_reserves_discrepancy(base_vault_balance, quote_vault_balance)?;
+        require!(reserves_discrepancy.base_shortfall == 0, ErrorCode::BaseVaultBalanceBelowReserves);
+        require!(reserves_discrepancy.quote_shortfall == 0, ErrorCode::QuoteVaultBalanceBelowReserves);
+        require!(lp_mint_supply == self.lp_tokens_supply, ErrorCode::LpTokensSupplyMismatch);
+        require!(
+            Self::calculate_constant_product_sqrt(self.base_liquidity, self.quote_liquidity).is_some_and(|constant_product_sqrt| constant_product_sqrt == self.constant_product_sqrt),
+            ErrorCode::ConstantProductMismatch
+        );
+        require!(
+            Self::calculate_base_quote_ratio_sqrt(self.base_liquidity, self.quote_liquidity).is_some_and(|base_quote_ratio_sqrt| base_quote_ratio_sqrt == self.base_quote_ratio_sqrt),
+            ErrorCode::BaseQuoteRatioMismatch
+        );
+        // Anyone can send LP tokens to the locked LP vault, so only missing ones are a violation
+        require!(locked_lp_vault_balance >= self.initial_locked_liquidity, ErrorCode::LockedLiquidityMismatch);
+        Ok(())
+    }
+
+    /// Recomputes the invariants of a closed AMM, whose vaults no longer exist.
+    ///
+    /// # Parameters
+    /// - `lp_mint_supply`: The supply of the LP mint.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the reserves, fees, prices and LP tokens of the AMM are cleared.
+    /// - `Err(ErrorCode)` of the first violated invariant.
+    pub fn verify_closed(&self, lp_mint_supply: u64) -> Result<()> {
+        require!(self.status == PoolStatus::Closed, ErrorCode::CpAmmNotClosed);
+        require!(lp_mint_supply == 0 && self.lp_tokens_supply == 0 && self.initial_locked_liquidity == 0, ErrorCode::LpTokensSupplyMismatch);
+        require!(
+            self.base_liquidity == 0 && self.protocol_base_fees_to_redeem == 0 && self.creator_base_fees_to_redeem == 0,
+            ErrorCode::BaseVaultBalanceBelowReserves
+        );
+        require!(
+            self.quote_liquidity == 0 && self.protocol_quote_fees_to_redeem == 0 && self.creator_quote_fees_to_redeem == 0,
+            ErrorCode::QuoteVaultBalanceBelowReserves
+        );
+        require!(self.constant_product_sqrt.is_zero() && self.last_constant_product_sqrt.is_zero(), ErrorCode::ConstantProductMismatch);
+        require!(self.base_quote_ratio_sqrt.is_zero(), ErrorCode::BaseQuoteRatioMismatch);
+        Ok(())
+    }
+
+    /// Prepares the payload for folding the vaults surplus into the reserves of the AMM.
+    ///
+    /// The surplus is added the same way as a donation of any ratio, a shortfall is left as is.
+    ///
+    /// # Parameters
+    /// - `reserves_discrepancy`: The discrepancy returned by `get_reserves_discrepancy`.
+    ///
+    /// # Returns
+    /// - `Ok(DonatePayload)` containing the updated pool state, unchanged if there is no surplus.
+    /// - `Err(ErrorCode)` if any checks fail or calculations encounter errors.
+    pub fn get_sync_payload(&self, reserves_discrepancy: &ReservesDiscrepancy) -> Result<DonatePayload> {
+        self.check_state()?;
+        let new_base_liquidity = self.base_liquidity.checked_add(reserves_discrepancy.base_surplus).ok_or(ErrorCode::ProvideOverflowError)?;
+        let new_quote_liquidity = self.quote_liquidity.checked_add(reserves_discrepancy.quote_surplus).ok_or(ErrorCode::ProvideOverflowError)?;
+
+        let new_base_quote_ratio_sqrt = Self::calculate_base_quote_ratio_sqrt(new_base_liquidity, new_quote_liquidity).ok_or(ErrorCode::BaseQuoteRatioCalculationFailed)?;
+        let new_constant_product_sqrt = Self::calculate_constant_product_sqrt(new_base_liquidity, new_quote_liquidity).ok_or(ErrorCode::ConstantProductCalculationFailed)?;
+        let new_last_constant_product_sqrt = self.last_constant_product_sqrt
+            .checked_mul(new_constant_product_sqrt)
+            .and_then(|value| value.checked_div(self.constant_product_sqrt))
+            .ok_or(ErrorCode::ProvideOverflowError)?;
+
+        Ok(DonatePayload {
+            base_quote_ratio_sqrt: new_base_quote_ratio_sqrt,
+            constant_product: new_constant_product_sqrt,
+            base_liquidity: new_base_liquidity,
+            quote_liquidity: new_quote_liquidity,
+            last_constant_product_sqrt: new_last_constant_product_sqrt,
+        })
+    }
+
     /// Prepares the payload for collecting protocol fees from the AMM.
     ///
     /// This method checks if there are any protocol fees available for redemption and creates
@@ -426,6 +1303,25 @@ impl CpAmm {
             0
         ))
     }
+
+    /// Prepares the payload for collecting creator fees from the AMM.
+    ///
+    /// This method checks if there are any creator fees available for redemption and creates
+    /// a `CollectCreatorFeesPayload` containing the amounts of base and quote token fees.
+    ///
+    /// # Returns
+    /// - `Ok(CollectCreatorFeesPayload)`: Contains the creator fees available for redemption for both base and quote tokens.
+    /// - `Err(ErrorCode::CreatorFeesIsZero)`: If both `creator_base_fees_to_redeem` and `creator_quote_fees_to_redeem` are zero, meaning no fees are available to collect.
+    #[inline(never)]
+    pub fn get_collect_creator_fees_payload(&self) -> Result<CollectCreatorFeesPayload>{
+        require!(self.creator_base_fees_to_redeem > 0 || self.creator_quote_fees_to_redeem > 0, ErrorCode::CreatorFeesIsZero);
+        Ok(CollectCreatorFeesPayload::new(
+            self.creator_base_fees_to_redeem,
+            self.creator_quote_fees_to_redeem,
+            0,
+            0
+        ))
+    }
 }
 
 impl CpAmm {
@@ -444,14 +1340,12 @@ impl CpAmm {
     /// - `base_vault`: Pubkey of the vault holding the base tokens.
     /// - `quote_vault`: Pubkey of the vault holding the quote tokens.
     /// - `locked_lp_vault`: Pubkey of the vault holding locked LP tokens.
-    /// - `bump`: The canonical bump seed for the AMM's PDA.
-    /// - `base_vault_bump`: The canonical bump seed for the AMM's PDA.
-    /// - `quote_vault_bump`: The canonical bump seed for the AMM's PDA.
-    /// - `locked_lp_vault_bump`: The canonical bump seed for the AMM's PDA.
+    /// - `bumps`: The canonical bumps of the AMM's PDA and its vaults.
+    /// - `options`: The creator fee share and the timestamp of the initialization.
     ///
     /// # Returns
     /// - `Ok(())` if the initialization is successful.
-    /// - `Err(ErrorCode)` if the AMM is already initialized.
+    /// - `Err(ErrorCode)` if the AMM is already initialized or the creator fee share exceeds the `AmmsConfig` maximum.
     #[inline(never)]
     pub fn initialize(
         &mut self,
@@ -463,15 +1357,14 @@ impl CpAmm {
         base_vault: &AccountInfo,
         quote_vault: &AccountInfo,
         locked_lp_vault: &AccountInfo,
-        bump: u8,
-        base_vault_bump: u8,
-        quote_vault_bump: u8,
-        locked_lp_vault_bump: u8
+        bumps: CpAmmBumps,
+        options: CpAmmInitializeOptions
     ) -> Result<()>{
         require!(!self.is_initialized, ErrorCode::CpAmmAlreadyInitialized);
+        require!(options.creator_fee_share_basis_points <= amms_config.max_creator_fee_share_basis_points(), ErrorCode::CreatorFeeShareExceeded);
 
         self.is_initialized = true;
-        self.is_launched = false;
+        self.status = PoolStatus::Initialized;
 
         self.base_mint = base_mint.key();
         self.quote_mint = quote_mint.key();
@@ -482,10 +1375,15 @@ impl CpAmm {
         self.quote_vault = quote_vault.key();
         self.locked_lp_vault = locked_lp_vault.key();
 
-        self.bump = [bump];
-        self.base_vault_bump = [base_vault_bump];
-        self.quote_vault_bump = [quote_vault_bump];
-        self.locked_lp_vault_bump = [locked_lp_vault_bump];
+        self.bump = [bumps.cp_amm];
+        self.base_vault_bump = [bumps.base_vault];
+        self.quote_vault_bump = [bumps.quote_vault];
+        self.locked_lp_vault_bump = [bumps.locked_lp_vault];
+
+        self.creator_fee_share_basis_points = options.creator_fee_share_basis_points;
+        self.initialized_at = options.initialized_at;
+        self.version = AccountVersion::current();
+        self.launch_authority = VersionedField::default();
 
         Ok(())
     }
@@ -497,18 +1395,23 @@ impl CpAmm {
     ///
     /// # Parameters
     /// - `launch_payload`: Contains the initial liquidity, LP token supply, and ratios.
+    /// - `open_time`: The Unix timestamp from which swaps are open, a past timestamp opens the AMM immediately.
+    /// - `launch_fee_schedule`: The optional launch fee schedule, validated by the caller.
     ///
     /// # Returns
     /// - No return value. Modifies the internal state of the AMM.
     #[inline(never)]
-    pub(crate) fn launch(&mut self, launch_payload: LaunchPayload) -> (){
-        self.is_launched = true;
+    pub(crate) fn launch(&mut self, launch_payload: LaunchPayload, open_time: i64, launch_fee_schedule: Option<LaunchFeeSchedule>) -> (){
+        self.status = PoolStatus::Launched;
+        self.open_time = open_time;
+        self.launch_fee_schedule = launch_fee_schedule;
         self.base_liquidity = launch_payload.base_liquidity;
         self.quote_liquidity = launch_payload.quote_liquidity;
         self.initial_locked_liquidity = launch_payload.initial_locked_liquidity;
         self.lp_tokens_supply = launch_payload.lp_tokens_supply;
         self.constant_product_sqrt = launch_payload.constant_product_sqrt;
         self.base_quote_ratio_sqrt = launch_payload.base_quote_ratio_sqrt;
+        self.last_constant_product_sqrt = launch_payload.last_constant_product_sqrt;
     }
 
     /// Updates the AMM state after liquidity is provided.
@@ -528,6 +1431,7 @@ impl CpAmm {
         self.lp_tokens_supply = provide_payload.lp_tokens_supply;
         self.constant_product_sqrt = provide_payload.constant_product;
         self.base_quote_ratio_sqrt = provide_payload.base_quote_ratio_sqrt;
+        self.last_constant_product_sqrt = provide_payload.protocol_fee_lp_tokens.last_constant_product_sqrt;
     }
 
     /// Updates the AMM state after liquidity is withdrawn.
@@ -547,6 +1451,20 @@ impl CpAmm {
         self.lp_tokens_supply = withdraw_payload.lp_tokens_supply;
         self.constant_product_sqrt = Self::calculate_constant_product_sqrt(self.base_liquidity, self.quote_liquidity).unwrap();
         self.base_quote_ratio_sqrt = withdraw_payload.base_quote_ratio_sqrt;
+        self.last_constant_product_sqrt = withdraw_payload.protocol_fee_lp_tokens.last_constant_product_sqrt;
+    }
+
+    /// Updates the AMM state after liquidity is donated.
+    ///
+    /// # Parameters
+    /// - `donate_payload`: Contains the new liquidity amounts and ratios.
+    #[inline(never)]
+    pub(crate) fn donate(&mut self, donate_payload: DonatePayload) {
+        self.base_liquidity = donate_payload.base_liquidity;
+        self.quote_liquidity = donate_payload.quote_liquidity;
+        self.constant_product_sqrt = donate_payload.constant_product;
+        self.base_quote_ratio_sqrt = donate_payload.base_quote_ratio_sqrt;
+        self.last_constant_product_sqrt = donate_payload.last_constant_product_sqrt;
     }
 
     /// Updates the AMM state after a token swap operation.
@@ -565,15 +1483,123 @@ impl CpAmm {
         self.base_liquidity = swap_payload.base_liquidity;
         self.quote_liquidity = swap_payload.quote_liquidity;
         if swap_payload.is_in_out{
-            self.protocol_base_fees_to_redeem = swap_payload.protocol_fees_to_redeem;
-            self.base_liquidity += swap_payload.providers_fee_amount
+            self.protocol_base_fees_to_redeem = swap_payload.fees.protocol_fees_to_redeem;
+            self.creator_base_fees_to_redeem = swap_payload.fees.creator_fees_to_redeem;
+            self.base_liquidity += swap_payload.fees.liquidity_fee_amount
         }
         else{
-            self.protocol_quote_fees_to_redeem = swap_payload.protocol_fees_to_redeem;
-            self.quote_liquidity += swap_payload.providers_fee_amount
+            self.protocol_quote_fees_to_redeem = swap_payload.fees.protocol_fees_to_redeem;
+            self.creator_quote_fees_to_redeem = swap_payload.fees.creator_fees_to_redeem;
+            self.quote_liquidity += swap_payload.fees.liquidity_fee_amount
         }
         self.constant_product_sqrt = Self::calculate_constant_product_sqrt(self.base_liquidity, self.quote_liquidity).unwrap();
         self.base_quote_ratio_sqrt = Self::calculate_base_quote_ratio_sqrt(self.base_liquidity, self.quote_liquidity).unwrap();
+        self.last_constant_product_sqrt = swap_payload.last_constant_product_sqrt;
+    }
+
+    /// Marks the AMM as closed after its reserves are swept and its vaults are closed.
+    ///
+    /// The liquidity, LP tokens supply, prices and unredeemed fees are reset, since the vaults no longer exist
+    /// and the locked liquidity is burned.
+    ///
+    /// # Returns
+    /// - No return value. Modifies the internal state of the AMM.
+    pub(crate) fn mark_closed(&mut self) {
+        self.status = PoolStatus::Closed;
+        self.base_liquidity = 0;
+        self.quote_liquidity = 0;
+        self.lp_tokens_supply = 0;
+        self.initial_locked_liquidity = 0;
+        self.constant_product_sqrt = Q64_128::from_u64(0);
+        self.base_quote_ratio_sqrt = Q64_128::from_u64(0);
+        self.last_constant_product_sqrt = Q64_128::from_u64(0);
+        self.protocol_base_fees_to_redeem = 0;
+        self.protocol_quote_fees_to_redeem = 0;
+        self.creator_base_fees_to_redeem = 0;
+        self.creator_quote_fees_to_redeem = 0;
+    }
+
+    /// Moves the AMM to its bonding curve, which launches it on graduation.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the AMM now trades on its bonding curve.
+    /// - `Err(ErrorCode)` if the AMM is not initialized or no longer waiting for its launch.
+    pub(crate) fn start_bonding_curve(&mut self) -> Result<()> {
+        require!(self.is_initialized, ErrorCode::CpAmmNotInitialized);
+        require!(self.status == PoolStatus::Initialized, ErrorCode::CpAmmAlreadyLaunched);
+        self.status = PoolStatus::BondingCurve;
+        Ok(())
+    }
+
+    /// Accumulates the quote fee of a bonding curve swap as the protocol fee to redeem after the launch.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the fee is accumulated.
+    /// - `Err(ErrorCode::SwapOverflowError)` if the protocol fees overflow.
+    pub(crate) fn add_bonding_curve_fee(&mut self, quote_fee_amount: u64) -> Result<()> {
+        self.protocol_quote_fees_to_redeem = self.protocol_quote_fees_to_redeem.checked_add(quote_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
+        Ok(())
+    }
+
+    /// Updates the launch authority of the AMM.
+    ///
+    /// # Parameters
+    /// - `launch_authority`: The new optional account allowed to launch the AMM besides the creator.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the launch authority is updated.
+    /// - `Err(ErrorCode::CpAmmAlreadyLaunched)` if the AMM is no longer waiting for its launch.
+    pub(crate) fn update_launch_authority(&mut self, launch_authority: Option<Pubkey>) -> Result<()> {
+        require!(self.status == PoolStatus::Initialized, ErrorCode::CpAmmAlreadyLaunched);
+        self.launch_authority = VersionedField::new(launch_authority);
+        Ok(())
+    }
+
+    /// Updates the pause flags of the AMM.
+    ///
+    /// The flags switch individual operations off on top of the AMM status.
+    /// Pausing swaps and provides while leaving withdrawals open puts the AMM in the `WithdrawOnly` status,
+    /// so liquidity providers can still exit.
+    ///
+    /// # Parameters
+    /// - `is_swap_paused`: Whether swaps are paused.
+    /// - `is_provide_paused`: Whether providing liquidity is paused.
+    /// - `is_withdraw_paused`: Whether withdrawing liquidity is paused.
+    pub(crate) fn update_pause_flags(&mut self, is_swap_paused: bool, is_provide_paused: bool, is_withdraw_paused: bool) {
+        self.is_swap_paused = is_swap_paused;
+        self.is_provide_paused = is_provide_paused;
+        self.is_withdraw_paused = is_withdraw_paused;
+    }
+
+    /// Updates the fee rates overrides of the AMM.
+    ///
+    /// Passing `None` for a rate removes its override, so the pool falls back to the `AmmsConfig` rate.
+    /// If the `AmmsConfig` maximum fee rate is lowered later, the overrides are clamped to it when the fees are computed.
+    ///
+    /// # Parameters
+    /// - `providers_fee_rate_basis_points_override`: The new providers fee rate override, measured in basis points.
+    /// - `protocol_fee_rate_basis_points_override`: The new protocol fee rate override, measured in basis points.
+    /// - `amms_config`: The `AmmsConfig` account of the AMM, which bounds the resulting total fee rate.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the overrides are updated.
+    /// - `Err(ErrorCode::FeeRateOverrideExceeded)` if the effective total fee rate exceeds
+    ///   the `AmmsConfig` maximum fee rate.
+    pub(crate) fn update_fee_rates_override(
+        &mut self,
+        providers_fee_rate_basis_points_override: Option<u16>,
+        protocol_fee_rate_basis_points_override: Option<u16>,
+        amms_config: &AmmsConfig
+    ) -> Result<()> {
+        let providers_fee_rate_basis_points = providers_fee_rate_basis_points_override.unwrap_or(amms_config.providers_fee_rate_basis_points());
+        let protocol_fee_rate_basis_points = protocol_fee_rate_basis_points_override.unwrap_or(amms_config.protocol_fee_rate_basis_points());
+        require!(
+            providers_fee_rate_basis_points as u32 + protocol_fee_rate_basis_points as u32 <= amms_config.max_fee_rate_basis_points() as u32,
+            ErrorCode::FeeRateOverrideExceeded
+        );
+        self.providers_fee_rate_basis_points_override = providers_fee_rate_basis_points_override;
+        self.protocol_fee_rate_basis_points_override = protocol_fee_rate_basis_points_override;
+        Ok(())
     }
 
     /// Updates the protocol fees for the AMM based on the provided payload.
@@ -593,6 +1619,23 @@ impl CpAmm {
         self.protocol_quote_fees_to_redeem = collect_fees_payload.new_protocol_quote_fees_to_redeem;
     }
 
+    /// Updates the creator fees for the AMM based on the provided payload.
+    ///
+    /// This method sets the creator fees available for redemption to the updated values
+    /// specified in the `CollectCreatorFeesPayload`.
+    ///
+    /// # Parameters
+    /// - `collect_creator_fees_payload`: A `CollectCreatorFeesPayload` containing the updated creator fees
+    ///   for both base and quote tokens.
+    ///
+    /// # Returns
+    /// - None. This method directly modifies the internal state of the AMM.
+    #[inline(never)]
+    pub(crate) fn collect_creator_fees(&mut self, collect_creator_fees_payload: CollectCreatorFeesPayload) {
+        self.creator_base_fees_to_redeem = collect_creator_fees_payload.new_creator_base_fees_to_redeem;
+        self.creator_quote_fees_to_redeem = collect_creator_fees_payload.new_creator_quote_fees_to_redeem;
+    }
+
 }
 
 #[cfg(test)]
@@ -604,7 +1647,7 @@ mod cp_amm_tests {
     #[derive(Default)]
     struct CpAmmBuilder {
         is_initialized: bool,
-        is_launched: bool,
+        status: PoolStatus,
         initial_locked_liquidity: u64,
         constant_product_sqrt: Q64_128,
         base_quote_ratio_sqrt: Q64_128,
@@ -613,6 +1656,8 @@ mod cp_amm_tests {
         lp_tokens_supply: u64,
         protocol_base_fees_to_redeem: u64,
         protocol_quote_fees_to_redeem: u64,
+        creator_base_fees_to_redeem: u64,
+        creator_quote_fees_to_redeem: u64,
         base_mint: Pubkey,
         quote_mint: Pubkey,
         lp_mint: Pubkey,
@@ -624,7 +1669,18 @@ mod cp_amm_tests {
         bump: [u8; 1],
         base_vault_bump: [u8; 1],
         quote_vault_bump: [u8; 1],
-        locked_lp_vault_bump: [u8; 1]
+        locked_lp_vault_bump: [u8; 1],
+        providers_fee_rate_basis_points_override: Option<u16>,
+        protocol_fee_rate_basis_points_override: Option<u16>,
+        creator_fee_share_basis_points: u16,
+        last_constant_product_sqrt: Q64_128,
+        is_swap_paused: bool,
+        is_provide_paused: bool,
+        is_withdraw_paused: bool,
+        initialized_at: i64,
+        open_time: i64,
+        launch_fee_schedule: Option<LaunchFeeSchedule>,
+        launch_authority: Option<Pubkey>,
     }
 
     impl CpAmmBuilder {
@@ -639,8 +1695,8 @@ mod cp_amm_tests {
             self
         }
 
-        fn is_launched(mut self, value: bool) -> Self {
-            self.is_launched = value;
+        fn status(mut self, value: PoolStatus) -> Self {
+            self.status = value;
             self
         }
 
@@ -684,6 +1740,16 @@ mod cp_amm_tests {
             self
         }
 
+        fn creator_base_fees_to_redeem(mut self, value: u64) -> Self {
+            self.creator_base_fees_to_redeem = value;
+            self
+        }
+
+        fn creator_quote_fees_to_redeem(mut self, value: u64) -> Self {
+            self.creator_quote_fees_to_redeem = value;
+            self
+        }
+
         fn base_mint(mut self, value: Pubkey) -> Self {
             self.base_mint = value;
             self
@@ -743,10 +1809,65 @@ mod cp_amm_tests {
             self
         }
 
+        fn providers_fee_rate_basis_points_override(mut self, value: Option<u16>) -> Self {
+            self.providers_fee_rate_basis_points_override = value;
+            self
+        }
+
+        fn protocol_fee_rate_basis_points_override(mut self, value: Option<u16>) -> Self {
+            self.protocol_fee_rate_basis_points_override = value;
+            self
+        }
+
+        fn creator_fee_share_basis_points(mut self, value: u16) -> Self {
+            self.creator_fee_share_basis_points = value;
+            self
+        }
+
+        fn last_constant_product_sqrt(mut self, value: Q64_128) -> Self {
+            self.last_constant_product_sqrt = value;
+            self
+        }
+
+        fn swap_paused(mut self, value: bool) -> Self {
+            self.is_swap_paused = value;
+            self
+        }
+
+        fn provide_paused(mut self, value: bool) -> Self {
+            self.is_provide_paused = value;
+            self
+        }
+
+        fn withdraw_paused(mut self, value: bool) -> Self {
+            self.is_withdraw_paused = value;
+            self
+        }
+
+        fn initialized_at(mut self, value: i64) -> Self {
+            self.initialized_at = value;
+            self
+        }
+
+        fn open_time(mut self, value: i64) -> Self {
+            self.open_time = value;
+            self
+        }
+
+        fn launch_fee_schedule(mut self, value: Option<LaunchFeeSchedule>) -> Self {
+            self.launch_fee_schedule = value;
+            self
+        }
+
+        fn launch_authority(mut self, value: Option<Pubkey>) -> Self {
+            self.launch_authority = value;
+            self
+        }
+
         fn build(self) -> CpAmm {
             CpAmm {
                 is_initialized: self.is_initialized,
-                is_launched: self.is_launched,
+                status: self.status,
                 initial_locked_liquidity: self.initial_locked_liquidity,
                 constant_product_sqrt: self.constant_product_sqrt,
                 base_quote_ratio_sqrt: self.base_quote_ratio_sqrt,
@@ -755,6 +1876,8 @@ mod cp_amm_tests {
                 lp_tokens_supply: self.lp_tokens_supply,
                 protocol_base_fees_to_redeem: self.protocol_base_fees_to_redeem,
                 protocol_quote_fees_to_redeem: self.protocol_quote_fees_to_redeem,
+                creator_base_fees_to_redeem: self.creator_base_fees_to_redeem,
+                creator_quote_fees_to_redeem: self.creator_quote_fees_to_redeem,
                 base_mint: self.base_mint,
                 quote_mint: self.quote_mint,
                 lp_mint: self.lp_mint,
@@ -766,16 +1889,59 @@ mod cp_amm_tests {
                 bump: self.bump,
                 base_vault_bump: self.base_vault_bump,
                 quote_vault_bump: self.quote_vault_bump,
-                locked_lp_vault_bump: self.locked_lp_vault_bump
+                locked_lp_vault_bump: self.locked_lp_vault_bump,
+                providers_fee_rate_basis_points_override: self.providers_fee_rate_basis_points_override,
+                protocol_fee_rate_basis_points_override: self.protocol_fee_rate_basis_points_override,
+                creator_fee_share_basis_points: self.creator_fee_share_basis_points,
+                last_constant_product_sqrt: self.last_constant_product_sqrt,
+                is_swap_paused: self.is_swap_paused,
+                is_provide_paused: self.is_provide_paused,
+                is_withdraw_paused: self.is_withdraw_paused,
+                initialized_at: self.initialized_at,
+                open_time: self.open_time,
+                launch_fee_schedule: self.launch_fee_schedule,
+                version: AccountVersion::current(),
+                launch_authority: VersionedField::new(self.launch_authority),
             }
         }
     }
 
+    /// Creates an `AmmsConfig` with the specified fee rates for testing.
+    fn build_amms_config(providers_fee_rate_basis_points: u16, protocol_fee_rate_basis_points: u16, max_fee_rate_basis_points: u16) -> AmmsConfig {
+        let data = [0u8; ANCHOR_DISCRIMINATOR + AmmsConfig::INIT_SPACE];
+        let mut amms_config = AmmsConfig::try_deserialize_unchecked(&mut data.as_ref()).unwrap();
+        amms_config.initialize(Pubkey::default(), protocol_fee_rate_basis_points, providers_fee_rate_basis_points, 0, 0).unwrap();
+        amms_config.update_max_fee_rate(max_fee_rate_basis_points).unwrap();
+        amms_config
+    }
+
+    /// Tests the operations allowed by `PoolStatus`.
+    #[test]
+    fn test_pool_status_operations() {
+        for status in [PoolStatus::Launched, PoolStatus::SwapsPaused, PoolStatus::WithdrawOnly] {
+            assert!(status.is_launched());
+            assert!(status.can_withdraw());
+        }
+        assert!(PoolStatus::Launched.can_swap() && PoolStatus::Launched.can_provide());
+        assert!(!PoolStatus::SwapsPaused.can_swap() && PoolStatus::SwapsPaused.can_provide());
+        assert!(!PoolStatus::WithdrawOnly.can_swap() && !PoolStatus::WithdrawOnly.can_provide());
+        for status in [PoolStatus::Initialized, PoolStatus::BondingCurve, PoolStatus::Closed] {
+            assert!(!status.is_launched());
+            assert!(!status.can_swap() && !status.can_provide() && !status.can_withdraw());
+        }
+
+        // Existing accounts stored `is_launched` in the status byte
+        assert_eq!(PoolStatus::try_from_slice(&[0]).unwrap(), PoolStatus::Initialized);
+        assert_eq!(PoolStatus::try_from_slice(&[1]).unwrap(), PoolStatus::Launched);
+    }
+
     /// Tests `CpAmm` account data layout.
     #[test]
     fn test_cp_amm_data_layout(){
+        assert_eq!(CpAmm::DISCRIMINATOR, anchor_lang::solana_program::hash::hash(b"account:CpAmm").to_bytes()[..8]);
+
         let is_initialized = true;
-        let is_launched = true;
+        let status = PoolStatus::Launched;
         let initial_locked_liquidity = 1_000_000u64;
         let constant_product_sqrt = Q64_128::from_u64(2_000_000);
         let base_quote_ratio_sqrt = Q64_128::from_u64(1_000_000);
@@ -784,6 +1950,8 @@ mod cp_amm_tests {
         let lp_tokens_supply = 100_000u64;
         let protocol_base_fees_to_redeem = 1_000u64;
         let protocol_quote_fees_to_redeem = 500u64;
+        let creator_base_fees_to_redeem = 300u64;
+        let creator_quote_fees_to_redeem = 150u64;
         let base_mint = Pubkey::new_unique();
         let quote_mint = Pubkey::new_unique();
         let lp_mint = Pubkey::new_unique();
@@ -793,13 +1961,28 @@ mod cp_amm_tests {
         let amms_config = Pubkey::new_unique();
         let creator = Pubkey::new_unique();
         let bump = [42u8];
+        let providers_fee_rate_basis_points_override = 250u16;
+        let protocol_fee_rate_basis_points_override = 100u16;
+        let creator_fee_share_basis_points = 2000u16;
+        let last_constant_product_sqrt = Q64_128::from_u64(1_900_000);
+        let is_swap_paused = true;
+        let is_provide_paused = true;
+        let is_withdraw_paused = false;
+        let initialized_at = 1_700_000_000i64;
+        let open_time = 1_700_003_600i64;
+        let launch_fee_schedule = LaunchFeeSchedule {
+            start_fee_rate_basis_points: 5000,
+            duration_in_seconds: 600,
+            decay_mode: LaunchFeeDecayMode::Exponential,
+        };
+        let launch_authority = Pubkey::new_unique();
         
-        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 358];
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 498];
         let mut offset = 0;
 
         data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&CpAmm::discriminator()); offset += ANCHOR_DISCRIMINATOR;
         data[offset] = is_initialized as u8; offset += 1;
-        data[offset] = is_launched as u8; offset += 1;
+        data[offset] = status as u8; offset += 1;
         data[offset] = bump[0]; offset += 1;
         data[offset] = bump[0]; offset += 1;
         data[offset] = bump[0]; offset += 1;
@@ -814,6 +1997,8 @@ mod cp_amm_tests {
         data[offset..offset + 8].copy_from_slice(&lp_tokens_supply.to_le_bytes()); offset += 8;
         data[offset..offset + 8].copy_from_slice(&protocol_base_fees_to_redeem.to_le_bytes()); offset += 8;
         data[offset..offset + 8].copy_from_slice(&protocol_quote_fees_to_redeem.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&creator_base_fees_to_redeem.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&creator_quote_fees_to_redeem.to_le_bytes()); offset += 8;
         data[offset..offset + 32].copy_from_slice(base_mint.as_ref()); offset += 32;
         data[offset..offset + 32].copy_from_slice(quote_mint.as_ref()); offset += 32;
         data[offset..offset + 32].copy_from_slice(lp_mint.as_ref()); offset += 32;
@@ -822,13 +2007,40 @@ mod cp_amm_tests {
         data[offset..offset + 32].copy_from_slice(locked_lp_vault.as_ref()); offset += 32;
         data[offset..offset + 32].copy_from_slice(amms_config.as_ref()); offset += 32;
         data[offset..offset + 32].copy_from_slice(creator.as_ref()); offset += 32;
+        data[offset] = 1; offset += 1;
+        data[offset..offset + 2].copy_from_slice(&providers_fee_rate_basis_points_override.to_le_bytes()); offset += 2;
+        data[offset] = 1; offset += 1;
+        data[offset..offset + 2].copy_from_slice(&protocol_fee_rate_basis_points_override.to_le_bytes()); offset += 2;
+        data[offset..offset + 2].copy_from_slice(&creator_fee_share_basis_points.to_le_bytes()); offset += 2;
+        data[offset..offset + 16].copy_from_slice(&last_constant_product_sqrt.get_fractional_bits().to_le_bytes()); offset += 16;
+        data[offset..offset + 8].copy_from_slice(&last_constant_product_sqrt.get_integer_bits().to_le_bytes()); offset += 8;
+        data[offset] = is_swap_paused as u8; offset += 1;
+        data[offset] = is_provide_paused as u8; offset += 1;
+        data[offset] = is_withdraw_paused as u8; offset += 1;
+        data[offset..offset + 8].copy_from_slice(&initialized_at.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&open_time.to_le_bytes()); offset += 8;
+        data[offset] = 1; offset += 1;
+        data[offset..offset + 2].copy_from_slice(&launch_fee_schedule.start_fee_rate_basis_points.to_le_bytes()); offset += 2;
+        data[offset..offset + 4].copy_from_slice(&launch_fee_schedule.duration_in_seconds.to_le_bytes()); offset += 4;
+        data[offset] = 1; offset += 1;
+        data[offset] = AccountVersion::<31>::CURRENT_VERSION; offset += 1;
+        offset += 31;
+        let version_layout_end = offset;
+        data[offset] = 1; offset += 1;
+        data[offset..offset + 32].copy_from_slice(launch_authority.as_ref()); offset += 32;
         
         assert_eq!(ANCHOR_DISCRIMINATOR + CpAmm::INIT_SPACE, offset);
 
+        let mut version_data = data;
+        version_data[version_layout_end..].fill(0);
+        let version_cp_amm = CpAmm::try_deserialize(&mut version_data.as_ref()).unwrap();
+        assert_eq!(version_cp_amm.version(), AccountVersion::<31>::CURRENT_VERSION);
+        assert_eq!(version_cp_amm.launch_authority(), None);
+
         let deserialized_cp_amm = CpAmm::try_deserialize(&mut data.as_ref()).unwrap();
 
         assert_eq!(deserialized_cp_amm.is_initialized, is_initialized);
-        assert_eq!(deserialized_cp_amm.is_launched, is_launched);
+        assert_eq!(deserialized_cp_amm.status, status);
         assert_eq!(deserialized_cp_amm.initial_locked_liquidity, initial_locked_liquidity);
         assert_eq!(deserialized_cp_amm.constant_product_sqrt, constant_product_sqrt);
         assert_eq!(deserialized_cp_amm.base_quote_ratio_sqrt, base_quote_ratio_sqrt);
@@ -837,6 +2049,8 @@ mod cp_amm_tests {
         assert_eq!(deserialized_cp_amm.lp_tokens_supply, lp_tokens_supply);
         assert_eq!(deserialized_cp_amm.protocol_base_fees_to_redeem, protocol_base_fees_to_redeem);
         assert_eq!(deserialized_cp_amm.protocol_quote_fees_to_redeem, protocol_quote_fees_to_redeem);
+        assert_eq!(deserialized_cp_amm.creator_base_fees_to_redeem, creator_base_fees_to_redeem);
+        assert_eq!(deserialized_cp_amm.creator_quote_fees_to_redeem, creator_quote_fees_to_redeem);
         assert_eq!(deserialized_cp_amm.base_mint, base_mint);
         assert_eq!(deserialized_cp_amm.quote_mint, quote_mint);
         assert_eq!(deserialized_cp_amm.lp_mint, lp_mint);
@@ -848,10 +2062,160 @@ mod cp_amm_tests {
         assert_eq!(deserialized_cp_amm.base_vault_bump, bump);
         assert_eq!(deserialized_cp_amm.quote_vault_bump, bump);
         assert_eq!(deserialized_cp_amm.locked_lp_vault_bump, bump);
+        assert_eq!(deserialized_cp_amm.providers_fee_rate_basis_points_override, Some(providers_fee_rate_basis_points_override));
+        assert_eq!(deserialized_cp_amm.protocol_fee_rate_basis_points_override, Some(protocol_fee_rate_basis_points_override));
+        assert_eq!(deserialized_cp_amm.creator_fee_share_basis_points, creator_fee_share_basis_points);
+        assert_eq!(deserialized_cp_amm.last_constant_product_sqrt, last_constant_product_sqrt);
+        assert_eq!(deserialized_cp_amm.is_swap_paused, is_swap_paused);
+        assert_eq!(deserialized_cp_amm.is_provide_paused, is_provide_paused);
+        assert_eq!(deserialized_cp_amm.is_withdraw_paused, is_withdraw_paused);
+        assert_eq!(deserialized_cp_amm.initialized_at, initialized_at);
+        assert_eq!(deserialized_cp_amm.open_time, open_time);
+        assert_eq!(deserialized_cp_amm.launch_fee_schedule, Some(launch_fee_schedule));
+        assert_eq!(deserialized_cp_amm.version(), AccountVersion::<31>::CURRENT_VERSION);
+        assert_eq!(deserialized_cp_amm.launch_authority(), Some(launch_authority));
 
         let mut serialized_cp_amm = Vec::new();
         deserialized_cp_amm.try_serialize(&mut serialized_cp_amm).unwrap();
         assert_eq!(serialized_cp_amm.as_slice(), data.as_ref());
+
+        // Without the optional fields the version moves forward, and the rest of the account stays zero-padded
+        let mut padded_data = [0u8; ANCHOR_DISCRIMINATOR + 498];
+        CpAmm {
+            providers_fee_rate_basis_points_override: None,
+            protocol_fee_rate_basis_points_override: None,
+            launch_fee_schedule: None,
+            ..deserialized_cp_amm
+        }.try_serialize(&mut padded_data.as_mut_slice()).unwrap();
+        let padded_cp_amm = CpAmm::try_deserialize(&mut padded_data.as_ref()).unwrap();
+        assert_eq!(padded_cp_amm.providers_fee_rate_basis_points_override, None);
+        assert_eq!(padded_cp_amm.protocol_fee_rate_basis_points_override, None);
+        assert_eq!(padded_cp_amm.launch_fee_schedule, None);
+        assert_eq!(padded_cp_amm.open_time, open_time);
+        assert_eq!(padded_cp_amm.version(), AccountVersion::<31>::CURRENT_VERSION);
+        assert_eq!(padded_cp_amm.launch_authority(), Some(launch_authority));
+    }
+
+    /// Tests reading and migrating the `CpAmm` layout deployed before versioning.
+    #[test]
+    fn test_legacy_cp_amm_migration() {
+        let is_initialized = true;
+        let bump = [42u8];
+        let initial_locked_liquidity = 1_000u64;
+        let constant_product_sqrt = Q64_128::from_u64(4_000_000);
+        let base_quote_ratio_sqrt = Q64_128::from_u64(2);
+        let base_liquidity = 8_000_000u64;
+        let quote_liquidity = 2_000_000u64;
+        let lp_tokens_supply = 4_000_000u64;
+        let protocol_base_fees_to_redeem = 300u64;
+        let protocol_quote_fees_to_redeem = 100u64;
+        let base_mint = Pubkey::new_unique();
+        let quote_mint = Pubkey::new_unique();
+        let lp_mint = Pubkey::new_unique();
+        let base_vault = Pubkey::new_unique();
+        let quote_vault = Pubkey::new_unique();
+        let locked_lp_vault = Pubkey::new_unique();
+        let amms_config = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let migration_timestamp = 1_700_000_000i64;
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 358];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&CpAmm::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset] = is_initialized as u8; offset += 1;
+        // is_launched
+        data[offset] = 1; offset += 1;
+        data[offset] = bump[0]; offset += 1;
+        data[offset] = bump[0]; offset += 1;
+        data[offset] = bump[0]; offset += 1;
+        data[offset] = bump[0]; offset += 1;
+        data[offset..offset + 8].copy_from_slice(&initial_locked_liquidity.to_le_bytes()); offset += 8;
+        data[offset..offset + 16].copy_from_slice(&constant_product_sqrt.get_fractional_bits().to_le_bytes()); offset += 16;
+        data[offset..offset + 8].copy_from_slice(&constant_product_sqrt.get_integer_bits().to_le_bytes()); offset += 8;
+        data[offset..offset + 16].copy_from_slice(&base_quote_ratio_sqrt.get_fractional_bits().to_le_bytes()); offset += 16;
+        data[offset..offset + 8].copy_from_slice(&base_quote_ratio_sqrt.get_integer_bits().to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&base_liquidity.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&quote_liquidity.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&lp_tokens_supply.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&protocol_base_fees_to_redeem.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&protocol_quote_fees_to_redeem.to_le_bytes()); offset += 8;
+        data[offset..offset + 32].copy_from_slice(base_mint.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(quote_mint.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(lp_mint.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(base_vault.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(quote_vault.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(locked_lp_vault.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(amms_config.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(creator.as_ref()); offset += 32;
+
+        assert_eq!(ANCHOR_DISCRIMINATOR + LegacyCpAmm::INIT_SPACE, offset);
+
+        let mut legacy_cp_amm = CpAmm::try_deserialize(&mut data.as_ref()).unwrap();
+        assert!(legacy_cp_amm.is_legacy());
+        assert_eq!(legacy_cp_amm.version(), AccountVersion::<31>::LEGACY_VERSION);
+        assert_eq!(legacy_cp_amm.status(), PoolStatus::Launched);
+        assert_eq!(legacy_cp_amm.base_liquidity, base_liquidity);
+        assert_eq!(legacy_cp_amm.creator, creator);
+        assert_eq!(legacy_cp_amm.last_constant_product_sqrt, constant_product_sqrt);
+
+        let mut legacy_data = [0u8; ANCHOR_DISCRIMINATOR + 358];
+        legacy_cp_amm.try_serialize(&mut legacy_data.as_mut_slice()).unwrap();
+        assert_eq!(legacy_data, data);
+
+        legacy_cp_amm.update_pause_flags(true, false, false);
+        assert_eq!(legacy_cp_amm.try_serialize(&mut legacy_data.as_mut_slice()).unwrap_err(), ErrorCode::AccountNotMigrated.into());
+
+        let migrated_cp_amm = LegacyCpAmm::try_from_account_data(&data).unwrap().migrate(migration_timestamp);
+
+        assert_eq!(migrated_cp_amm.is_initialized, is_initialized);
+        assert_eq!(migrated_cp_amm.status(), PoolStatus::Launched);
+        assert_eq!(migrated_cp_amm.bump, bump);
+        assert_eq!(migrated_cp_amm.base_vault_bump, bump);
+        assert_eq!(migrated_cp_amm.quote_vault_bump, bump);
+        assert_eq!(migrated_cp_amm.locked_lp_vault_bump, bump);
+        assert_eq!(migrated_cp_amm.initial_locked_liquidity, initial_locked_liquidity);
+        assert_eq!(migrated_cp_amm.constant_product_sqrt, constant_product_sqrt);
+        assert_eq!(migrated_cp_amm.base_quote_ratio_sqrt, base_quote_ratio_sqrt);
+        assert_eq!(migrated_cp_amm.base_liquidity, base_liquidity);
+        assert_eq!(migrated_cp_amm.quote_liquidity, quote_liquidity);
+        assert_eq!(migrated_cp_amm.lp_tokens_supply, lp_tokens_supply);
+        assert_eq!(migrated_cp_amm.protocol_base_fees_to_redeem, protocol_base_fees_to_redeem);
+        assert_eq!(migrated_cp_amm.protocol_quote_fees_to_redeem, protocol_quote_fees_to_redeem);
+        assert_eq!(migrated_cp_amm.creator_base_fees_to_redeem, 0);
+        assert_eq!(migrated_cp_amm.creator_quote_fees_to_redeem, 0);
+        assert_eq!(migrated_cp_amm.base_mint, base_mint);
+        assert_eq!(migrated_cp_amm.quote_mint, quote_mint);
+        assert_eq!(migrated_cp_amm.lp_mint, lp_mint);
+        assert_eq!(migrated_cp_amm.base_vault, base_vault);
+        assert_eq!(migrated_cp_amm.quote_vault, quote_vault);
+        assert_eq!(migrated_cp_amm.locked_lp_vault, locked_lp_vault);
+        assert_eq!(migrated_cp_amm.amms_config, amms_config);
+        assert_eq!(migrated_cp_amm.creator, creator);
+        assert_eq!(migrated_cp_amm.providers_fee_rate_basis_points_override, None);
+        assert_eq!(migrated_cp_amm.protocol_fee_rate_basis_points_override, None);
+        assert_eq!(migrated_cp_amm.creator_fee_share_basis_points, 0);
+        assert_eq!(migrated_cp_amm.last_constant_product_sqrt, constant_product_sqrt);
+        assert!(!migrated_cp_amm.is_swap_paused);
+        assert!(!migrated_cp_amm.is_provide_paused);
+        assert!(!migrated_cp_amm.is_withdraw_paused);
+        assert_eq!(migrated_cp_amm.initialized_at, migration_timestamp);
+        assert_eq!(migrated_cp_amm.open_time, 0);
+        assert_eq!(migrated_cp_amm.launch_fee_schedule, None);
+        assert_eq!(migrated_cp_amm.version(), AccountVersion::<31>::CURRENT_VERSION);
+        assert_eq!(migrated_cp_amm.launch_authority(), None);
+
+        let mut migrated_data = [0u8; ANCHOR_DISCRIMINATOR + CpAmm::INIT_SPACE];
+        migrated_cp_amm.try_serialize(&mut migrated_data.as_mut_slice()).unwrap();
+        let deserialized_cp_amm = CpAmm::try_deserialize(&mut migrated_data.as_ref()).unwrap();
+        assert_eq!(deserialized_cp_amm.creator, creator);
+        assert_eq!(deserialized_cp_amm.launch_authority(), None);
+        assert_eq!(LegacyCpAmm::try_from_account_data(&migrated_data).unwrap_err(), ErrorCode::AccountAlreadyMigrated.into());
+
+        // is_launched
+        data[ANCHOR_DISCRIMINATOR + 1] = 0;
+        let unlaunched_cp_amm = LegacyCpAmm::try_from_account_data(&data).unwrap().migrate(migration_timestamp);
+        assert_eq!(unlaunched_cp_amm.status(), PoolStatus::Initialized);
     }
     
     /// Tests getter methods of the `CpAmm` struct.
@@ -861,7 +2225,7 @@ mod cp_amm_tests {
 
         let amm = CpAmmBuilder::new()
             .is_initialized(true)
-            .is_launched(false)
+            .status(PoolStatus::Initialized)
             .initial_locked_liquidity(1000)
             .constant_product_sqrt(Q64_128::from_u64(2000))
             .base_quote_ratio_sqrt(Q64_128::from_u64(3000))
@@ -880,10 +2244,12 @@ mod cp_amm_tests {
             .base_vault_bump([245])
             .quote_vault_bump([212])
             .locked_lp_vault_bump([123])
+            .providers_fee_rate_basis_points_override(Some(300))
+            .protocol_fee_rate_basis_points_override(None)
             .build();
 
         assert!(amm.is_initialized());
-        assert!(!amm.is_launched());
+        assert_eq!(amm.status(), PoolStatus::Initialized);
         assert_eq!(amm.bump(), 253);
         assert_eq!(amm.base_vault_bump(), 245);
         assert_eq!(amm.quote_vault_bump(), 212);
@@ -895,6 +2261,8 @@ mod cp_amm_tests {
         assert_eq!(amm.quote_vault(), &unique_pubkey);
         assert_eq!(amm.amms_config(), &unique_pubkey);
         assert_eq!(amm.creator(), &unique_pubkey);
+        assert_eq!(amm.providers_fee_rate_basis_points_override(), Some(300));
+        assert_eq!(amm.protocol_fee_rate_basis_points_override(), None);
 
         assert_eq!(amm.constant_product_sqrt(), Q64_128::from_u64(2000));
         assert_eq!(amm.base_quote_ratio_sqrt(), Q64_128::from_u64(3000));
@@ -918,17 +2286,20 @@ mod cp_amm_tests {
                 400000,
                 400000,
                 400000,
+                Q64_128::from_u64(400000),
             );
             
-            amm.launch(launch_payload);
+            amm.launch(launch_payload, 1_700_000_000, None);
 
-            assert!(amm.is_launched);
+            assert_eq!(amm.status, PoolStatus::Launched);
+            assert_eq!(amm.open_time, 1_700_000_000);
             assert_eq!(amm.base_liquidity, 400000);
             assert_eq!(amm.quote_liquidity, 400000);
             assert_eq!(amm.lp_tokens_supply, 400000);
             assert_eq!(amm.initial_locked_liquidity, 100000);
             assert_eq!(amm.base_quote_ratio_sqrt, Q64_128::from_u64(1));
             assert_eq!(amm.constant_product_sqrt, Q64_128::from_u64(400000));
+            assert_eq!(amm.last_constant_product_sqrt, Q64_128::from_u64(400000));
         }
         
         /// Tests the `provide` method of `CpAmm`.
@@ -943,6 +2314,7 @@ mod cp_amm_tests {
                 1000,
                 6000,
                 1000,
+                ProtocolFeeLpTokens::new(0, Q64_128::from_u64(0)),
             );
 
             amm.provide(provide_payload);
@@ -966,6 +2338,7 @@ mod cp_amm_tests {
                 5000,
                 400,
                 100,
+                ProtocolFeeLpTokens::new(0, Q64_128::from_u64(0)),
             );
 
             amm.withdraw(withdraw_payload);
@@ -982,13 +2355,14 @@ mod cp_amm_tests {
         fn test_swap() {
             let mut amm = CpAmmBuilder::new().build();
 
-            let swap_payload_in = SwapPayload::new(3980, 1000, 1, 20, 100, true);
-            let swap_payload_out = SwapPayload::new(1000, 985, 15, 15, 100, false);
+            let swap_payload_in = SwapPayload::new(3980, 1000, SwapFees { protocol_fees_to_redeem: 1, creator_fees_to_redeem: 2, referral_fee_amount: 0, liquidity_fee_amount: 20 }, 100, Q64_128::from_u64(0), true);
+            let swap_payload_out = SwapPayload::new(1000, 985, SwapFees { protocol_fees_to_redeem: 15, creator_fees_to_redeem: 3, referral_fee_amount: 0, liquidity_fee_amount: 15 }, 100, Q64_128::from_u64(0), false);
 
             amm.swap(swap_payload_in);
             assert_eq!(amm.base_liquidity, 4000);
             assert_eq!(amm.quote_liquidity, 1000);
             assert_eq!(amm.protocol_base_fees_to_redeem, 1);
+            assert_eq!(amm.creator_base_fees_to_redeem, 2);
             assert_eq!(amm.constant_product_sqrt, Q64_128::from_u64(2000));
             assert_eq!(amm.base_quote_ratio_sqrt, Q64_128::from_u64(2));
 
@@ -997,44 +2371,370 @@ mod cp_amm_tests {
             assert_eq!(amm.quote_liquidity, 1000);
             assert_eq!(amm.protocol_base_fees_to_redeem, 1);
             assert_eq!(amm.protocol_quote_fees_to_redeem, 15);
+            assert_eq!(amm.creator_base_fees_to_redeem, 2);
+            assert_eq!(amm.creator_quote_fees_to_redeem, 3);
             assert_eq!(amm.constant_product_sqrt, Q64_128::from_u64(1000));
             assert_eq!(amm.base_quote_ratio_sqrt, Q64_128::from_u64(1));
         }
 
-        /// Tests the `collect_fees` method of `CpAmm`.
+        /// Tests the `update_fee_rates_override` method of `CpAmm`.
+        #[test]
+        fn test_update_fee_rates_override() {
+            let amms_config = build_amms_config(25, 5, 500);
+            let mut amm = CpAmmBuilder::new().build();
+
+            assert_eq!(amm.providers_fee_rate_basis_points(&amms_config), 25);
+            assert_eq!(amm.protocol_fee_rate_basis_points(&amms_config), 5);
+
+            amm.update_fee_rates_override(Some(300), None, &amms_config).unwrap();
+            assert_eq!(amm.providers_fee_rate_basis_points_override, Some(300));
+            assert_eq!(amm.protocol_fee_rate_basis_points_override, None);
+            assert_eq!(amm.providers_fee_rate_basis_points(&amms_config), 300);
+            assert_eq!(amm.protocol_fee_rate_basis_points(&amms_config), 5);
+
+            amm.update_fee_rates_override(Some(400), Some(100), &amms_config).unwrap();
+            assert_eq!(amm.providers_fee_rate_basis_points(&amms_config), 400);
+            assert_eq!(amm.protocol_fee_rate_basis_points(&amms_config), 100);
+
+            assert!(amm.update_fee_rates_override(Some(496), None, &amms_config).is_err());
+            assert!(amm.update_fee_rates_override(Some(400), Some(101), &amms_config).is_err());
+            assert_eq!(amm.providers_fee_rate_basis_points_override, Some(400));
+            assert_eq!(amm.protocol_fee_rate_basis_points_override, Some(100));
+
+            amm.update_fee_rates_override(None, None, &amms_config).unwrap();
+            assert_eq!(amm.providers_fee_rate_basis_points(&amms_config), 25);
+            assert_eq!(amm.protocol_fee_rate_basis_points(&amms_config), 5);
+        }
+
+        /// Tests that the fee rates overrides of `CpAmm` are clamped when the `AmmsConfig` maximum fee rate is lowered.
+        #[test]
+        fn test_fee_rates_override_clamped_by_max_fee_rate() {
+            let mut amms_config = build_amms_config(25, 5, 500);
+            let mut amm = CpAmmBuilder::new().build();
+
+            amm.update_fee_rates_override(Some(400), Some(100), &amms_config).unwrap();
+            amms_config.update_max_fee_rate(300).unwrap();
+            assert_eq!(amm.protocol_fee_rate_basis_points(&amms_config), 100);
+            assert_eq!(amm.providers_fee_rate_basis_points(&amms_config), 200);
+            amms_config.update_max_fee_rate(50).unwrap();
+            assert_eq!(amm.protocol_fee_rate_basis_points(&amms_config), 50);
+            assert_eq!(amm.providers_fee_rate_basis_points(&amms_config), 0);
+
+            amm.update_fee_rates_override(None, Some(20), &amms_config).unwrap();
+            amms_config.update_max_fee_rate(40).unwrap();
+            assert_eq!(amm.providers_fee_rate_basis_points(&amms_config), 25);
+            assert_eq!(amm.protocol_fee_rate_basis_points(&amms_config), 15);
+
+            amm.update_fee_rates_override(Some(30), None, &amms_config).unwrap();
+            amms_config.update_max_fee_rate(20).unwrap();
+            assert_eq!(amm.providers_fee_rate_basis_points(&amms_config), 15);
+            assert_eq!(amm.protocol_fee_rate_basis_points(&amms_config), 5);
+        }
+
+        /// Tests that the creator fee share of `CpAmm` is capped by its `AmmsConfig`.
+        #[test]
+        fn test_creator_fee_share_basis_points() {
+            let mut amms_config = build_amms_config(25, 5, 10000);
+            amms_config.update_max_creator_fee_share(3000).unwrap();
+            let amm = CpAmmBuilder::new().creator_fee_share_basis_points(2000).build();
+
+            assert_eq!(amm.creator_fee_share_basis_points(&amms_config), 2000);
+            amms_config.update_max_creator_fee_share(1000).unwrap();
+            assert_eq!(amm.creator_fee_share_basis_points(&amms_config), 1000);
+        }
+
+        /// Tests that swaps record the constant product growth of every fee except the net protocol fee retained in the pool.
+        #[test]
+        fn test_swap_records_protocol_fee_lp_tokens_growth() {
+            let amm_builder = || CpAmmBuilder::new()
+                .status(PoolStatus::Launched)
+                .base_liquidity(6_000_000)
+                .quote_liquidity(1_500_000)
+                .constant_product_sqrt(Q64_128::from_u64(3_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(3_000_000)
+                .last_constant_product_sqrt(Q64_128::from_u64(3_000_000));
+
+            // The providers fee rate includes a launch fee excess, the referrer and the creator take their shares of the protocol fee
+            let fee_rates = FeeRates {
+                providers_fee_rate_basis_points: 300,
+                protocol_fee_rate_basis_points: 100,
+                creator_fee_share_basis_points: 2500,
+                referral_fee_share_basis_points: 1000,
+                protocol_fee_mode: ProtocolFeeMode::LpTokens,
+            };
+            let mut amm = amm_builder().build();
+            let payload = amm.get_swap_payload(3_061_224, 1, u64::MAX, fee_rates, true).unwrap();
+            let providers_fee = 91_836;
+            let protocol_net_fee = 30_612 - 3_061 - 6_887;
+            assert_eq!(payload.fees.referral_fee_amount, 3_061);
+            assert_eq!(payload.fees.liquidity_fee_amount, providers_fee + protocol_net_fee);
+            amm.swap(payload);
+
+            let growth = amm.constant_product_sqrt.checked_sub(Q64_128::from_u64(3_000_000)).unwrap();
+            let protocol_growth = amm.constant_product_sqrt.checked_sub(amm.last_constant_product_sqrt).unwrap();
+            let expected_protocol_growth = growth.as_u64() * protocol_net_fee / (providers_fee + protocol_net_fee);
+            assert!(protocol_growth.as_u64().abs_diff(expected_protocol_growth) <= 1, "Expected: {}, Got: {}", expected_protocol_growth, protocol_growth.as_u64());
+            assert!(amm.get_protocol_fee_lp_tokens_to_mint(ProtocolFeeMode::LpTokens).unwrap() > 0);
+
+            // In accumulators mode the whole growth is recorded and nothing is left to mint
+            let mut amm = amm_builder().build();
+            let payload = amm.get_swap_payload(3_061_224, 1, u64::MAX, FeeRates { protocol_fee_mode: ProtocolFeeMode::Accumulators, ..fee_rates }, true).unwrap();
+            amm.swap(payload);
+            assert_eq!(amm.last_constant_product_sqrt, amm.constant_product_sqrt);
+            assert_eq!(amm.get_protocol_fee_lp_tokens_to_mint(ProtocolFeeMode::LpTokens).unwrap(), 0);
+        }
+
+        /// Tests the swap fee rates of `CpAmm` resolved from its `AmmsConfig`.
+        #[test]
+        fn test_fee_rates_at() {
+            let mut amms_config = build_amms_config(25, 5, 10000);
+            amms_config.update_max_creator_fee_share(3000).unwrap();
+            amms_config.update_referral_fee_share(1000).unwrap();
+            amms_config.update_protocol_fee_mode(ProtocolFeeMode::LpTokens);
+            let amm = CpAmmBuilder::new().creator_fee_share_basis_points(2000).build();
+
+            let expected_fee_rates = FeeRates {
+                providers_fee_rate_basis_points: 25,
+                protocol_fee_rate_basis_points: 5,
+                creator_fee_share_basis_points: 2000,
+                referral_fee_share_basis_points: 1000,
+                protocol_fee_mode: ProtocolFeeMode::LpTokens,
+            };
+            assert_eq!(amm.fee_rates_at(&amms_config, 0, true), expected_fee_rates);
+            assert_eq!(amm.fee_rates_at(&amms_config, 0, false), FeeRates { referral_fee_share_basis_points: 0, ..expected_fee_rates });
+        }
+
+        /// Tests the `update_pause_flags` method of `CpAmm`.
+        #[test]
+        fn test_update_pause_flags() {
+            let mut amm = CpAmmBuilder::new().build();
+
+            amm.update_pause_flags(true, true, false);
+            assert!(amm.is_swap_paused());
+            assert!(amm.is_provide_paused());
+            assert!(!amm.is_withdraw_paused());
+
+            let mut amm = CpAmmBuilder::new().status(PoolStatus::Launched).build();
+            amm.update_pause_flags(true, false, false);
+            assert_eq!(amm.status(), PoolStatus::SwapsPaused);
+            amm.update_pause_flags(true, true, false);
+            assert_eq!(amm.status(), PoolStatus::WithdrawOnly);
+            amm.update_pause_flags(false, true, true);
+            assert_eq!(amm.status(), PoolStatus::Launched);
+
+            amm.update_pause_flags(false, false, false);
+            assert!(!amm.is_swap_paused());
+            assert!(!amm.is_provide_paused());
+            assert!(!amm.is_withdraw_paused());
+        }
+
+        /// Tests the `update_launch_authority` and `can_launch` methods of `CpAmm`.
+        #[test]
+        fn test_update_launch_authority() {
+            let creator = Pubkey::new_unique();
+            let launch_authority = Pubkey::new_unique();
+            let mut amm = CpAmmBuilder::new().status(PoolStatus::Initialized).creator(creator).build();
+            assert!(amm.can_launch(&creator));
+            assert!(!amm.can_launch(&launch_authority));
+
+            amm.update_launch_authority(Some(launch_authority)).unwrap();
+            assert_eq!(amm.launch_authority(), Some(launch_authority));
+            assert!(amm.can_launch(&creator));
+            assert!(amm.can_launch(&launch_authority));
+
+            amm.update_launch_authority(None).unwrap();
+            assert!(!amm.can_launch(&launch_authority));
+
+            let mut amm = CpAmmBuilder::new().status(PoolStatus::Launched).launch_authority(Some(launch_authority)).build();
+            assert_eq!(amm.update_launch_authority(None).unwrap_err(), ErrorCode::CpAmmAlreadyLaunched.into());
+        }
+
+        /// Tests the `collect_creator_fees` method of `CpAmm`.
+        #[test]
+        fn test_collect_creator_fees() {
+            let mut amm = CpAmmBuilder::new().creator_base_fees_to_redeem(5342).creator_quote_fees_to_redeem(9876).build();
+
+            let collect_creator_fees_payload = CollectCreatorFeesPayload::new(5342, 9876, 0,0);
+
+            amm.collect_creator_fees(collect_creator_fees_payload);
+            assert_eq!(amm.creator_base_fees_to_redeem, 0);
+            assert_eq!(amm.creator_quote_fees_to_redeem, 0);
+        }
+
+        /// Tests the `collect_fees` method of `CpAmm`.
+        #[test]
+        fn test_collect_fees() {
+            let mut amm = CpAmmBuilder::new().protocol_base_fees_to_redeem(123213).protocol_quote_fees_to_redeem(213442).build();
+
+            let collect_fees_payload = CollectFeesPayload::new(123213, 213442, 0,0);
+
+            amm.collect_fees(collect_fees_payload);
+            assert_eq!(amm.protocol_base_fees_to_redeem, 0);
+            assert_eq!(amm.protocol_quote_fees_to_redeem, 0);
+        }
+    }
+    
+    mod operations_calculations_tests {
+        use super::*;
+
+        /// Tests the `check_state` method of `CpAmm`.
+        #[test]
+        fn test_check_state() {
+            let amm1 = CpAmmBuilder::new()
+                .status(PoolStatus::Initialized)
+                .base_liquidity(1000)
+                .quote_liquidity(1000)
+                .lp_tokens_supply(0)
+                .build();
+            
+            let amm2 = CpAmmBuilder::new()
+                .status(PoolStatus::Launched)
+                .base_liquidity(1000)
+                .quote_liquidity(1000)
+                .lp_tokens_supply(5000)
+                .build();
+            assert!(amm1.check_state().is_err());
+            assert!(amm2.check_state().is_ok());
+
+            let amm3 = CpAmmBuilder::new()
+                .status(PoolStatus::Launched)
+                .base_liquidity(1000)
+                .quote_liquidity(0)
+                .lp_tokens_supply(5000)
+                .build();
+            assert_eq!(amm3.check_state().unwrap_err(), ErrorCode::QuoteLiquidityIsZero.into());
+
+            let amm4 = CpAmmBuilder::new()
+                .status(PoolStatus::Closed)
+                .base_liquidity(1000)
+                .quote_liquidity(1000)
+                .lp_tokens_supply(5000)
+                .build();
+            assert_eq!(amm4.check_state().unwrap_err(), ErrorCode::CpAmmClosed.into());
+        }
+
+        /// Tests the `check_cancel` method of `CpAmm`.
+        #[test]
+        fn test_check_cancel() {
+            let creator = Pubkey::new_unique();
+            let crank = Pubkey::new_unique();
+            let initialized_at = 1_700_000_000;
+            let launch_deadline = initialized_at + CpAmm::LAUNCH_DEADLINE_IN_SECONDS;
+
+            let amm = CpAmmBuilder::new()
+                .is_initialized(true)
+                .status(PoolStatus::Initialized)
+                .creator(creator)
+                .initialized_at(initialized_at)
+                .build();
+
+            assert!(amm.check_cancel(&creator, initialized_at).is_ok());
+            assert_eq!(amm.check_cancel(&crank, launch_deadline - 1).unwrap_err(), ErrorCode::CpAmmLaunchDeadlineNotExpired.into());
+            assert!(amm.check_cancel(&crank, launch_deadline).is_ok());
+
+            let launched_amm = CpAmmBuilder::new()
+                .is_initialized(true)
+                .status(PoolStatus::Launched)
+                .creator(creator)
+                .build();
+            assert_eq!(launched_amm.check_cancel(&creator, initialized_at).unwrap_err(), ErrorCode::CpAmmAlreadyLaunched.into());
+        }
+
+        #[test]
+        fn test_check_close_and_mark_closed() {
+            let mut amm = CpAmmBuilder::new()
+                .is_initialized(true)
+                .status(PoolStatus::Launched)
+                .swap_paused(true)
+                .provide_paused(true)
+                .initial_locked_liquidity(1000)
+                .lp_tokens_supply(1001)
+                .base_liquidity(1500)
+                .quote_liquidity(700)
+                .protocol_base_fees_to_redeem(10)
+                .creator_quote_fees_to_redeem(5)
+                .build();
+            assert_eq!(amm.get_close_payload().unwrap_err(), ErrorCode::CpAmmNotDrained.into());
+
+            amm.lp_tokens_supply = 1000;
+            assert_eq!(amm.get_close_payload().unwrap_err(), ErrorCode::CpAmmProtocolFeesNotCollected.into());
+
+            amm.protocol_base_fees_to_redeem = 0;
+            let close_payload = amm.get_close_payload().unwrap();
+            assert_eq!(close_payload.creator_base_fees_to_redeem(), 0);
+            assert_eq!(close_payload.creator_quote_fees_to_redeem(), 5);
+
+            assert_eq!(amm.verify_closed(0).unwrap_err(), ErrorCode::CpAmmNotClosed.into());
+            amm.mark_closed();
+            assert_eq!(amm.status(), PoolStatus::Closed);
+            assert_eq!(amm.base_liquidity, 0);
+            assert_eq!(amm.quote_liquidity, 0);
+            assert_eq!(amm.lp_tokens_supply, 0);
+            assert_eq!(amm.creator_quote_fees_to_redeem, 0);
+            assert!(amm.base_quote_ratio_sqrt.is_zero());
+            assert!(amm.last_constant_product_sqrt.is_zero());
+            amm.verify_closed(0).unwrap();
+            assert_eq!(amm.verify_closed(1).unwrap_err(), ErrorCode::LpTokensSupplyMismatch.into());
+            assert_eq!(amm.get_close_payload().unwrap_err(), ErrorCode::CpAmmClosed.into());
+
+            let initialized_amm = CpAmmBuilder::new()
+                .is_initialized(true)
+                .status(PoolStatus::Initialized)
+                .build();
+            assert_eq!(initialized_amm.get_close_payload().unwrap_err(), ErrorCode::CpAmmNotLaunched.into());
+        }
+
         #[test]
-        fn test_collect_fees() {
-            let mut amm = CpAmmBuilder::new().protocol_base_fees_to_redeem(123213).protocol_quote_fees_to_redeem(213442).build();
-
-            let collect_fees_payload = CollectFeesPayload::new(123213, 213442, 0,0);
+        fn test_is_open() {
+            let amm = CpAmmBuilder::new()
+                .is_initialized(true)
+                .status(PoolStatus::Launched)
+                .open_time(1_700_000_000)
+                .build();
 
-            amm.collect_fees(collect_fees_payload);
-            assert_eq!(amm.protocol_base_fees_to_redeem, 0);
-            assert_eq!(amm.protocol_quote_fees_to_redeem, 0);
+            assert!(!amm.is_open(1_699_999_999));
+            assert!(amm.is_open(1_700_000_000));
+            assert!(amm.is_open(1_700_000_001));
         }
-    }
-    
-    mod operations_calculations_tests {
-        use super::*;
 
-        /// Tests the `check_state` method of `CpAmm`.
         #[test]
-        fn test_check_state() {
-            let amm1 = CpAmmBuilder::new()
-                .is_launched(false)
-                .base_liquidity(1000)
-                .quote_liquidity(1000)
-                .lp_tokens_supply(0)
-                .build();
-            
-            let amm2 = CpAmmBuilder::new()
-                .is_launched(true)
-                .base_liquidity(1000)
-                .quote_liquidity(1000)
-                .lp_tokens_supply(5000)
+        fn test_launch_fee_schedule() {
+            let linear_schedule = LaunchFeeSchedule {
+                start_fee_rate_basis_points: 5000,
+                duration_in_seconds: 1000,
+                decay_mode: LaunchFeeDecayMode::Linear,
+            };
+            assert!(linear_schedule.validate().is_ok());
+            assert_eq!(linear_schedule.fee_rate_basis_points_at(-10, 100), 5000);
+            assert_eq!(linear_schedule.fee_rate_basis_points_at(0, 100), 5000);
+            assert_eq!(linear_schedule.fee_rate_basis_points_at(500, 100), 2550);
+            assert_eq!(linear_schedule.fee_rate_basis_points_at(1000, 100), 100);
+            assert_eq!(linear_schedule.fee_rate_basis_points_at(500, 6000), 6000);
+
+            let exponential_schedule = LaunchFeeSchedule {
+                decay_mode: LaunchFeeDecayMode::Exponential,
+                ..linear_schedule
+            };
+            assert_eq!(exponential_schedule.fee_rate_basis_points_at(0, 100), 5000);
+            assert_eq!(exponential_schedule.fee_rate_basis_points_at(125, 100), 2550);
+            assert_eq!(exponential_schedule.fee_rate_basis_points_at(250, 100), 1325);
+            assert_eq!(exponential_schedule.fee_rate_basis_points_at(999, 100), 101);
+            assert_eq!(exponential_schedule.fee_rate_basis_points_at(1000, 100), 100);
+
+            let too_high_schedule = LaunchFeeSchedule { start_fee_rate_basis_points: 5001, ..linear_schedule };
+            assert_eq!(too_high_schedule.validate().unwrap_err(), ErrorCode::InvalidLaunchFeeSchedule.into());
+            let zero_duration_schedule = LaunchFeeSchedule { duration_in_seconds: 0, ..linear_schedule };
+            assert_eq!(zero_duration_schedule.validate().unwrap_err(), ErrorCode::InvalidLaunchFeeSchedule.into());
+
+            let amms_config = build_amms_config(20, 10, 10000);
+            let amm = CpAmmBuilder::new()
+                .open_time(1_700_000_000)
+                .launch_fee_schedule(Some(linear_schedule))
                 .build();
-            assert!(amm1.check_state().is_err());
-            assert!(amm2.check_state().is_ok());
+            assert_eq!(amm.providers_fee_rate_basis_points_at(&amms_config, 1_700_000_000), 4990);
+            assert_eq!(amm.providers_fee_rate_basis_points_at(&amms_config, 1_700_001_000), 20);
+            assert_eq!(CpAmmBuilder::new().build().providers_fee_rate_basis_points_at(&amms_config, 0), 20);
         }
 
         /// Tests the `get_launch_payload` method of `CpAmm`.
@@ -1042,13 +2742,13 @@ mod cp_amm_tests {
         fn test_get_launch_payload() {
             let amm = CpAmmBuilder::new()
                 .is_initialized(true)
-                .is_launched(false)
+                .status(PoolStatus::Initialized)
                 .build();
 
             let base_liquidity = 400000;
             let quote_liquidity = 400000;
 
-            let payload = amm.get_launch_payload(base_liquidity, quote_liquidity).unwrap();
+            let payload = amm.get_launch_payload(base_liquidity, quote_liquidity, ProtocolFeeMode::Accumulators).unwrap();
 
             assert_eq!(payload.base_liquidity, 400000);
             assert_eq!(payload.quote_liquidity, 400000);
@@ -1057,7 +2757,34 @@ mod cp_amm_tests {
             assert_eq!(payload.lp_tokens_supply, payload.constant_product_sqrt.as_u64());
             assert_eq!(payload.initial_locked_liquidity, CpAmm::INITIAL_LOCKED_LP_TOKENS);
             
-            assert!(amm.get_launch_payload(5500, 1000).is_err());
+            assert!(amm.get_launch_payload(5500, 1000, ProtocolFeeMode::Accumulators).is_err());
+        }
+
+        /// Tests the `start_bonding_curve` and `add_bonding_curve_fee` methods of `CpAmm`.
+        #[test]
+        fn test_start_bonding_curve() {
+            let mut amm = CpAmmBuilder::new()
+                .is_initialized(true)
+                .status(PoolStatus::Initialized)
+                .build();
+
+            amm.start_bonding_curve().unwrap();
+            assert_eq!(amm.status(), PoolStatus::BondingCurve);
+            assert_eq!(amm.start_bonding_curve().unwrap_err(), ErrorCode::CpAmmAlreadyLaunched.into());
+            assert!(amm.check_cancel(amm.creator(), i64::MAX).is_err());
+
+            amm.add_bonding_curve_fee(1_000).unwrap();
+            assert_eq!(amm.protocol_quote_fees_to_redeem, 1_000);
+            assert_eq!(amm.add_bonding_curve_fee(u64::MAX).unwrap_err(), ErrorCode::SwapOverflowError.into());
+
+            // The graduation launches the AMM from its bonding curve
+            let payload = amm.get_launch_payload(400000, 400000, ProtocolFeeMode::Accumulators).unwrap();
+            amm.launch(payload, 0, None);
+            assert_eq!(amm.status(), PoolStatus::Launched);
+            assert_eq!(amm.get_launch_payload(400000, 400000, ProtocolFeeMode::Accumulators).unwrap_err(), ErrorCode::CpAmmAlreadyLaunched.into());
+
+            let mut amm = CpAmmBuilder::new().is_initialized(true).status(PoolStatus::Launched).build();
+            assert_eq!(amm.start_bonding_curve().unwrap_err(), ErrorCode::CpAmmAlreadyLaunched.into());
         }
 
         /// Tests the `get_provide_payload` method of `CpAmm`.
@@ -1070,7 +2797,7 @@ mod cp_amm_tests {
             let initial_lp_tokens_supply = 2_000_000;
 
             let amm = CpAmmBuilder::new()
-                .is_launched(true)
+                .status(PoolStatus::Launched)
                 .base_liquidity(initial_base_liquidity)
                 .quote_liquidity(initial_quote_liquidity)
                 .constant_product_sqrt(initial_constant_product_sqrt)
@@ -1081,7 +2808,7 @@ mod cp_amm_tests {
             let provided_base_liquidity = 2_000_000;
             let provided_quote_liquidity = 500_000;
 
-            let payload = amm.get_provide_payload(provided_base_liquidity, provided_quote_liquidity).unwrap();
+            let payload = amm.get_provide_payload(provided_base_liquidity, provided_quote_liquidity, ProtocolFeeMode::Accumulators, true).unwrap();
 
             let expected_base_liquidity = initial_base_liquidity + provided_base_liquidity;
             let expected_quote_liquidity = initial_quote_liquidity + provided_quote_liquidity;
@@ -1097,6 +2824,143 @@ mod cp_amm_tests {
             assert_eq!(payload.lp_tokens_supply, expected_lp_tokens_supply);
         }
 
+        /// Tests the `get_provide_payload` method of `CpAmm` minting the protocol fee as LP tokens.
+        #[test]
+        fn test_get_provide_payload_with_protocol_fee_lp_tokens() {
+            let initial_lp_tokens_supply = 2_000_000;
+
+            let amm = CpAmmBuilder::new()
+                .status(PoolStatus::Launched)
+                .base_liquidity(4_000_000)
+                .quote_liquidity(1_000_000)
+                .constant_product_sqrt(Q64_128::from_u64(2_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(initial_lp_tokens_supply)
+                .last_constant_product_sqrt(Q64_128::from_u64(1_600_000))
+                .build();
+
+            let payload = amm.get_provide_payload(2_000_000, 500_000, ProtocolFeeMode::LpTokens, true).unwrap();
+
+            // Protocol owns the growth above the recorded constant product: 400_000 / 2_000_000 of the supply after minting
+            let expected_protocol_fee_lp_tokens_to_mint = 500_000;
+            let expected_lp_tokens_to_mint = (initial_lp_tokens_supply + expected_protocol_fee_lp_tokens_to_mint) / 2;
+
+            assert_eq!(payload.protocol_fee_lp_tokens.lp_tokens_to_mint, expected_protocol_fee_lp_tokens_to_mint);
+            assert_eq!(payload.lp_tokens_to_mint, expected_lp_tokens_to_mint);
+            assert_eq!(payload.lp_tokens_supply, initial_lp_tokens_supply + expected_protocol_fee_lp_tokens_to_mint + expected_lp_tokens_to_mint);
+            assert_eq!(payload.protocol_fee_lp_tokens.last_constant_product_sqrt, Q64_128::from_u64(3_000_000));
+
+            // Without the fee authority LP account the protocol keeps its share in the recorded constant product
+            let payload = amm.get_provide_payload(2_000_000, 500_000, ProtocolFeeMode::LpTokens, false).unwrap();
+            assert_eq!(payload.protocol_fee_lp_tokens.lp_tokens_to_mint, 0);
+            assert_eq!(payload.lp_tokens_to_mint, expected_lp_tokens_to_mint);
+            assert_eq!(payload.lp_tokens_supply, initial_lp_tokens_supply + expected_lp_tokens_to_mint);
+            assert!(payload.protocol_fee_lp_tokens.last_constant_product_sqrt.abs_diff(Q64_128::from_u64(2_600_000)) <= Q64_128::from_u64(1));
+
+            let payload = amm.get_provide_payload(2_000_000, 500_000, ProtocolFeeMode::Accumulators, true).unwrap();
+            assert_eq!(payload.protocol_fee_lp_tokens.lp_tokens_to_mint, 0);
+            assert_eq!(payload.protocol_fee_lp_tokens.last_constant_product_sqrt, Q64_128::from_u64(0));
+        }
+
+        /// Tests the `get_donate_payload` method of `CpAmm` and applying it with `donate`.
+        #[test]
+        fn test_get_donate_payload() {
+            let mut amm = CpAmmBuilder::new()
+                .status(PoolStatus::Launched)
+                .base_liquidity(4_000_000)
+                .quote_liquidity(1_000_000)
+                .constant_product_sqrt(Q64_128::from_u64(2_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(2_000_000)
+                .last_constant_product_sqrt(Q64_128::from_u64(1_000_000))
+                .build();
+
+            assert_eq!(amm.get_donate_payload(2_000_000, 500_000, DonationRatioPolicy::Disabled).unwrap_err(), ErrorCode::DonationsDisabled.into());
+            assert_eq!(amm.get_donate_payload(2_000_000, 0, DonationRatioPolicy::Proportional).unwrap_err(), ErrorCode::ProvidedQuoteLiquidityIsZero.into());
+            assert_eq!(amm.get_donate_payload(2_000_000, 100_000, DonationRatioPolicy::Proportional).unwrap_err(), ErrorCode::LiquidityRatioToleranceExceeded.into());
+            assert_eq!(amm.get_donate_payload(0, 0, DonationRatioPolicy::AnyRatio).unwrap_err(), ErrorCode::DonationIsZero.into());
+
+            let payload = amm.get_donate_payload(2_000_000, 500_000, DonationRatioPolicy::Proportional).unwrap();
+            assert_eq!(payload.base_liquidity, 6_000_000);
+            assert_eq!(payload.quote_liquidity, 1_500_000);
+            assert_eq!(payload.base_quote_ratio_sqrt, Q64_128::from_u64(2));
+            assert_eq!(payload.constant_product, Q64_128::from_u64(3_000_000));
+            // The pending protocol share of the growth before the donation is kept
+            assert_eq!(payload.last_constant_product_sqrt, Q64_128::from_u64(1_500_000));
+
+            amm.donate(payload);
+            assert_eq!(amm.base_liquidity, 6_000_000);
+            assert_eq!(amm.quote_liquidity, 1_500_000);
+            assert_eq!(amm.lp_tokens_supply, 2_000_000);
+            assert_eq!(amm.constant_product_sqrt, Q64_128::from_u64(3_000_000));
+
+            let payload = amm.get_donate_payload(0, 500_000, DonationRatioPolicy::AnyRatio).unwrap();
+            assert_eq!(payload.base_liquidity, 6_000_000);
+            assert_eq!(payload.quote_liquidity, 2_000_000);
+            assert_eq!(payload.last_constant_product_sqrt, payload.constant_product * Q64_128::from_u64(1_500_000) / Q64_128::from_u64(3_000_000));
+        }
+
+        /// Tests the `get_reserves_discrepancy` and `get_sync_payload` methods of `CpAmm`.
+        #[test]
+        fn test_get_reserves_discrepancy_and_sync_payload() {
+            let amm = CpAmmBuilder::new()
+                .status(PoolStatus::Launched)
+                .base_liquidity(4_000_000)
+                .quote_liquidity(1_000_000)
+                .constant_product_sqrt(Q64_128::from_u64(2_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(2_000_000)
+                .protocol_base_fees_to_redeem(300)
+                .creator_base_fees_to_redeem(200)
+                .protocol_quote_fees_to_redeem(100)
+                .build();
+
+            let discrepancy = amm.get_reserves_discrepancy(4_000_500, 1_000_100).unwrap();
+            assert_eq!(discrepancy, ReservesDiscrepancy::default());
+
+            let discrepancy = amm.get_reserves_discrepancy(6_000_500, 1_000_000).unwrap();
+            assert_eq!(discrepancy.base_surplus(), 2_000_000);
+            assert_eq!(discrepancy.quote_surplus(), 0);
+            assert_eq!(discrepancy.base_shortfall(), 0);
+            assert_eq!(discrepancy.quote_shortfall(), 100);
+
+            let payload = amm.get_sync_payload(&discrepancy).unwrap();
+            assert_eq!(payload.base_liquidity, 6_000_000);
+            assert_eq!(payload.quote_liquidity, 1_000_000);
+            assert_eq!(payload.last_constant_product_sqrt, Q64_128::from_u64(0));
+
+            let initialized_amm = CpAmmBuilder::new().status(PoolStatus::Initialized).build();
+            assert_eq!(initialized_amm.get_reserves_discrepancy(0, 0).unwrap_err(), ErrorCode::CpAmmNotLaunched.into());
+        }
+
+        /// Tests the `verify` method of `CpAmm`.
+        #[test]
+        fn test_verify() {
+            let mut amm = CpAmmBuilder::new()
+                .status(PoolStatus::Launched)
+                .base_liquidity(4_000_000)
+                .quote_liquidity(1_000_000)
+                .constant_product_sqrt(Q64_128::from_u64(2_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(2_000_000)
+                .initial_locked_liquidity(1000)
+                .protocol_quote_fees_to_redeem(100)
+                .build();
+
+            assert!(amm.verify(4_000_000, 1_000_100, 2_000_000, 1000).is_ok());
+            assert!(amm.verify(4_000_001, 1_000_200, 2_000_000, 1001).is_ok());
+            assert_eq!(amm.verify(3_999_999, 1_000_100, 2_000_000, 1000).unwrap_err(), ErrorCode::BaseVaultBalanceBelowReserves.into());
+            assert_eq!(amm.verify(4_000_000, 1_000_099, 2_000_000, 1000).unwrap_err(), ErrorCode::QuoteVaultBalanceBelowReserves.into());
+            assert_eq!(amm.verify(4_000_000, 1_000_100, 2_000_001, 1000).unwrap_err(), ErrorCode::LpTokensSupplyMismatch.into());
+            assert_eq!(amm.verify(4_000_000, 1_000_100, 2_000_000, 999).unwrap_err(), ErrorCode::LockedLiquidityMismatch.into());
+
+            amm.base_quote_ratio_sqrt = Q64_128::from_u64(3);
+            assert_eq!(amm.verify(4_000_000, 1_000_100, 2_000_000, 1000).unwrap_err(), ErrorCode::BaseQuoteRatioMismatch.into());
+
+            amm.constant_product_sqrt = Q64_128::from_u64(2_000_001);
+            assert_eq!(amm.verify(4_000_000, 1_000_100, 2_000_000, 1000).unwrap_err(), ErrorCode::ConstantProductMismatch.into());
+        }
+
         /// Tests the `get_withdraw_payload` method of `CpAmm`.
         #[test]
         fn test_get_withdraw_payload() {
@@ -1107,7 +2971,7 @@ mod cp_amm_tests {
             let initial_lp_tokens_supply = 3_000_000;
 
             let amm = CpAmmBuilder::new()
-                .is_launched(true)
+                .status(PoolStatus::Launched)
                 .base_liquidity(initial_base_liquidity)
                 .quote_liquidity(initial_quote_liquidity)
                 .constant_product_sqrt(initial_constant_product_sqrt)
@@ -1117,7 +2981,7 @@ mod cp_amm_tests {
 
             let lp_tokens_withdraw = 1000000;
 
-            let payload = amm.get_withdraw_payload(lp_tokens_withdraw).unwrap();
+            let payload = amm.get_withdraw_payload(lp_tokens_withdraw, ProtocolFeeMode::Accumulators, true).unwrap();
 
             let expected_base_withdraw_amount = 2_000_000;
             let expected_quote_withdraw_amount = 500_000;
@@ -1133,6 +2997,47 @@ mod cp_amm_tests {
             assert_eq!(payload.lp_tokens_supply, expected_lp_tokens_supply);
         }
 
+        /// Tests the `get_withdraw_payload` method of `CpAmm` minting the protocol fee as LP tokens.
+        #[test]
+        fn test_get_withdraw_payload_with_protocol_fee_lp_tokens() {
+            let initial_lp_tokens_supply = 3_000_000;
+
+            let amm = CpAmmBuilder::new()
+                .status(PoolStatus::Launched)
+                .base_liquidity(6_000_000)
+                .quote_liquidity(1_500_000)
+                .constant_product_sqrt(Q64_128::from_u64(3_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(initial_lp_tokens_supply)
+                .last_constant_product_sqrt(Q64_128::from_u64(2_400_000))
+                .build();
+
+            // Protocol owns the growth above the recorded constant product: 600_000 / 3_000_000 of the supply after minting
+            let expected_protocol_fee_lp_tokens_to_mint = 750_000;
+            let lp_tokens_withdraw = (initial_lp_tokens_supply + expected_protocol_fee_lp_tokens_to_mint) / 2;
+
+            let payload = amm.get_withdraw_payload(lp_tokens_withdraw, ProtocolFeeMode::LpTokens, true).unwrap();
+
+            assert_eq!(payload.protocol_fee_lp_tokens.lp_tokens_to_mint, expected_protocol_fee_lp_tokens_to_mint);
+            assert_eq!(payload.base_withdraw_amount, 3_000_000);
+            assert_eq!(payload.quote_withdraw_amount, 750_000);
+            assert_eq!(payload.lp_tokens_supply, lp_tokens_withdraw);
+            assert_eq!(payload.protocol_fee_lp_tokens.last_constant_product_sqrt, Q64_128::from_u64(1_500_000));
+
+            // Without the fee authority LP account the withdraw still measures the share against the accrued LP tokens
+            let payload = amm.get_withdraw_payload(lp_tokens_withdraw, ProtocolFeeMode::LpTokens, false).unwrap();
+            assert_eq!(payload.protocol_fee_lp_tokens.lp_tokens_to_mint, 0);
+            assert_eq!(payload.base_withdraw_amount, 3_000_000);
+            assert_eq!(payload.quote_withdraw_amount, 750_000);
+            assert_eq!(payload.lp_tokens_supply, initial_lp_tokens_supply - lp_tokens_withdraw);
+            assert!(payload.protocol_fee_lp_tokens.last_constant_product_sqrt.abs_diff(Q64_128::from_u64(900_000)) <= Q64_128::from_u64(1));
+
+            // The next liquidity event mints the same protocol fee LP tokens
+            let mut amm = amm;
+            amm.withdraw(payload);
+            assert!(amm.get_protocol_fee_lp_tokens_to_mint(ProtocolFeeMode::LpTokens).unwrap().abs_diff(expected_protocol_fee_lp_tokens_to_mint) <= 1);
+        }
+
         /// Tests the `get_swap_payload` method of `CpAmm` for in->out swap.
         #[test]
         fn test_get_base_to_quote_swap_payload() {
@@ -1145,7 +3050,7 @@ mod cp_amm_tests {
             let initial_lp_tokens_supply = 3_000_000;
                 
             let amm = CpAmmBuilder::new()
-                .is_launched(true)
+                .status(PoolStatus::Launched)
                 .base_liquidity(initial_base_liquidity)
                 .quote_liquidity(initial_quote_liquidity)
                 .constant_product_sqrt(initial_constant_product_sqrt)
@@ -1160,12 +3065,12 @@ mod cp_amm_tests {
             let allowed_slippage = 0;
 
             
-            let payload = amm.get_swap_payload(base_amount, estimated_result, allowed_slippage, providers_fee_basis_points, protocol_fee_basis_points, true).unwrap();
+            let payload = amm.get_swap_payload(base_amount, estimated_result, allowed_slippage, FeeRates { providers_fee_rate_basis_points: providers_fee_basis_points, protocol_fee_rate_basis_points: protocol_fee_basis_points, ..Default::default() }, true).unwrap();
         
             assert_eq!(payload.base_liquidity, initial_base_liquidity + base_amount - protocol_fee - providers_fee);
             assert_eq!(payload.quote_liquidity, initial_quote_liquidity - estimated_result);
-            assert_eq!(payload.protocol_fees_to_redeem, protocol_fee);
-            assert_eq!(payload.providers_fee_amount, providers_fee);
+            assert_eq!(payload.fees.protocol_fees_to_redeem, protocol_fee);
+            assert_eq!(payload.fees.liquidity_fee_amount, providers_fee);
             assert_eq!(payload.amount_to_withdraw, estimated_result);
             assert!(payload.is_in_out);
         }
@@ -1182,7 +3087,7 @@ mod cp_amm_tests {
             let initial_lp_tokens_supply = 3_000_000;
                 
             let amm = CpAmmBuilder::new()
-                .is_launched(true)
+                .status(PoolStatus::Launched)
                 .base_liquidity(initial_base_liquidity)
                 .quote_liquidity(initial_quote_liquidity)
                 .constant_product_sqrt(initial_constant_product_sqrt)
@@ -1196,16 +3101,177 @@ mod cp_amm_tests {
             let estimated_result = 1_500_000;
             let allowed_slippage = 0;
 
-            let payload = amm.get_swap_payload(quote_amount, estimated_result, allowed_slippage, providers_fee_basis_points, protocol_fee_basis_points, false).unwrap();
+            let payload = amm.get_swap_payload(quote_amount, estimated_result, allowed_slippage, FeeRates { providers_fee_rate_basis_points: providers_fee_basis_points, protocol_fee_rate_basis_points: protocol_fee_basis_points, ..Default::default() }, false).unwrap();
 
             assert_eq!(payload.base_liquidity, initial_base_liquidity - estimated_result);
             assert_eq!(payload.quote_liquidity, initial_quote_liquidity + quote_amount - protocol_fee - providers_fee);
-            assert_eq!(payload.protocol_fees_to_redeem, protocol_fee);
-            assert_eq!(payload.providers_fee_amount, providers_fee);
+            assert_eq!(payload.fees.protocol_fees_to_redeem, protocol_fee);
+            assert_eq!(payload.fees.liquidity_fee_amount, providers_fee);
             assert_eq!(payload.amount_to_withdraw, estimated_result);
             assert!(!payload.is_in_out);
         }
 
+        /// Tests the `get_swap_payload` method of `CpAmm` with a creator fee share.
+        #[test]
+        fn test_get_swap_payload_with_creator_fee_share() {
+            let initial_base_liquidity = 6_000_000;
+            let initial_quote_liquidity = 1_500_000;
+            let protocol_fee_basis_points = 100;
+            let providers_fee_basis_points = 100;
+            let creator_fee_share_basis_points = 2500;
+
+            let amm = CpAmmBuilder::new()
+                .status(PoolStatus::Launched)
+                .base_liquidity(initial_base_liquidity)
+                .quote_liquidity(initial_quote_liquidity)
+                .constant_product_sqrt(Q64_128::from_u64(3_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(3_000_000)
+                .protocol_base_fees_to_redeem(10)
+                .creator_base_fees_to_redeem(20)
+                .build();
+
+            let base_amount: u64 = 3_061_224;
+            let protocol_fee = base_amount * protocol_fee_basis_points as u64 / 10000;
+            let providers_fee = base_amount * providers_fee_basis_points as u64 / 10000;
+            let creator_fee = protocol_fee * creator_fee_share_basis_points as u64 / 10000;
+            let estimated_result = 500_000;
+
+            let payload = amm.get_swap_payload(base_amount, estimated_result, 0, FeeRates { providers_fee_rate_basis_points: providers_fee_basis_points, protocol_fee_rate_basis_points: protocol_fee_basis_points, creator_fee_share_basis_points, ..Default::default() }, true).unwrap();
+
+            assert_eq!(payload.base_liquidity, initial_base_liquidity + base_amount - protocol_fee - providers_fee);
+            assert_eq!(payload.quote_liquidity, initial_quote_liquidity - estimated_result);
+            assert_eq!(payload.fees.protocol_fees_to_redeem, 10 + protocol_fee - creator_fee);
+            assert_eq!(payload.fees.creator_fees_to_redeem, 20 + creator_fee);
+            assert_eq!(payload.fees.liquidity_fee_amount, providers_fee);
+            assert_eq!(payload.amount_to_withdraw, estimated_result);
+        }
+
+        /// Tests the `get_swap_payload` method of `CpAmm` with a referrer and a creator fee share.
+        #[test]
+        fn test_get_swap_payload_with_referral_fee_share() {
+            let initial_base_liquidity = 6_000_000;
+            let initial_quote_liquidity = 1_500_000;
+            let protocol_fee_basis_points = 100;
+            let providers_fee_basis_points = 100;
+            let creator_fee_share_basis_points = 2500;
+            let referral_fee_share_basis_points = 5000;
+
+            let amm = CpAmmBuilder::new()
+                .status(PoolStatus::Launched)
+                .base_liquidity(initial_base_liquidity)
+                .quote_liquidity(initial_quote_liquidity)
+                .constant_product_sqrt(Q64_128::from_u64(3_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(3_000_000)
+                .protocol_base_fees_to_redeem(10)
+                .creator_base_fees_to_redeem(20)
+                .build();
+
+            let base_amount: u64 = 3_061_224;
+            let protocol_fee = base_amount * protocol_fee_basis_points as u64 / 10000;
+            let providers_fee = base_amount * providers_fee_basis_points as u64 / 10000;
+            let referral_fee = protocol_fee * referral_fee_share_basis_points as u64 / 10000;
+            let creator_fee = (protocol_fee - referral_fee) * creator_fee_share_basis_points as u64 / 10000;
+            let estimated_result = 500_000;
+
+            let payload = amm.get_swap_payload(base_amount, estimated_result, 0, FeeRates { providers_fee_rate_basis_points: providers_fee_basis_points, protocol_fee_rate_basis_points: protocol_fee_basis_points, creator_fee_share_basis_points, referral_fee_share_basis_points, ..Default::default() }, true).unwrap();
+
+            assert_eq!(payload.base_liquidity, initial_base_liquidity + base_amount - protocol_fee - providers_fee);
+            assert_eq!(payload.quote_liquidity, initial_quote_liquidity - estimated_result);
+            assert_eq!(payload.fees.referral_fee_amount, referral_fee);
+            assert_eq!(payload.fees.protocol_fees_to_redeem, 10 + protocol_fee - referral_fee - creator_fee);
+            assert_eq!(payload.fees.creator_fees_to_redeem, 20 + creator_fee);
+            assert_eq!(payload.fees.liquidity_fee_amount, providers_fee);
+            assert_eq!(payload.amount_to_withdraw, estimated_result);
+        }
+
+        /// Tests the `get_swap_payload` method of `CpAmm` keeping the protocol fee in the pool liquidity.
+        #[test]
+        fn test_get_swap_payload_with_lp_tokens_protocol_fee_mode() {
+            let initial_base_liquidity = 6_000_000;
+            let initial_quote_liquidity = 1_500_000;
+            let protocol_fee_basis_points = 100;
+            let providers_fee_basis_points = 100;
+            let creator_fee_share_basis_points = 2500;
+
+            let amm = CpAmmBuilder::new()
+                .status(PoolStatus::Launched)
+                .base_liquidity(initial_base_liquidity)
+                .quote_liquidity(initial_quote_liquidity)
+                .constant_product_sqrt(Q64_128::from_u64(3_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(3_000_000)
+                .protocol_base_fees_to_redeem(10)
+                .creator_base_fees_to_redeem(20)
+                .build();
+
+            let base_amount: u64 = 3_061_224;
+            let protocol_fee = base_amount * protocol_fee_basis_points as u64 / 10000;
+            let providers_fee = base_amount * providers_fee_basis_points as u64 / 10000;
+            let creator_fee = protocol_fee * creator_fee_share_basis_points as u64 / 10000;
+            let estimated_result = 500_000;
+
+            let payload = amm.get_swap_payload(base_amount, estimated_result, 0, FeeRates { providers_fee_rate_basis_points: providers_fee_basis_points, protocol_fee_rate_basis_points: protocol_fee_basis_points, creator_fee_share_basis_points, protocol_fee_mode: ProtocolFeeMode::LpTokens, ..Default::default() }, true).unwrap();
+
+            assert_eq!(payload.base_liquidity, initial_base_liquidity + base_amount - protocol_fee - providers_fee);
+            assert_eq!(payload.fees.protocol_fees_to_redeem, 10);
+            assert_eq!(payload.fees.creator_fees_to_redeem, 20 + creator_fee);
+            assert_eq!(payload.fees.liquidity_fee_amount, providers_fee + protocol_fee - creator_fee);
+            assert_eq!(payload.amount_to_withdraw, estimated_result);
+        }
+
+        /// Tests that paused operations of `CpAmm` fail while withdrawals stay open in the withdraw-only mode.
+        #[test]
+        fn test_paused_operations() {
+            let amm_builder = || CpAmmBuilder::new()
+                .status(PoolStatus::Launched)
+                .base_liquidity(6_000_000)
+                .quote_liquidity(1_500_000)
+                .constant_product_sqrt(Q64_128::from_u64(3_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(3_000_000);
+
+            let amm = amm_builder().swap_paused(true).provide_paused(true).build();
+            assert_eq!(amm.get_swap_payload(3_061_224, 500_000, 0, FeeRates { providers_fee_rate_basis_points: 100, protocol_fee_rate_basis_points: 100, ..Default::default() }, true).unwrap_err(), ErrorCode::CpAmmSwapPaused.into());
+            assert_eq!(amm.get_provide_payload(2_000_000, 500_000, ProtocolFeeMode::Accumulators, true).unwrap_err(), ErrorCode::CpAmmProvidePaused.into());
+            assert!(amm.get_withdraw_payload(1_000_000, ProtocolFeeMode::Accumulators, true).is_ok());
+
+            let amm = amm_builder().withdraw_paused(true).build();
+            assert_eq!(amm.get_withdraw_payload(1_000_000, ProtocolFeeMode::Accumulators, true).unwrap_err(), ErrorCode::CpAmmWithdrawPaused.into());
+
+            let mut amm = amm_builder().build();
+            amm.update_pause_flags(true, false, false);
+            assert_eq!(amm.status(), PoolStatus::SwapsPaused);
+            assert_eq!(amm.get_swap_payload(3_061_224, 500_000, 0, FeeRates { providers_fee_rate_basis_points: 100, protocol_fee_rate_basis_points: 100, ..Default::default() }, true).unwrap_err(), ErrorCode::CpAmmSwapPaused.into());
+            assert!(amm.get_provide_payload(2_000_000, 500_000, ProtocolFeeMode::Accumulators, true).is_ok());
+
+            amm.update_pause_flags(true, true, false);
+            assert_eq!(amm.status(), PoolStatus::WithdrawOnly);
+            assert_eq!(amm.get_provide_payload(2_000_000, 500_000, ProtocolFeeMode::Accumulators, true).unwrap_err(), ErrorCode::CpAmmProvidePaused.into());
+            assert!(amm.get_withdraw_payload(1_000_000, ProtocolFeeMode::Accumulators, true).is_ok());
+        }
+
+        /// Tests the `get_collect_creator_fees_payload` method of `CpAmm`.
+        #[test]
+        fn test_get_collect_creator_fees_payload() {
+            let creator_base_fees_to_redeem = 4353;
+            let creator_quote_fees_to_redeem = 74567;
+            let amm = CpAmmBuilder::new()
+                .creator_base_fees_to_redeem(creator_base_fees_to_redeem)
+                .creator_quote_fees_to_redeem(creator_quote_fees_to_redeem)
+                .build();
+
+            let payload = amm.get_collect_creator_fees_payload().unwrap();
+
+            assert_eq!(payload.creator_base_fees_to_redeem, creator_base_fees_to_redeem);
+            assert_eq!(payload.creator_quote_fees_to_redeem, creator_quote_fees_to_redeem);
+            assert_eq!(payload.new_creator_base_fees_to_redeem, 0);
+            assert_eq!(payload.new_creator_quote_fees_to_redeem, 0);
+
+            assert!(CpAmmBuilder::new().build().get_collect_creator_fees_payload().is_err());
+        }
+
         /// Tests the `get_collect_fees_payload` method of `CpAmm`.
         #[test]
         fn test_get_collect_fees_payload() {
@@ -1238,6 +3304,7 @@ mod cp_amm_tests {
 /// - `base_liquidity`: The initial base token liquidity in the pool.
 /// - `quote_liquidity`: The initial quote token liquidity in the pool.
 /// - `lp_tokens_supply`: The total supply of LP tokens minted upon launch.
+/// - `last_constant_product_sqrt`: The constant product square root to record for protocol fee LP tokens.
 #[derive(Debug)]
 pub struct LaunchPayload {
     initial_locked_liquidity: u64,
@@ -1246,6 +3313,7 @@ pub struct LaunchPayload {
     base_liquidity: u64,
     quote_liquidity: u64,
     lp_tokens_supply: u64,
+    last_constant_product_sqrt: Q64_128,
 }
 impl LaunchPayload {
     /// Creates a new `LaunchPayload` instance with the specified parameters.
@@ -1257,6 +3325,7 @@ impl LaunchPayload {
     /// - `base_liquidity`: The base token liquidity.
     /// - `quote_liquidity`: The quote token liquidity.
     /// - `lp_tokens_supply`: The total LP token supply.
+    /// - `last_constant_product_sqrt`: The constant product square root to record for protocol fee LP tokens.
     pub fn new(
         initial_locked_liquidity: u64,
         constant_product_sqrt: Q64_128,
@@ -1264,6 +3333,7 @@ impl LaunchPayload {
         base_liquidity: u64,
         quote_liquidity: u64,
         lp_tokens_supply: u64,
+        last_constant_product_sqrt: Q64_128,
     ) -> Self {
         Self {
             initial_locked_liquidity,
@@ -1272,6 +3342,7 @@ impl LaunchPayload {
             base_liquidity,
             quote_liquidity,
             lp_tokens_supply,
+            last_constant_product_sqrt,
         }
     }
 
@@ -1298,6 +3369,7 @@ impl LaunchPayload {
 /// - `quote_liquidity`: The updated quote token liquidity in the pool.
 /// - `lp_tokens_supply`: The updated total supply of LP tokens.
 /// - `lp_tokens_to_mint`: The number of LP tokens to mint for the liquidity provider.
+/// - `protocol_fee_lp_tokens`: The protocol fee LP tokens to mint for the fee authority.
 #[derive(Debug)]
 pub struct ProvidePayload {
     base_quote_ratio_sqrt: Q64_128,
@@ -1306,6 +3378,7 @@ pub struct ProvidePayload {
     quote_liquidity: u64,
     lp_tokens_supply: u64,
     lp_tokens_to_mint: u64,
+    protocol_fee_lp_tokens: ProtocolFeeLpTokens,
 }
 impl ProvidePayload {
     /// Creates a new `ProvidePayload` instance with the specified parameters.
@@ -1317,6 +3390,7 @@ impl ProvidePayload {
     /// - `quote_liquidity`: The updated quote liquidity amount.
     /// - `lp_tokens_supply`: The updated LP token supply.
     /// - `lp_tokens_to_mint`: The LP tokens to mint for the provider.
+    /// - `protocol_fee_lp_tokens`: The protocol fee LP tokens to mint for the fee authority.
     pub fn new(
         base_quote_ratio_sqrt: Q64_128,
         constant_product: Q64_128,
@@ -1324,6 +3398,7 @@ impl ProvidePayload {
         quote_liquidity: u64,
         lp_tokens_supply: u64,
         lp_tokens_to_mint: u64,
+        protocol_fee_lp_tokens: ProtocolFeeLpTokens,
     ) -> Self {
         Self {
             base_quote_ratio_sqrt,
@@ -1332,6 +3407,7 @@ impl ProvidePayload {
             quote_liquidity,
             lp_tokens_supply,
             lp_tokens_to_mint,
+            protocol_fee_lp_tokens,
         }
     }
     
@@ -1339,6 +3415,35 @@ impl ProvidePayload {
     pub fn lp_tokens_to_mint(&self) -> u64{
         self.lp_tokens_to_mint
     }
+
+    /// Returns the number of LP tokens to mint for the fee authority as the protocol fee.
+    pub fn protocol_fee_lp_tokens_to_mint(&self) -> u64{
+        self.protocol_fee_lp_tokens.lp_tokens_to_mint
+    }
+}
+
+/// Represents the protocol fee taken as LP tokens on a provide or withdraw.
+///
+/// # Fields
+/// - `lp_tokens_to_mint`: The number of LP tokens to mint for the fee authority as the protocol fee.
+/// - `last_constant_product_sqrt`: The constant product square root to record for protocol fee LP tokens.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProtocolFeeLpTokens {
+    lp_tokens_to_mint: u64,
+    last_constant_product_sqrt: Q64_128,
+}
+impl ProtocolFeeLpTokens {
+    /// Creates a new `ProtocolFeeLpTokens` instance with the specified parameters.
+    ///
+    /// # Parameters
+    /// - `lp_tokens_to_mint`: The LP tokens to mint for the fee authority.
+    /// - `last_constant_product_sqrt`: The constant product square root to record for protocol fee LP tokens.
+    pub fn new(lp_tokens_to_mint: u64, last_constant_product_sqrt: Q64_128) -> Self {
+        Self {
+            lp_tokens_to_mint,
+            last_constant_product_sqrt,
+        }
+    }
 }
 
 /// Represents the data required to withdraw liquidity from the AMM.
@@ -1353,6 +3458,7 @@ impl ProvidePayload {
 /// - `lp_tokens_supply`: The updated total supply of LP tokens.
 /// - `base_withdraw_amount`: The amount of base tokens withdrawn.
 /// - `quote_withdraw_amount`: The amount of quote tokens withdrawn.
+/// - `protocol_fee_lp_tokens`: The protocol fee LP tokens to mint for the fee authority.
 #[derive(Debug)]
 pub struct WithdrawPayload{
     base_quote_ratio_sqrt: Q64_128,
@@ -1360,7 +3466,8 @@ pub struct WithdrawPayload{
     quote_liquidity: u64,
     lp_tokens_supply: u64,
     base_withdraw_amount: u64,
-    quote_withdraw_amount: u64
+    quote_withdraw_amount: u64,
+    protocol_fee_lp_tokens: ProtocolFeeLpTokens,
 }
 impl WithdrawPayload {
     /// Creates a new `WithdrawPayload` instance with the specified parameters.
@@ -1372,6 +3479,7 @@ impl WithdrawPayload {
     /// - `lp_tokens_supply`: The updated LP token supply.
     /// - `base_withdraw_amount`: The base tokens withdrawn.
     /// - `quote_withdraw_amount`: The quote tokens withdrawn.
+    /// - `protocol_fee_lp_tokens`: The protocol fee LP tokens to mint for the fee authority.
     pub fn new(
         base_quote_ratio_sqrt: Q64_128,
         base_liquidity: u64,
@@ -1379,6 +3487,7 @@ impl WithdrawPayload {
         lp_tokens_supply: u64,
         base_withdraw_amount: u64,
         quote_withdraw_amount: u64,
+        protocol_fee_lp_tokens: ProtocolFeeLpTokens,
     ) -> Self {
         Self {
             base_quote_ratio_sqrt,
@@ -1387,6 +3496,7 @@ impl WithdrawPayload {
             lp_tokens_supply,
             base_withdraw_amount,
             quote_withdraw_amount,
+            protocol_fee_lp_tokens,
         }
     }
 
@@ -1400,6 +3510,65 @@ impl WithdrawPayload {
     pub fn quote_withdraw_amount(&self) -> u64{
         self.quote_withdraw_amount
     }
+
+    /// Returns the number of LP tokens to mint for the fee authority as the protocol fee.
+    pub fn protocol_fee_lp_tokens_to_mint(&self) -> u64{
+        self.protocol_fee_lp_tokens.lp_tokens_to_mint
+    }
+}
+
+/// Represents the data required to donate liquidity to the AMM.
+///
+/// # Fields
+/// - `base_quote_ratio_sqrt`: The updated square root of the base-to-quote liquidity ratio.
+/// - `constant_product`: The updated square root of the constant product.
+/// - `base_liquidity`: The updated base token liquidity in the pool.
+/// - `quote_liquidity`: The updated quote token liquidity in the pool.
+/// - `last_constant_product_sqrt`: The constant product square root to record for protocol fee LP tokens.
+#[derive(Debug)]
+pub struct DonatePayload {
+    base_quote_ratio_sqrt: Q64_128,
+    constant_product: Q64_128,
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    last_constant_product_sqrt: Q64_128,
+}
+
+/// Represents the difference between the vault balances of the AMM
+/// and its recorded reserves plus the protocol and creator fees to redeem.
+///
+/// # Fields
+/// - `base_surplus`: The base tokens held by the base vault above the recorded amount.
+/// - `quote_surplus`: The quote tokens held by the quote vault above the recorded amount.
+/// - `base_shortfall`: The base tokens missing from the base vault below the recorded amount.
+/// - `quote_shortfall`: The quote tokens missing from the quote vault below the recorded amount.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ReservesDiscrepancy {
+    base_surplus: u64,
+    quote_surplus: u64,
+    base_shortfall: u64,
+    quote_shortfall: u64,
+}
+impl ReservesDiscrepancy {
+    /// Returns the base tokens held by the base vault above the recorded amount.
+    pub fn base_surplus(&self) -> u64 {
+        self.base_surplus
+    }
+
+    /// Returns the quote tokens held by the quote vault above the recorded amount.
+    pub fn quote_surplus(&self) -> u64 {
+        self.quote_surplus
+    }
+
+    /// Returns the base tokens missing from the base vault below the recorded amount.
+    pub fn base_shortfall(&self) -> u64 {
+        self.base_shortfall
+    }
+
+    /// Returns the quote tokens missing from the quote vault below the recorded amount.
+    pub fn quote_shortfall(&self) -> u64 {
+        self.quote_shortfall
+    }
 }
 
 /// Represents the data required for a token swap operation in the AMM.
@@ -1410,37 +3579,52 @@ impl WithdrawPayload {
 /// # Fields
 /// - `base_liquidity`: The updated base token liquidity in the pool.
 /// - `quote_liquidity`: The updated quote token liquidity in the pool.
-/// - `protocol_fees_to_redeem`: The protocol fees to redeem updated with fees collected from the swap.
-/// - `providers_fee_amount`: The providers fees collected from the swap.
+/// - `fees`: The fees collected from the swap.
 /// - `amount_to_withdraw`: The amount of tokens to withdraw after the swap.
+/// - `last_constant_product_sqrt`: The constant product square root to record for protocol fee LP tokens.
 /// - `is_in_out`: Indicates whether the swap is "in-to-out" (true) or "out-to-in" (false).
 #[derive(Debug)]
 pub struct SwapPayload {
     base_liquidity: u64,
     quote_liquidity: u64,
-    protocol_fees_to_redeem: u64,
-    providers_fee_amount: u64,
+    fees: SwapFees,
     amount_to_withdraw: u64,
+    last_constant_product_sqrt: Q64_128,
     is_in_out: bool,
 }
 
+/// Represents the fees collected from a swap in the input token.
+///
+/// # Fields
+/// - `protocol_fees_to_redeem`: The protocol fees to redeem updated with fees collected from the swap.
+/// - `creator_fees_to_redeem`: The creator fees to redeem updated with fees collected from the swap.
+/// - `referral_fee_amount`: The referral fee paid out to the swap referrer.
+/// - `liquidity_fee_amount`: The swap fees added to the pool liquidity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapFees {
+    protocol_fees_to_redeem: u64,
+    creator_fees_to_redeem: u64,
+    referral_fee_amount: u64,
+    liquidity_fee_amount: u64,
+}
+
 impl SwapPayload {
     /// Creates a new `SwapPayload` instance with the specified parameters.
     ///
     /// # Parameters
     /// - `base_liquidity`: The updated base token liquidity.
     /// - `quote_liquidity`: The updated quote token liquidity.
-    /// - `protocol_fees_to_redeem`: The protocol fees to redeem updated with fees collected from the swap.
-    /// - `providers_fee_amount`: The providers fees collected from the swap.
+    /// - `fees`: The fees collected from the swap.
     /// - `amount_to_withdraw`: The amount of tokens withdrawn.
+    /// - `last_constant_product_sqrt`: The constant product square root to record for protocol fee LP tokens.
     /// - `is_in_out`: Indicates the direction of the swap.
-    fn new(base_liquidity: u64, quote_liquidity: u64, protocol_fees_to_redeem: u64, providers_fee_amount: u64, amount_to_withdraw: u64, is_in_out: bool) -> Self {
+    fn new(base_liquidity: u64, quote_liquidity: u64, fees: SwapFees, amount_to_withdraw: u64, last_constant_product_sqrt: Q64_128, is_in_out: bool) -> Self {
         Self{
             base_liquidity,
             quote_liquidity,
-            protocol_fees_to_redeem,
-            providers_fee_amount,
+            fees,
             amount_to_withdraw,
+            last_constant_product_sqrt,
             is_in_out,
         }
     }
@@ -1449,6 +3633,11 @@ impl SwapPayload {
     pub fn amount_to_withdraw(&self) -> u64{
         self.amount_to_withdraw
     }
+
+    /// Returns the referral fee to pay out to the swap referrer.
+    pub fn referral_fee_amount(&self) -> u64{
+        self.fees.referral_fee_amount
+    }
 }
 
 /// Represents the data required for collecting protocol fees in the AMM.
@@ -1510,6 +3699,102 @@ impl CollectFeesPayload {
         self.protocol_quote_fees_to_redeem
     }
 }
+
+/// Represents the data required for collecting creator fees in the AMM.
+///
+/// This struct contains the creator fees for redemption and left fees.
+///
+/// # Fields
+/// - `creator_base_fees_to_redeem`: The amount of creator fees in base tokens for redemption.
+/// - `creator_quote_fees_to_redeem`: The amount of creator fees in quote tokens for redemption.
+/// - `new_creator_base_fees_to_redeem`: Left amount of creator fees in base tokens available for redemption.
+/// - `new_creator_quote_fees_to_redeem`: Left amount of creator fees in quote tokens available for redemption.
+#[derive(Debug)]
+pub struct CollectCreatorFeesPayload {
+    /// The amount of creator fees in base tokens that will be redeemed.
+    creator_base_fees_to_redeem: u64,
+
+    /// The amount of creator fees in quote tokens that will be redeemed.
+    creator_quote_fees_to_redeem: u64,
+
+    /// Left amount of creator fees in base tokens that can be redeemed.
+    new_creator_base_fees_to_redeem: u64,
+
+    /// Left amount of creator fees in quote tokens that can be redeemed.
+    new_creator_quote_fees_to_redeem: u64,
+}
+
+impl CollectCreatorFeesPayload {
+    /// Creates a new `CollectCreatorFeesPayload` instance with the specified parameters.
+    ///
+    /// # Parameters
+    /// - `creator_base_fees_to_redeem`: The amount of creator fees in base tokens for redemption.
+    /// - `creator_quote_fees_to_redeem`: The amount of creator fees in quote tokens for redemption.
+    /// - `new_creator_base_fees_to_redeem`: Left amount of creator fees in base tokens available for redemption.
+    /// - `new_creator_quote_fees_to_redeem`: Left amount of creator fees in quote tokens available for redemption.
+    ///
+    /// # Returns
+    /// - A new instance of `CollectCreatorFeesPayload`.
+    pub fn new(
+        creator_base_fees_to_redeem: u64,
+        creator_quote_fees_to_redeem: u64,
+        new_creator_base_fees_to_redeem: u64,
+        new_creator_quote_fees_to_redeem: u64,
+    ) -> Self {
+        Self {
+            creator_base_fees_to_redeem,
+            creator_quote_fees_to_redeem,
+            new_creator_base_fees_to_redeem,
+            new_creator_quote_fees_to_redeem
+        }
+    }
+
+    /// Returns the amount of creator fees in base tokens for redemption.
+    pub fn creator_base_fees_to_redeem(&self) -> u64 {
+        self.creator_base_fees_to_redeem
+    }
+
+    /// Returns the amount of creator fees in quote tokens for redemption.
+    pub fn creator_quote_fees_to_redeem(&self) -> u64 {
+        self.creator_quote_fees_to_redeem
+    }
+}
+
+/// Represents the payload for closing an AMM.
+///
+/// This struct contains the unredeemed creator fees paid to the creator before the reserves are swept.
+///
+/// # Fields
+/// - `creator_base_fees_to_redeem`: The amount of creator fees in base tokens paid to the creator.
+/// - `creator_quote_fees_to_redeem`: The amount of creator fees in quote tokens paid to the creator.
+#[derive(Debug)]
+pub struct ClosePayload {
+    /// The amount of creator fees in base tokens paid to the creator.
+    creator_base_fees_to_redeem: u64,
+
+    /// The amount of creator fees in quote tokens paid to the creator.
+    creator_quote_fees_to_redeem: u64,
+}
+
+impl ClosePayload {
+    /// Creates a new `ClosePayload` with the specified creator fees.
+    pub fn new(creator_base_fees_to_redeem: u64, creator_quote_fees_to_redeem: u64) -> Self {
+        Self {
+            creator_base_fees_to_redeem,
+            creator_quote_fees_to_redeem
+        }
+    }
+
+    /// Returns the amount of creator fees in base tokens paid to the creator.
+    pub fn creator_base_fees_to_redeem(&self) -> u64 {
+        self.creator_base_fees_to_redeem
+    }
+
+    /// Returns the amount of creator fees in quote tokens paid to the creator.
+    pub fn creator_quote_fees_to_redeem(&self) -> u64 {
+        self.creator_quote_fees_to_redeem
+    }
+}
 #[cfg(test)]
 mod payloads_tests {
     use super::*;
@@ -1524,6 +3809,7 @@ mod payloads_tests {
             4000,
             5000,
             6000,
+            Q64_128::from_u64(2000),
         );
 
         assert_eq!(payload.initial_locked_liquidity, 1000);
@@ -1532,6 +3818,7 @@ mod payloads_tests {
         assert_eq!(payload.base_liquidity, 4000);
         assert_eq!(payload.quote_liquidity, 5000);
         assert_eq!(payload.lp_tokens_supply, 6000);
+        assert_eq!(payload.last_constant_product_sqrt, Q64_128::from_u64(2000));
 
         assert_eq!(payload.initial_locked_liquidity(), 1000);
         assert_eq!(payload.launch_liquidity(), 5000);
@@ -1547,6 +3834,7 @@ mod payloads_tests {
             5000,
             6000,
             7000,
+            ProtocolFeeLpTokens::new(100, Q64_128::from_u64(3000)),
         );
 
         assert_eq!(payload.base_quote_ratio_sqrt, Q64_128::from_u64(2000));
@@ -1555,8 +3843,11 @@ mod payloads_tests {
         assert_eq!(payload.quote_liquidity, 5000);
         assert_eq!(payload.lp_tokens_supply, 6000);
         assert_eq!(payload.lp_tokens_to_mint, 7000);
+        assert_eq!(payload.protocol_fee_lp_tokens.lp_tokens_to_mint, 100);
+        assert_eq!(payload.protocol_fee_lp_tokens.last_constant_product_sqrt, Q64_128::from_u64(3000));
 
         assert_eq!(payload.lp_tokens_to_mint(), 7000);
+        assert_eq!(payload.protocol_fee_lp_tokens_to_mint(), 100);
     }
 
     /// Tests the `WithdrawPayload` struct's creation and getters.
@@ -1569,6 +3860,7 @@ mod payloads_tests {
             6000,
             1000,
             2000,
+            ProtocolFeeLpTokens::new(100, Q64_128::from_u64(3000)),
         );
 
         assert_eq!(payload.base_quote_ratio_sqrt, Q64_128::from_u64(2000));
@@ -1577,24 +3869,30 @@ mod payloads_tests {
         assert_eq!(payload.lp_tokens_supply, 6000);
         assert_eq!(payload.base_withdraw_amount, 1000);
         assert_eq!(payload.quote_withdraw_amount, 2000);
+        assert_eq!(payload.protocol_fee_lp_tokens.lp_tokens_to_mint, 100);
+        assert_eq!(payload.protocol_fee_lp_tokens.last_constant_product_sqrt, Q64_128::from_u64(3000));
 
         assert_eq!(payload.base_withdraw_amount(), 1000);
         assert_eq!(payload.quote_withdraw_amount(), 2000);
+        assert_eq!(payload.protocol_fee_lp_tokens_to_mint(), 100);
     }
 
     /// Tests the `SwapPayload` struct's creation and getters.
     #[test]
     fn test_swap_payload() {
-        let payload = SwapPayload::new(4000, 5000, 6000, 6500,7000, true);
+        let payload = SwapPayload::new(4000, 5000, SwapFees { protocol_fees_to_redeem: 6000, creator_fees_to_redeem: 6200, referral_fee_amount: 6300, liquidity_fee_amount: 6500 }, 7000, Q64_128::from_u64(0), true);
 
         assert_eq!(payload.base_liquidity, 4000);
         assert_eq!(payload.quote_liquidity, 5000);
-        assert_eq!(payload.protocol_fees_to_redeem, 6000);
-        assert_eq!(payload.providers_fee_amount, 6500);
+        assert_eq!(payload.fees.protocol_fees_to_redeem, 6000);
+        assert_eq!(payload.fees.creator_fees_to_redeem, 6200);
+        assert_eq!(payload.fees.referral_fee_amount, 6300);
+        assert_eq!(payload.fees.liquidity_fee_amount, 6500);
         assert_eq!(payload.amount_to_withdraw, 7000);
         assert!(payload.is_in_out);
 
         assert_eq!(payload.amount_to_withdraw(), 7000);
+        assert_eq!(payload.referral_fee_amount(), 6300);
     }
     
     /// Tests the `CollectFeesPayload` struct's creation and getters.
@@ -1609,4 +3907,17 @@ mod payloads_tests {
         assert_eq!(payload.protocol_base_fees_to_redeem(), 112314);
         assert_eq!(payload.protocol_quote_fees_to_redeem(), 536454000);
     }
+
+    /// Tests the `CollectCreatorFeesPayload` struct's creation and getters.
+    #[test]
+    fn test_collect_creator_fees_payload() {
+        let payload = CollectCreatorFeesPayload::new(2314, 6454000, 0, 0);
+
+        assert_eq!(payload.creator_base_fees_to_redeem, 2314);
+        assert_eq!(payload.creator_quote_fees_to_redeem, 6454000);
+        assert_eq!(payload.new_creator_base_fees_to_redeem, 0);
+        assert_eq!(payload.new_creator_quote_fees_to_redeem, 0);
+        assert_eq!(payload.creator_base_fees_to_redeem(), 2314);
+        assert_eq!(payload.creator_quote_fees_to_redeem(), 6454000);
+    }
 }
\ No newline at end of file
@@ -60,15 +60,16 @@ pub(crate) trait CpAmmCalculate: CpAmmCore {
     ///
     /// # Parameters
     /// - `new_constant_product_sqrt`: The square root of the new constant product after providing liquidity.
+    /// - `lp_tokens_supply`: The LP tokens supply the provided liquidity is measured against.
     ///
     /// # Returns
     /// - `Some(u64)` with the amount of LP tokens to mint if the calculation is valid.
     /// - `None` if the calculation fails (e.g., due to underflow or zero tokens).
-    fn calculate_lp_mint_for_provided_liquidity(&self, new_constant_product_sqrt: Q64_128) -> Option<u64> {
+    fn calculate_lp_mint_for_provided_liquidity(&self, new_constant_product_sqrt: Q64_128, lp_tokens_supply: u64) -> Option<u64> {
         let provided_liquidity = new_constant_product_sqrt.checked_sub(self.constant_product_sqrt())?;
 
         let share_from_current_liquidity = provided_liquidity.checked_div(self.constant_product_sqrt())?;
-        let tokens_to_mint = share_from_current_liquidity.checked_mul(Q64_128::from_u64(self.lp_tokens_supply()))?.as_u64();
+        let tokens_to_mint = share_from_current_liquidity.checked_mul(Q64_128::from_u64(lp_tokens_supply))?.as_u64();
         if tokens_to_mint == 0{
             return None;
         }
@@ -79,12 +80,13 @@ pub(crate) trait CpAmmCalculate: CpAmmCore {
     ///
     /// # Parameters
     /// - `lp_tokens`: The number of LP tokens being redeemed.
+    /// - `lp_tokens_supply`: The LP tokens supply the redeemed tokens are a share of.
     ///
     /// # Returns
     /// - `Some((u64, u64))` with the base and quote liquidity amounts.
     /// - `None` if the calculation fails (e.g., due to zero tokens).
-    fn calculate_liquidity_from_share(&self, lp_tokens: u64) -> Option<(u64, u64)>{
-        let liquidity_share = Q64_128::from_u64(lp_tokens).checked_div(Q64_128::from_u64(self.lp_tokens_supply()))?;
+    fn calculate_liquidity_from_share(&self, lp_tokens: u64, lp_tokens_supply: u64) -> Option<(u64, u64)>{
+        let liquidity_share = Q64_128::from_u64(lp_tokens).checked_div(Q64_128::from_u64(lp_tokens_supply))?;
         let constant_product_sqrt_share = self.constant_product_sqrt().checked_mul(liquidity_share)?;
         let base_withdraw = constant_product_sqrt_share.saturating_mul(self.base_quote_ratio_sqrt()).as_u64_round();
         let quote_withdraw = constant_product_sqrt_share.saturating_checked_div(self.base_quote_ratio_sqrt())?.as_u64_round();
@@ -95,6 +97,64 @@ pub(crate) trait CpAmmCalculate: CpAmmCore {
         Some((base_withdraw, quote_withdraw))
     }
 
+    /// Calculates the LP tokens minted to the protocol for the constant product growth since the last liquidity event,
+    /// following Uniswap v2 `mintFee`.
+    ///
+    /// Swaps raise the recorded constant product by the growth from the providers fee,
+    /// so the whole growth left above it comes from the protocol fee retained in the pool.
+    /// The minted amount `x` makes the protocol own `(√k - √k_last) / √k` of the pool: `x = S * (√k - √k_last) / √k_last`.
+    ///
+    /// # Parameters
+    /// - `last_constant_product_sqrt`: The constant product square root recorded for protocol fee LP tokens, zero if unknown.
+    ///
+    /// # Returns
+    /// - `Some(u64)` with the amount of LP tokens to mint, `0` if there is no protocol growth.
+    /// - `None` if the calculation fails.
+    fn calculate_protocol_fee_lp_tokens(&self, last_constant_product_sqrt: Q64_128) -> Option<u64> {
+        if last_constant_product_sqrt.is_zero() || self.constant_product_sqrt() <= last_constant_product_sqrt {
+            return Some(0);
+        }
+        let protocol_growth = self.constant_product_sqrt().checked_sub(last_constant_product_sqrt)?;
+        let protocol_pool_share = protocol_growth.checked_div(last_constant_product_sqrt)?;
+        Some(protocol_pool_share.checked_mul(Q64_128::from_u64(self.lp_tokens_supply()))?.as_u64())
+    }
+
+    /// Calculates the constant product square root to record for protocol fee LP tokens after a swap.
+    ///
+    /// The recorded value follows the growth from the liquidity fee except its protocol part,
+    /// so referral, creator and launch fees never count towards protocol fee LP tokens.
+    ///
+    /// # Parameters
+    /// - `last_constant_product_sqrt`: The constant product square root recorded before the swap, zero if unknown.
+    /// - `new_constant_product_sqrt`: The constant product square root after the swap fees are added to the liquidity.
+    /// - `liquidity_fee_amount`: The swap fees added to the pool liquidity.
+    /// - `protocol_liquidity_fee_amount`: The part of `liquidity_fee_amount` retained for the protocol.
+    ///
+    /// # Returns
+    /// - `Some(Q64_128)` with the constant product square root to record, zero if none is recorded.
+    /// - `None` if the calculation fails.
+    fn calculate_last_constant_product_sqrt_after_swap(
+        &self,
+        last_constant_product_sqrt: Q64_128,
+        new_constant_product_sqrt: Q64_128,
+        liquidity_fee_amount: u64,
+        protocol_liquidity_fee_amount: u64
+    ) -> Option<Q64_128> {
+        if last_constant_product_sqrt.is_zero() || new_constant_product_sqrt <= self.constant_product_sqrt() {
+            return Some(last_constant_product_sqrt);
+        }
+        let growth = new_constant_product_sqrt.checked_sub(self.constant_product_sqrt())?;
+        let providers_growth = if liquidity_fee_amount == 0 {
+            growth
+        }
+        else {
+            let providers_liquidity_fee_amount = liquidity_fee_amount.checked_sub(protocol_liquidity_fee_amount)?;
+            let providers_share = Q64_128::from_u64(providers_liquidity_fee_amount).checked_div(Q64_128::from_u64(liquidity_fee_amount))?;
+            growth.checked_mul(providers_share)?
+        };
+        last_constant_product_sqrt.checked_add(providers_growth)
+    }
+
     /// Calculates the new base and quote liquidity after a swap.
     ///
     /// # Parameters
@@ -449,7 +509,7 @@ mod tests {
             let amm = TestCpAmm::try_new(base_liquidity, quote_liquidity).unwrap();
 
             let lp_tokens = 2_000;
-            let (base, quote) = amm.calculate_liquidity_from_share(lp_tokens).unwrap();
+            let (base, quote) = amm.calculate_liquidity_from_share(lp_tokens, amm.lp_tokens_supply).unwrap();
 
             assert!(
                 base > 0 && quote > 0,
@@ -486,7 +546,7 @@ mod tests {
             let new_quote_liquidity = quote_liquidity + 2_500;
 
             let new_constant_product_sqrt = TestCpAmm::calculate_constant_product_sqrt(new_base_liquidity, new_quote_liquidity).unwrap();
-            let minted_tokens = amm.calculate_lp_mint_for_provided_liquidity(new_constant_product_sqrt).unwrap();
+            let minted_tokens = amm.calculate_lp_mint_for_provided_liquidity(new_constant_product_sqrt, amm.lp_tokens_supply).unwrap();
 
             assert!(minted_tokens > 0, "Minted tokens should be positive, got: {}", minted_tokens);
 
@@ -503,6 +563,44 @@ mod tests {
             );
         }
 
+        /// Tests `calculate_protocol_fee_lp_tokens` against the Uniswap v2 `mintFee` formula.
+        #[test]
+        fn test_calculate_protocol_fee_lp_tokens() {
+            let amm = TestCpAmm::try_new(1_440_000, 1_000_000).unwrap();
+            let last_constant_product_sqrt = Q64_128::from_u64(1_000_000);
+
+            // The whole growth belongs to the protocol: `S * (√k - √k_last) / √k_last`
+            let expected_lp_tokens = amm.lp_tokens_supply as f64 * 200_000.0 / 1_000_000.0;
+            let lp_tokens = amm.calculate_protocol_fee_lp_tokens(last_constant_product_sqrt).unwrap();
+            assert!((lp_tokens as f64 - expected_lp_tokens).abs() <= expected_lp_tokens * 0.001, "Expected: {}, Got: {}", expected_lp_tokens, lp_tokens);
+
+            assert_eq!(amm.calculate_protocol_fee_lp_tokens(Q64_128::from_u64(0)), Some(0));
+            assert_eq!(amm.calculate_protocol_fee_lp_tokens(Q64_128::from_u64(1_200_000)), Some(0));
+            assert_eq!(amm.calculate_protocol_fee_lp_tokens(Q64_128::from_u64(1_300_000)), Some(0));
+        }
+
+        /// Tests `calculate_last_constant_product_sqrt_after_swap` follows only the providers part of the growth.
+        #[test]
+        fn test_calculate_last_constant_product_sqrt_after_swap() {
+            let amm = TestCpAmm::try_new(1_000_000, 1_000_000).unwrap();
+            let last_constant_product_sqrt = Q64_128::from_u64(900_000);
+            let new_constant_product_sqrt = Q64_128::from_u64(1_000_400);
+
+            // A quarter of the liquidity fee is the protocol's, three quarters of the growth are recorded
+            let recorded = amm.calculate_last_constant_product_sqrt_after_swap(last_constant_product_sqrt, new_constant_product_sqrt, 400, 100).unwrap();
+            assert_eq!(recorded, Q64_128::from_u64(900_300));
+
+            // Without a protocol part the recorded value follows the whole growth
+            let recorded = amm.calculate_last_constant_product_sqrt_after_swap(last_constant_product_sqrt, new_constant_product_sqrt, 400, 0).unwrap();
+            assert_eq!(recorded, Q64_128::from_u64(900_400));
+            let recorded = amm.calculate_last_constant_product_sqrt_after_swap(last_constant_product_sqrt, new_constant_product_sqrt, 0, 0).unwrap();
+            assert_eq!(recorded, Q64_128::from_u64(900_400));
+
+            // Nothing is recorded when the protocol fee is not taken as LP tokens
+            assert_eq!(amm.calculate_last_constant_product_sqrt_after_swap(Q64_128::from_u64(0), new_constant_product_sqrt, 400, 100), Some(Q64_128::from_u64(0)));
+            assert_eq!(amm.calculate_last_constant_product_sqrt_after_swap(last_constant_product_sqrt, amm.constant_product_sqrt, 400, 100), Some(last_constant_product_sqrt));
+        }
+
         /// Tests `calculate_fee_amount` for correctness.
         #[test]
         fn test_calculate_fee_amount() {
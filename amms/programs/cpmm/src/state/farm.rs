@@ -0,0 +1,596 @@
+use anchor_lang::{account, InitSpace};
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+
+/// Represents a liquidity mining farm of a `CpAmm`.
+///
+/// LP tokens staked in the farm earn external reward tokens. Each reward is emitted at a constant rate
+/// between its start and end timestamps and distributed through a reward-per-share accumulator.
+/// Emissions never exceed the funded amount of the reward.
+#[account]
+#[derive(InitSpace)]
+pub struct Farm {
+    /// The canonical bump seed used for the account's PDA.
+    bump: [u8; 1], // 1 byte
+
+    /// The canonical bump seed used for the stake vault's PDA.
+    stake_vault_bump: [u8; 1], // 1 byte
+
+    /// The public key of the `CpAmm` the farm belongs to.
+    cp_amm: Pubkey, // 32 bytes
+
+    /// The public key of the LP mint staked in the farm.
+    lp_mint: Pubkey, // 32 bytes
+
+    /// The public key of the authority that can add and extend rewards.
+    authority: Pubkey, // 32 bytes
+
+    /// The total amount of LP tokens staked in the farm.
+    total_staked: u64, // 8 bytes
+
+    /// Unix timestamp of the last accumulators update.
+    last_update_timestamp: i64, // 8 bytes
+
+    /// The number of rewards added to the farm.
+    rewards_count: u8, // 1 byte
+
+    /// The rewards of the farm, only the first `rewards_count` are in use.
+    rewards: [FarmReward; Farm::MAX_REWARDS], // 3 * 137 bytes
+}
+
+/// Represents a reward token emitted by a `Farm`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FarmReward {
+    /// The public key of the reward mint.
+    pub mint: Pubkey, // 32 bytes
+
+    /// The public key of the vault holding the reward tokens.
+    pub vault: Pubkey, // 32 bytes
+
+    /// The canonical bump seed used for the vault's PDA.
+    pub vault_bump: u8, // 1 byte
+
+    /// The amount of reward tokens emitted per second.
+    pub emission_rate_per_second: u64, // 8 bytes
+
+    /// Unix timestamp from which the reward is emitted.
+    pub start_timestamp: i64, // 8 bytes
+
+    /// Unix timestamp at which the reward emission ends.
+    pub end_timestamp: i64, // 8 bytes
+
+    /// The accumulated reward per staked LP token, scaled by `Farm::REWARD_PER_SHARE_SCALE`.
+    pub reward_per_share: u128, // 16 bytes
+
+    /// The total amount of reward tokens received by the vault, less the unemitted tokens reclaimed by the authority.
+    pub funded_amount: u64, // 8 bytes
+
+    /// The total amount of reward tokens emitted to stakers.
+    pub emitted_amount: u64, // 8 bytes
+
+    /// The total amount of reward tokens claimed by stakers.
+    pub claimed_amount: u64, // 8 bytes
+
+    /// Reserved for future use.
+    pub padding: [u8; 8], // 8 bytes
+}
+
+/// Represents the emission schedule of a `FarmReward`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FarmRewardSchedule {
+    /// The amount of reward tokens emitted per second.
+    pub emission_rate_per_second: u64,
+
+    /// Unix timestamp from which the reward is emitted.
+    pub start_timestamp: i64,
+
+    /// Unix timestamp at which the reward emission ends.
+    pub end_timestamp: i64,
+}
+
+/// Represents the stake of an owner in a `Farm`.
+#[account]
+#[derive(InitSpace)]
+pub struct FarmStake {
+    /// The canonical bump seed used for the account's PDA.
+    bump: [u8; 1], // 1 byte
+
+    /// The public key of the farm.
+    farm: Pubkey, // 32 bytes
+
+    /// The public key of the stake owner.
+    owner: Pubkey, // 32 bytes
+
+    /// The amount of LP tokens staked.
+    staked_amount: u64, // 8 bytes
+
+    /// The farm reward per share of each reward at the last settlement of the stake.
+    reward_per_share_paid: [u128; Farm::MAX_REWARDS], // 3 * 16 bytes
+
+    /// The settled rewards not yet claimed, for each reward.
+    pending_rewards: [u64; Farm::MAX_REWARDS], // 3 * 8 bytes
+}
+
+impl Farm {
+    /// The seed used to derive the account's PDA.
+    pub const SEED: &'static [u8] = b"farm";
+
+    /// The seed used to derive the stake vault's PDA.
+    pub const STAKE_VAULT_SEED: &'static [u8] = b"farm_stake_vault";
+
+    /// The seed used to derive the reward vaults PDAs.
+    pub const REWARD_VAULT_SEED: &'static [u8] = b"farm_reward_vault";
+
+    /// The maximum number of rewards of a farm.
+    pub const MAX_REWARDS: usize = 3;
+
+    /// The scale of the reward per share accumulators.
+    pub const REWARD_PER_SHARE_SCALE: u128 = 1 << 64;
+
+    /// Returns the seeds for generating the PDA.
+    pub fn seeds(&self) -> [&[u8]; 3] {
+        [Self::SEED, self.cp_amm.as_ref(), self.bump.as_ref()]
+    }
+
+    /// Initializes the `Farm` with the provided parameters.
+    ///
+    /// # Parameters
+    /// - `cp_amm`: The public key of the `CpAmm` the farm belongs to.
+    /// - `lp_mint`: The public key of the LP mint.
+    /// - `authority`: The public key of the authority that can add and extend rewards.
+    /// - `current_timestamp`: The current Unix timestamp.
+    /// - `bump`: The bump seed for the account's PDA.
+    /// - `stake_vault_bump`: The bump seed for the stake vault's PDA.
+    pub(crate) fn initialize(&mut self, cp_amm: Pubkey, lp_mint: Pubkey, authority: Pubkey, current_timestamp: i64, bump: u8, stake_vault_bump: u8) {
+        self.bump = [bump];
+        self.stake_vault_bump = [stake_vault_bump];
+        self.cp_amm = cp_amm;
+        self.lp_mint = lp_mint;
+        self.authority = authority;
+        self.last_update_timestamp = current_timestamp;
+    }
+
+    /// Adds a new reward to the farm.
+    ///
+    /// # Parameters
+    /// - `mint`: The public key of the reward mint.
+    /// - `vault`: The public key of the reward vault.
+    /// - `vault_bump`: The bump seed for the reward vault's PDA.
+    /// - `schedule`: The emission rate and period of the reward.
+    /// - `current_timestamp`: The current Unix timestamp.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::FarmRewardsLimitReached` if the farm already has `MAX_REWARDS` rewards.
+    /// - Returns `ErrorCode::InvalidFarmRewardSchedule` if the emission ends before it starts or in the past.
+    pub(crate) fn add_reward(
+        &mut self,
+        mint: Pubkey,
+        vault: Pubkey,
+        vault_bump: u8,
+        schedule: FarmRewardSchedule,
+        current_timestamp: i64
+    ) -> Result<()> {
+        require!((self.rewards_count as usize) < Self::MAX_REWARDS, ErrorCode::FarmRewardsLimitReached);
+        require!(
+            schedule.end_timestamp > schedule.start_timestamp && schedule.end_timestamp > current_timestamp,
+            ErrorCode::InvalidFarmRewardSchedule
+        );
+        self.update_rewards(current_timestamp);
+        self.rewards[self.rewards_count as usize] = FarmReward {
+            mint,
+            vault,
+            vault_bump,
+            emission_rate_per_second: schedule.emission_rate_per_second,
+            start_timestamp: schedule.start_timestamp,
+            end_timestamp: schedule.end_timestamp,
+            ..Default::default()
+        };
+        self.rewards_count += 1;
+        Ok(())
+    }
+
+    /// Records reward tokens received by a reward vault.
+    ///
+    /// # Parameters
+    /// - `reward_index`: The index of the reward.
+    /// - `amount`: The amount of reward tokens received.
+    pub(crate) fn fund_reward(&mut self, reward_index: usize, amount: u64) -> Result<()> {
+        let reward = self.reward_mut(reward_index)?;
+        reward.funded_amount = reward.funded_amount.checked_add(amount).ok_or(ErrorCode::FarmOverflowError)?;
+        Ok(())
+    }
+
+    /// Extends the emission of a reward, optionally changing its emission rate for the remaining time.
+    ///
+    /// Emissions up to `current_timestamp` are accumulated with the previous rate first.
+    ///
+    /// # Parameters
+    /// - `reward_index`: The index of the reward.
+    /// - `end_timestamp`: The new end of the emission, not earlier than the current one.
+    /// - `emission_rate_per_second`: The new amount of reward tokens emitted per second.
+    /// - `current_timestamp`: The current Unix timestamp.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::InvalidFarmRewardSchedule` if the new end is earlier than the current one or in the past.
+    pub(crate) fn extend_reward(&mut self, reward_index: usize, end_timestamp: i64, emission_rate_per_second: u64, current_timestamp: i64) -> Result<()> {
+        self.update_rewards(current_timestamp);
+        let reward = self.reward_mut(reward_index)?;
+        require!(end_timestamp >= reward.end_timestamp && end_timestamp > current_timestamp, ErrorCode::InvalidFarmRewardSchedule);
+        reward.end_timestamp = end_timestamp;
+        reward.emission_rate_per_second = emission_rate_per_second;
+        Ok(())
+    }
+
+    /// Takes the funded reward tokens that were never emitted out of an ended reward, for the authority to reclaim.
+    ///
+    /// Tokens scheduled while no LP tokens were staked, or funded beyond the schedule, are never emitted.
+    ///
+    /// # Parameters
+    /// - `reward_index`: The index of the reward.
+    /// - `current_timestamp`: The current Unix timestamp.
+    ///
+    /// # Returns
+    /// - `Ok(u64)` with the amount of reward tokens to send to the authority.
+    /// - `Err(ErrorCode::FarmRewardNotEnded)` if the reward emission hasn't ended yet.
+    /// - `Err(ErrorCode::FarmNothingToReclaim)` if all the funded reward tokens were emitted.
+    pub(crate) fn reclaim_unemitted_reward(&mut self, reward_index: usize, current_timestamp: i64) -> Result<u64> {
+        self.update_rewards(current_timestamp);
+        let reward = self.reward_mut(reward_index)?;
+        require!(current_timestamp >= reward.end_timestamp, ErrorCode::FarmRewardNotEnded);
+        let amount = reward.funded_amount.saturating_sub(reward.emitted_amount);
+        require!(amount > 0, ErrorCode::FarmNothingToReclaim);
+        reward.funded_amount = reward.emitted_amount;
+        Ok(amount)
+    }
+
+    /// Accumulates the rewards emitted since the last update into the reward per share accumulators.
+    ///
+    /// Nothing is emitted while no LP tokens are staked, and emissions are capped by the unemitted funded amount.
+    ///
+    /// # Parameters
+    /// - `current_timestamp`: The current Unix timestamp.
+    pub(crate) fn update_rewards(&mut self, current_timestamp: i64) {
+        if current_timestamp <= self.last_update_timestamp {
+            return;
+        }
+        if self.total_staked > 0 {
+            for reward in self.rewards.iter_mut().take(self.rewards_count as usize) {
+                let from = self.last_update_timestamp.max(reward.start_timestamp);
+                let to = current_timestamp.min(reward.end_timestamp);
+                if to <= from {
+                    continue;
+                }
+                let scheduled_amount = (reward.emission_rate_per_second as u128) * ((to - from) as u128);
+                let available_amount = reward.funded_amount.saturating_sub(reward.emitted_amount) as u128;
+                let emitted_amount = scheduled_amount.min(available_amount);
+                reward.reward_per_share += emitted_amount * Self::REWARD_PER_SHARE_SCALE / self.total_staked as u128;
+                reward.emitted_amount += emitted_amount as u64;
+            }
+        }
+        self.last_update_timestamp = current_timestamp;
+    }
+
+    /// Stakes LP tokens, settling the stake rewards first.
+    ///
+    /// # Parameters
+    /// - `stake`: The stake of the owner.
+    /// - `amount`: The amount of LP tokens to stake.
+    /// - `current_timestamp`: The current Unix timestamp.
+    pub(crate) fn stake(&mut self, stake: &mut FarmStake, amount: u64, current_timestamp: i64) -> Result<()> {
+        require!(amount > 0, ErrorCode::FarmStakeAmountIsZero);
+        self.update_rewards(current_timestamp);
+        stake.settle(self)?;
+        stake.staked_amount = stake.staked_amount.checked_add(amount).ok_or(ErrorCode::FarmOverflowError)?;
+        self.total_staked = self.total_staked.checked_add(amount).ok_or(ErrorCode::FarmOverflowError)?;
+        Ok(())
+    }
+
+    /// Unstakes LP tokens, settling the stake rewards first.
+    ///
+    /// # Parameters
+    /// - `stake`: The stake of the owner.
+    /// - `amount`: The amount of LP tokens to unstake.
+    /// - `current_timestamp`: The current Unix timestamp.
+    pub(crate) fn unstake(&mut self, stake: &mut FarmStake, amount: u64, current_timestamp: i64) -> Result<()> {
+        require!(amount > 0, ErrorCode::FarmStakeAmountIsZero);
+        require!(stake.staked_amount >= amount, ErrorCode::InsufficientFarmStake);
+        self.update_rewards(current_timestamp);
+        stake.settle(self)?;
+        stake.staked_amount -= amount;
+        self.total_staked -= amount;
+        Ok(())
+    }
+
+    /// Settles the stake rewards and takes its pending amount of a reward out for claiming.
+    ///
+    /// # Parameters
+    /// - `stake`: The stake of the owner.
+    /// - `reward_index`: The index of the reward to claim.
+    /// - `current_timestamp`: The current Unix timestamp.
+    ///
+    /// # Returns
+    /// - `Ok(u64)` with the amount of reward tokens to send to the owner.
+    /// - `Err(ErrorCode::FarmNothingToClaim)` if the stake has no pending amount of the reward.
+    pub(crate) fn claim(&mut self, stake: &mut FarmStake, reward_index: usize, current_timestamp: i64) -> Result<u64> {
+        self.reward(reward_index)?;
+        self.update_rewards(current_timestamp);
+        stake.settle(self)?;
+        let amount = stake.pending_rewards[reward_index];
+        require!(amount > 0, ErrorCode::FarmNothingToClaim);
+        stake.pending_rewards[reward_index] = 0;
+        let reward = &mut self.rewards[reward_index];
+        reward.claimed_amount = reward.claimed_amount.checked_add(amount).ok_or(ErrorCode::FarmOverflowError)?;
+        Ok(amount)
+    }
+
+    /// Returns the reward at `reward_index`.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::InvalidFarmRewardIndex` if the farm has no reward at `reward_index`.
+    pub fn reward(&self, reward_index: usize) -> Result<&FarmReward> {
+        require!(reward_index < self.rewards_count as usize, ErrorCode::InvalidFarmRewardIndex);
+        Ok(&self.rewards[reward_index])
+    }
+
+    fn reward_mut(&mut self, reward_index: usize) -> Result<&mut FarmReward> {
+        require!(reward_index < self.rewards_count as usize, ErrorCode::InvalidFarmRewardIndex);
+        Ok(&mut self.rewards[reward_index])
+    }
+
+    /// Returns the bump seed used for the account's PDA.
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    /// Returns the bump seed used for the stake vault's PDA.
+    #[inline]
+    pub fn stake_vault_bump(&self) -> u8 {
+        self.stake_vault_bump[0]
+    }
+
+    /// Returns the public key of the `CpAmm` the farm belongs to.
+    #[inline]
+    pub fn cp_amm(&self) -> &Pubkey {
+        &self.cp_amm
+    }
+
+    /// Returns the public key of the LP mint staked in the farm.
+    #[inline]
+    pub fn lp_mint(&self) -> &Pubkey {
+        &self.lp_mint
+    }
+
+    /// Returns the public key of the authority that can add and extend rewards.
+    #[inline]
+    pub fn authority(&self) -> &Pubkey {
+        &self.authority
+    }
+
+    /// Returns the total amount of LP tokens staked in the farm.
+    #[inline]
+    pub fn total_staked(&self) -> u64 {
+        self.total_staked
+    }
+
+    /// Returns the number of rewards added to the farm.
+    #[inline]
+    pub fn rewards_count(&self) -> u8 {
+        self.rewards_count
+    }
+}
+
+impl FarmStake {
+    /// The seed used to derive the account's PDA.
+    pub const SEED: &'static [u8] = b"farm_stake";
+
+    /// Initializes the `FarmStake` if it's new.
+    ///
+    /// # Parameters
+    /// - `farm`: The public key of the farm.
+    /// - `owner`: The public key of the stake owner.
+    /// - `bump`: The bump seed for the account's PDA.
+    pub(crate) fn initialize_if_needed(&mut self, farm: Pubkey, owner: Pubkey, bump: u8) {
+        if self.farm == Pubkey::default() {
+            self.bump = [bump];
+            self.farm = farm;
+            self.owner = owner;
+        }
+    }
+
+    /// Moves the rewards accumulated since the last settlement to the pending rewards.
+    ///
+    /// A reward added after the last settlement starts with a zero accumulator, so the stake
+    /// earns all of its emissions.
+    fn settle(&mut self, farm: &Farm) -> Result<()> {
+        for (index, reward) in farm.rewards.iter().enumerate().take(farm.rewards_count as usize) {
+            let accrued_reward_per_share = reward.reward_per_share - self.reward_per_share_paid[index];
+            let accrued_amount = (self.staked_amount as u128 * accrued_reward_per_share / Farm::REWARD_PER_SHARE_SCALE) as u64;
+            self.pending_rewards[index] = self.pending_rewards[index].checked_add(accrued_amount).ok_or(ErrorCode::FarmOverflowError)?;
+            self.reward_per_share_paid[index] = reward.reward_per_share;
+        }
+        Ok(())
+    }
+
+    /// Returns the bump seed used for the account's PDA.
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    /// Returns the public key of the farm.
+    #[inline]
+    pub fn farm(&self) -> &Pubkey {
+        &self.farm
+    }
+
+    /// Returns the public key of the stake owner.
+    #[inline]
+    pub fn owner(&self) -> &Pubkey {
+        &self.owner
+    }
+
+    /// Returns the amount of LP tokens staked.
+    #[inline]
+    pub fn staked_amount(&self) -> u64 {
+        self.staked_amount
+    }
+
+    /// Returns the settled rewards not yet claimed.
+    #[inline]
+    pub fn pending_rewards(&self) -> [u64; Farm::MAX_REWARDS] {
+        self.pending_rewards
+    }
+}
+
+#[cfg(test)]
+mod farm_tests {
+    use anchor_lang::Discriminator;
+    use crate::constants::ANCHOR_DISCRIMINATOR;
+    use super::*;
+
+    fn build_farm() -> Farm {
+        let data = [0u8; ANCHOR_DISCRIMINATOR + Farm::INIT_SPACE];
+        let mut farm = Farm::try_deserialize_unchecked(&mut data.as_ref()).unwrap();
+        farm.initialize(Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), 0, 255, 254);
+        farm
+    }
+
+    fn build_schedule(emission_rate_per_second: u64, start_timestamp: i64, end_timestamp: i64) -> FarmRewardSchedule {
+        FarmRewardSchedule { emission_rate_per_second, start_timestamp, end_timestamp }
+    }
+
+    fn build_stake(farm: &Farm) -> FarmStake {
+        let data = [0u8; ANCHOR_DISCRIMINATOR + FarmStake::INIT_SPACE];
+        let mut stake = FarmStake::try_deserialize_unchecked(&mut data.as_ref()).unwrap();
+        stake.initialize_if_needed(Pubkey::new_unique(), Pubkey::new_unique(), 253);
+        stake.reward_per_share_paid = farm.rewards.map(|reward| reward.reward_per_share);
+        stake
+    }
+
+    /// Tests the validation of `add_reward`.
+    #[test]
+    fn test_farm_add_reward() {
+        let mut farm = build_farm();
+        assert_eq!(farm.add_reward(Pubkey::new_unique(), Pubkey::new_unique(), 0, build_schedule(10, 100, 100), 0).unwrap_err(), ErrorCode::InvalidFarmRewardSchedule.into());
+        assert_eq!(farm.add_reward(Pubkey::new_unique(), Pubkey::new_unique(), 0, build_schedule(10, 100, 200), 200).unwrap_err(), ErrorCode::InvalidFarmRewardSchedule.into());
+        for _ in 0..Farm::MAX_REWARDS {
+            farm.add_reward(Pubkey::new_unique(), Pubkey::new_unique(), 0, build_schedule(10, 100, 200), 0).unwrap();
+        }
+        assert_eq!(farm.rewards_count(), Farm::MAX_REWARDS as u8);
+        assert_eq!(farm.add_reward(Pubkey::new_unique(), Pubkey::new_unique(), 0, build_schedule(10, 100, 200), 0).unwrap_err(), ErrorCode::FarmRewardsLimitReached.into());
+        assert_eq!(farm.fund_reward(Farm::MAX_REWARDS, 10).unwrap_err(), ErrorCode::InvalidFarmRewardIndex.into());
+    }
+
+    /// Tests the distribution of a reward between two stakers.
+    #[test]
+    fn test_farm_rewards_distribution() {
+        let mut farm = build_farm();
+        farm.add_reward(Pubkey::new_unique(), Pubkey::new_unique(), 0, build_schedule(10, 100, 200), 0).unwrap();
+        farm.fund_reward(0, 1000).unwrap();
+
+        let mut first_stake = build_stake(&farm);
+        let mut second_stake = build_stake(&farm);
+
+        // Nothing is emitted before the start
+        farm.stake(&mut first_stake, 300, 50).unwrap();
+        assert_eq!(farm.reward(0).unwrap().emitted_amount, 0);
+
+        // The first staker earns all the emissions until the second one joins
+        farm.stake(&mut second_stake, 100, 150).unwrap();
+        assert_eq!(farm.reward(0).unwrap().emitted_amount, 500);
+        assert_eq!(farm.total_staked(), 400);
+
+        // The rest is split 3:1, the accumulator rounds down so dust stays in the vault
+        assert_eq!(farm.claim(&mut first_stake, 0, 250).unwrap(), 874);
+        assert_eq!(farm.claim(&mut second_stake, 0, 250).unwrap(), 125);
+        assert_eq!(farm.claim(&mut second_stake, 0, 300).unwrap_err(), ErrorCode::FarmNothingToClaim.into());
+        assert_eq!(farm.reward(0).unwrap().emitted_amount, 1000);
+        assert_eq!(farm.reward(0).unwrap().claimed_amount, 999);
+
+        farm.unstake(&mut first_stake, 300, 300).unwrap();
+        assert_eq!(first_stake.staked_amount(), 0);
+        assert_eq!(farm.unstake(&mut first_stake, 1, 300).unwrap_err(), ErrorCode::InsufficientFarmStake.into());
+        assert_eq!(farm.claim(&mut first_stake, 1, 300).unwrap_err(), ErrorCode::InvalidFarmRewardIndex.into());
+    }
+
+    /// Tests that emissions are capped by the funded amount and resume once funded and extended.
+    #[test]
+    fn test_farm_underfunded_and_extended_reward() {
+        let mut farm = build_farm();
+        farm.add_reward(Pubkey::new_unique(), Pubkey::new_unique(), 0, build_schedule(10, 0, 100), 0).unwrap();
+        farm.fund_reward(0, 300).unwrap();
+
+        let mut stake = build_stake(&farm);
+        farm.stake(&mut stake, 1000, 0).unwrap();
+
+        farm.update_rewards(50);
+        assert_eq!(farm.reward(0).unwrap().emitted_amount, 300);
+
+        assert_eq!(farm.extend_reward(0, 90, 10, 60).unwrap_err(), ErrorCode::InvalidFarmRewardSchedule.into());
+        farm.fund_reward(0, 1000).unwrap();
+        farm.extend_reward(0, 200, 5, 100).unwrap();
+        farm.update_rewards(200);
+        assert_eq!(farm.reward(0).unwrap().emitted_amount, 1200);
+        assert_eq!(farm.claim(&mut stake, 0, 200).unwrap(), 1199);
+    }
+
+    /// Tests that the authority can reclaim the rewards scheduled while nothing was staked once the emission ends.
+    #[test]
+    fn test_farm_reclaim_unemitted_reward() {
+        let mut farm = build_farm();
+        farm.add_reward(Pubkey::new_unique(), Pubkey::new_unique(), 0, build_schedule(10, 0, 100), 0).unwrap();
+        farm.fund_reward(0, 1200).unwrap();
+
+        // Nothing is staked for the first half of the emission
+        let mut stake = build_stake(&farm);
+        farm.stake(&mut stake, 1024, 50).unwrap();
+        assert_eq!(farm.reclaim_unemitted_reward(0, 99).unwrap_err(), ErrorCode::FarmRewardNotEnded.into());
+
+        assert_eq!(farm.reclaim_unemitted_reward(0, 100).unwrap(), 700);
+        assert_eq!(farm.reward(0).unwrap().funded_amount, 500);
+        assert_eq!(farm.reclaim_unemitted_reward(0, 150).unwrap_err(), ErrorCode::FarmNothingToReclaim.into());
+        assert_eq!(farm.claim(&mut stake, 0, 150).unwrap(), 500);
+    }
+
+    /// Tests the data layout of `FarmStake`.
+    #[test]
+    fn test_farm_stake_data_layout() {
+        let bump = 253u8;
+        let farm = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let staked_amount = 1_000u64;
+        let reward_per_share_paid = [1u128 << 64, 2, 3];
+        let pending_rewards = [4u64, 5, 6];
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 145];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&FarmStake::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset] = bump; offset += 1;
+        data[offset..offset + 32].copy_from_slice(farm.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(owner.as_ref()); offset += 32;
+        data[offset..offset + 8].copy_from_slice(&staked_amount.to_le_bytes()); offset += 8;
+        for value in reward_per_share_paid {
+            data[offset..offset + 16].copy_from_slice(&value.to_le_bytes()); offset += 16;
+        }
+        for value in pending_rewards {
+            data[offset..offset + 8].copy_from_slice(&value.to_le_bytes()); offset += 8;
+        }
+
+        assert_eq!(offset, ANCHOR_DISCRIMINATOR + FarmStake::INIT_SPACE);
+
+        let deserialized_stake = FarmStake::try_deserialize(&mut data.as_ref()).unwrap();
+
+        assert_eq!(deserialized_stake.bump(), bump);
+        assert_eq!(deserialized_stake.farm, farm);
+        assert_eq!(deserialized_stake.owner, owner);
+        assert_eq!(deserialized_stake.staked_amount, staked_amount);
+        assert_eq!(deserialized_stake.reward_per_share_paid, reward_per_share_paid);
+        assert_eq!(deserialized_stake.pending_rewards, pending_rewards);
+
+        let mut serialized_stake = Vec::new();
+        deserialized_stake.try_serialize(&mut serialized_stake).unwrap();
+        assert_eq!(serialized_stake.as_slice(), data.as_ref());
+    }
+}
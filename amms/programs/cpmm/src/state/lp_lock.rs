@@ -0,0 +1,358 @@
+use anchor_lang::{account, InitSpace};
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+
+/// Represents an escrow of LP tokens, released to its beneficiary over time.
+///
+/// LP tokens are held in a vault owned by the `LpLock` PDA. They unlock at `unlock_timestamp`,
+/// or linearly from `vesting_start_timestamp` to `unlock_timestamp` when it's earlier.
+#[account]
+#[derive(InitSpace)]
+pub struct LpLock {
+    /// The canonical bump seed used for the account's PDA.
+    bump: [u8; 1], // 1 byte
+
+    /// The canonical bump seed used for the vault's PDA.
+    vault_bump: [u8; 1], // 1 byte
+
+    /// The public key of the `CpAmm` the LP tokens belong to.
+    cp_amm: Pubkey, // 32 bytes
+
+    /// The public key of the LP mint.
+    lp_mint: Pubkey, // 32 bytes
+
+    /// The public key of the account that locked the LP tokens.
+    owner: Pubkey, // 32 bytes
+
+    /// The public key of the account that can claim the unlocked LP tokens.
+    beneficiary: Pubkey, // 32 bytes
+
+    /// The nonce distinguishing locks of the same owner and LP mint.
+    nonce: u64, // 8 bytes
+
+    /// The amount of LP tokens locked.
+    locked_amount: u64, // 8 bytes
+
+    /// The amount of LP tokens already claimed by the beneficiary.
+    claimed_amount: u64, // 8 bytes
+
+    /// Unix timestamp from which the LP tokens start vesting.
+    vesting_start_timestamp: i64, // 8 bytes
+
+    /// Unix timestamp at which all the LP tokens are unlocked.
+    unlock_timestamp: i64, // 8 bytes
+}
+
+/// Represents the release schedule of an `LpLock`.
+///
+/// A `vesting_start_timestamp` equal to `unlock_timestamp` releases all the LP tokens at once.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LpLockSchedule {
+    /// Unix timestamp from which the LP tokens start vesting.
+    pub vesting_start_timestamp: i64, // 8 bytes
+
+    /// Unix timestamp at which all the LP tokens are unlocked.
+    pub unlock_timestamp: i64, // 8 bytes
+}
+
+/// Represents the LP tokens escrowed by an `LpLock`, their beneficiary and release schedule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LpLockTerms {
+    /// The public key of the account that can claim the unlocked LP tokens.
+    pub beneficiary: Pubkey,
+
+    /// The amount of LP tokens locked.
+    pub locked_amount: u64,
+
+    /// The release schedule of the locked LP tokens.
+    pub schedule: LpLockSchedule,
+}
+
+/// Represents the share of the creator's launch LP tokens to lock at the launch and its release schedule.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CreatorLpLockConfig {
+    /// The share of the creator's launch LP tokens to lock, measured in basis points.
+    pub share_basis_points: u16,
+
+    /// The release schedule of the locked LP tokens.
+    pub schedule: LpLockSchedule,
+}
+
+impl LpLock {
+    /// The seed used to derive the account's PDA.
+    pub const SEED: &'static [u8] = b"lp_lock";
+
+    /// The seed used to derive the vault's PDA.
+    pub const VAULT_SEED: &'static [u8] = b"lp_lock_vault";
+
+    /// The nonce of the lock created by `launch_cp_amm`.
+    pub const LAUNCH_NONCE: u64 = 0;
+
+    /// Initializes the `LpLock` with the provided parameters.
+    ///
+    /// The LP tokens are locked by `lock` in the same instruction.
+    ///
+    /// # Parameters
+    /// - `cp_amm`: The public key of the `CpAmm` the LP tokens belong to.
+    /// - `lp_mint`: The public key of the LP mint.
+    /// - `owner`: The public key of the account locking the LP tokens.
+    /// - `nonce`: The nonce of the lock.
+    /// - `bump`: The bump seed for the account's PDA.
+    /// - `vault_bump`: The bump seed for the vault's PDA.
+    pub(crate) fn initialize(&mut self, cp_amm: Pubkey, lp_mint: Pubkey, owner: Pubkey, nonce: u64, bump: u8, vault_bump: u8) {
+        self.bump = [bump];
+        self.vault_bump = [vault_bump];
+        self.cp_amm = cp_amm;
+        self.lp_mint = lp_mint;
+        self.owner = owner;
+        self.nonce = nonce;
+    }
+
+    /// Locks the LP tokens of the `LpLock` on the provided terms.
+    ///
+    /// # Parameters
+    /// - `terms`: The amount of LP tokens locked, their beneficiary and release schedule.
+    /// - `current_timestamp`: The current Unix timestamp.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::LpLockAmountIsZero` if the locked amount is zero.
+    /// - Returns `ErrorCode::InvalidLpLockSchedule` if the vesting starts after the unlock or the unlock isn't in the future.
+    pub(crate) fn lock(&mut self, terms: LpLockTerms, current_timestamp: i64) -> Result<()> {
+        let LpLockTerms { beneficiary, locked_amount, schedule } = terms;
+        require!(locked_amount > 0, ErrorCode::LpLockAmountIsZero);
+        require!(
+            schedule.vesting_start_timestamp <= schedule.unlock_timestamp && schedule.unlock_timestamp > current_timestamp,
+            ErrorCode::InvalidLpLockSchedule
+        );
+        self.beneficiary = beneficiary;
+        self.locked_amount = locked_amount;
+        self.claimed_amount = 0;
+        self.vesting_start_timestamp = schedule.vesting_start_timestamp;
+        self.unlock_timestamp = schedule.unlock_timestamp;
+        Ok(())
+    }
+
+    /// Calculates the amount of LP tokens unlocked at `current_timestamp`, including the claimed ones.
+    pub fn unlocked_amount(&self, current_timestamp: i64) -> u64 {
+        if current_timestamp >= self.unlock_timestamp {
+            return self.locked_amount;
+        }
+        if current_timestamp <= self.vesting_start_timestamp {
+            return 0;
+        }
+        let elapsed = (current_timestamp - self.vesting_start_timestamp) as u128;
+        let duration = (self.unlock_timestamp - self.vesting_start_timestamp) as u128;
+        (self.locked_amount as u128 * elapsed / duration) as u64
+    }
+
+    /// Calculates the amount of LP tokens the beneficiary can claim at `current_timestamp`.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::LpLockNothingToClaim` if no unlocked LP tokens are left to claim.
+    pub fn get_claimable_amount(&self, current_timestamp: i64) -> Result<u64> {
+        let claimable_amount = self.unlocked_amount(current_timestamp).saturating_sub(self.claimed_amount);
+        require!(claimable_amount > 0, ErrorCode::LpLockNothingToClaim);
+        Ok(claimable_amount)
+    }
+
+    /// Records LP tokens claimed by the beneficiary.
+    ///
+    /// # Parameters
+    /// - `amount`: The amount of LP tokens claimed.
+    pub(crate) fn claim(&mut self, amount: u64) {
+        self.claimed_amount = self.claimed_amount.checked_add(amount).unwrap();
+    }
+
+    /// Returns the bump seed used for the account's PDA.
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    /// Returns the bump seed used for the vault's PDA.
+    #[inline]
+    pub fn vault_bump(&self) -> u8 {
+        self.vault_bump[0]
+    }
+
+    /// Returns the public key of the `CpAmm` the LP tokens belong to.
+    #[inline]
+    pub fn cp_amm(&self) -> &Pubkey {
+        &self.cp_amm
+    }
+
+    /// Returns the public key of the LP mint.
+    #[inline]
+    pub fn lp_mint(&self) -> &Pubkey {
+        &self.lp_mint
+    }
+
+    /// Returns the public key of the account that locked the LP tokens.
+    #[inline]
+    pub fn owner(&self) -> &Pubkey {
+        &self.owner
+    }
+
+    /// Returns the public key of the account that can claim the unlocked LP tokens.
+    #[inline]
+    pub fn beneficiary(&self) -> &Pubkey {
+        &self.beneficiary
+    }
+
+    /// Returns the nonce of the lock.
+    #[inline]
+    pub fn nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    /// Returns the amount of LP tokens locked.
+    #[inline]
+    pub fn locked_amount(&self) -> u64 {
+        self.locked_amount
+    }
+
+    /// Returns the amount of LP tokens already claimed.
+    #[inline]
+    pub fn claimed_amount(&self) -> u64 {
+        self.claimed_amount
+    }
+
+    /// Returns the Unix timestamp from which the LP tokens start vesting.
+    #[inline]
+    pub fn vesting_start_timestamp(&self) -> i64 {
+        self.vesting_start_timestamp
+    }
+
+    /// Returns the Unix timestamp at which all the LP tokens are unlocked.
+    #[inline]
+    pub fn unlock_timestamp(&self) -> i64 {
+        self.unlock_timestamp
+    }
+}
+
+#[cfg(test)]
+mod lp_lock_tests {
+    use anchor_lang::Discriminator;
+    use crate::constants::ANCHOR_DISCRIMINATOR;
+    use super::*;
+
+    fn build_lp_lock(locked_amount: u64, schedule: LpLockSchedule) -> LpLock {
+        let data = [0u8; ANCHOR_DISCRIMINATOR + LpLock::INIT_SPACE];
+        let mut lp_lock = LpLock::try_deserialize_unchecked(&mut data.as_ref()).unwrap();
+        lp_lock.initialize(Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), 7, 254, 253);
+        lp_lock.lock(LpLockTerms { beneficiary: Pubkey::new_unique(), locked_amount, schedule }, 0).unwrap();
+        lp_lock
+    }
+
+    /// Tests the validation of the `lock` method of `LpLock`.
+    #[test]
+    fn test_lp_lock_initialize() {
+        let schedule = LpLockSchedule { vesting_start_timestamp: 100, unlock_timestamp: 200 };
+        let lp_lock = build_lp_lock(1000, schedule);
+        assert_eq!(lp_lock.locked_amount(), 1000);
+        assert_eq!(lp_lock.claimed_amount(), 0);
+        assert_eq!(lp_lock.nonce(), 7);
+        assert_eq!(lp_lock.bump(), 254);
+        assert_eq!(lp_lock.vault_bump(), 253);
+        assert_eq!(lp_lock.vesting_start_timestamp(), 100);
+        assert_eq!(lp_lock.unlock_timestamp(), 200);
+
+        let mut lp_lock = build_lp_lock(1000, schedule);
+        let terms = LpLockTerms { beneficiary: Pubkey::default(), locked_amount: 1000, schedule };
+        assert_eq!(
+            lp_lock.lock(LpLockTerms { locked_amount: 0, ..terms }, 0).unwrap_err(),
+            ErrorCode::LpLockAmountIsZero.into()
+        );
+        let reversed_schedule = LpLockSchedule { vesting_start_timestamp: 201, unlock_timestamp: 200 };
+        assert_eq!(
+            lp_lock.lock(LpLockTerms { schedule: reversed_schedule, ..terms }, 0).unwrap_err(),
+            ErrorCode::InvalidLpLockSchedule.into()
+        );
+        assert_eq!(
+            lp_lock.lock(terms, 200).unwrap_err(),
+            ErrorCode::InvalidLpLockSchedule.into()
+        );
+    }
+
+    /// Tests the unlocking and claiming of a vesting `LpLock`.
+    #[test]
+    fn test_lp_lock_vesting_claims() {
+        let mut lp_lock = build_lp_lock(1000, LpLockSchedule { vesting_start_timestamp: 100, unlock_timestamp: 200 });
+
+        assert_eq!(lp_lock.unlocked_amount(50), 0);
+        assert_eq!(lp_lock.get_claimable_amount(100).unwrap_err(), ErrorCode::LpLockNothingToClaim.into());
+        assert_eq!(lp_lock.unlocked_amount(150), 500);
+
+        let claimable_amount = lp_lock.get_claimable_amount(125).unwrap();
+        assert_eq!(claimable_amount, 250);
+        lp_lock.claim(claimable_amount);
+        assert_eq!(lp_lock.get_claimable_amount(125).unwrap_err(), ErrorCode::LpLockNothingToClaim.into());
+        assert_eq!(lp_lock.get_claimable_amount(199).unwrap(), 740);
+        assert_eq!(lp_lock.get_claimable_amount(300).unwrap(), 750);
+
+        lp_lock.claim(750);
+        assert_eq!(lp_lock.claimed_amount(), 1000);
+        assert_eq!(lp_lock.get_claimable_amount(300).unwrap_err(), ErrorCode::LpLockNothingToClaim.into());
+    }
+
+    /// Tests the unlocking of a cliff `LpLock`.
+    #[test]
+    fn test_lp_lock_cliff_unlock() {
+        let lp_lock = build_lp_lock(1000, LpLockSchedule { vesting_start_timestamp: 200, unlock_timestamp: 200 });
+
+        assert_eq!(lp_lock.unlocked_amount(199), 0);
+        assert_eq!(lp_lock.unlocked_amount(200), 1000);
+    }
+
+    /// Tests the data layout of `LpLock`.
+    #[test]
+    fn test_lp_lock_data_layout() {
+        let bump = 254u8;
+        let vault_bump = 253u8;
+        let cp_amm = Pubkey::new_unique();
+        let lp_mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let beneficiary = Pubkey::new_unique();
+        let nonce = 3u64;
+        let locked_amount = 1_000_000u64;
+        let claimed_amount = 250_000u64;
+        let vesting_start_timestamp = 1_700_000_000i64;
+        let unlock_timestamp = 1_800_000_000i64;
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 170];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&LpLock::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset] = bump; offset += 1;
+        data[offset] = vault_bump; offset += 1;
+        data[offset..offset + 32].copy_from_slice(cp_amm.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(lp_mint.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(owner.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(beneficiary.as_ref()); offset += 32;
+        data[offset..offset + 8].copy_from_slice(&nonce.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&locked_amount.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&claimed_amount.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&vesting_start_timestamp.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&unlock_timestamp.to_le_bytes()); offset += 8;
+
+        assert_eq!(offset, ANCHOR_DISCRIMINATOR + LpLock::INIT_SPACE);
+
+        let deserialized_lp_lock = LpLock::try_deserialize(&mut data.as_ref()).unwrap();
+
+        assert_eq!(deserialized_lp_lock.bump(), bump);
+        assert_eq!(deserialized_lp_lock.vault_bump(), vault_bump);
+        assert_eq!(deserialized_lp_lock.cp_amm, cp_amm);
+        assert_eq!(deserialized_lp_lock.lp_mint, lp_mint);
+        assert_eq!(deserialized_lp_lock.owner, owner);
+        assert_eq!(deserialized_lp_lock.beneficiary, beneficiary);
+        assert_eq!(deserialized_lp_lock.nonce, nonce);
+        assert_eq!(deserialized_lp_lock.locked_amount, locked_amount);
+        assert_eq!(deserialized_lp_lock.claimed_amount, claimed_amount);
+        assert_eq!(deserialized_lp_lock.vesting_start_timestamp, vesting_start_timestamp);
+        assert_eq!(deserialized_lp_lock.unlock_timestamp, unlock_timestamp);
+
+        let mut serialized_lp_lock = Vec::new();
+        deserialized_lp_lock.try_serialize(&mut serialized_lp_lock).unwrap();
+        assert_eq!(serialized_lp_lock.as_slice(), data.as_ref());
+    }
+}
@@ -1,6 +1,14 @@
+mod account_version;
 mod amms_config;
 mod amms_configs_manager;
+mod lp_lock;
+mod farm;
+mod bonding_curve;
 pub mod cp_amm;
 
+pub use account_version::*;
 pub use amms_configs_manager::*;
-pub use amms_config::*;
\ No newline at end of file
+pub use amms_config::*;
+pub use lp_lock::*;
+pub use farm::*;
+pub use bonding_curve::*;
\ No newline at end of file
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
+use crate::constants::ANCHOR_DISCRIMINATOR;
 use crate::error::ErrorCode;
+use crate::state::LegacyLayout;
+use crate::utils::system_instructions::TransferLamportsInstruction;
 use anchor_spl::{
     token::{ID as TOKEN_PROGRAM_ID},
     token_2022::{ID as TOKEN_2022_PROGRAM_ID},
@@ -55,4 +58,28 @@ pub(crate) fn validate_tradable_mint(tradable_mint: &InterfaceAccount<Mint>) ->
         TOKEN_PROGRAM_ID => Ok(()),
         _ => Err(ErrorCode::UnsupportedMint.into()),
     }
-}
\ No newline at end of file
+}
+
+/// Migrates a legacy account to the current layout of its type in place.
+///
+/// # Parameters
+/// - `account`: The program-owned account with the `LegacyLayout` `L`.
+/// - `payer`: Funds the rent of the grown account.
+/// - `system_program`: The system program to transfer the rent with.
+///
+/// # Returns
+/// - `Ok(())`: If the account is grown and holds the migrated state.
+/// - `Err(ErrorCode)`: If the account data doesn't match the legacy layout or the rent can't be paid.
+pub(crate) fn migrate_legacy_account<'info, L: LegacyLayout>(account: &AccountInfo<'info>, payer: &Signer<'info>, system_program: &Program<'info, System>) -> Result<()> {
+    let legacy = L::try_from_account_data(&account.try_borrow_data()?)?;
+
+    let space = ANCHOR_DISCRIMINATOR + L::Current::INIT_SPACE;
+    let rent_to_pay = Rent::get()?.minimum_balance(space).saturating_sub(account.lamports());
+    if rent_to_pay > 0 {
+        TransferLamportsInstruction::new(rent_to_pay, payer.to_account_info(), account.clone(), system_program)?.execute()?;
+    }
+    account.realloc(space, true)?;
+
+    let migrated = legacy.migrate(Clock::get()?.unix_timestamp);
+    migrated.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{CloseAccount, close_account};
+
+/// Represents an instruction to close a PDA token account.
+///
+/// This struct handles closing token accounts of both standard SPL tokens and SPL Token 2022 tokens,
+/// returning the rent lamports to the destination account. The token account must be empty.
+///
+/// # Fields
+/// - `close_cpi_context`: Context for closing the token account with the appropriate token program.
+pub(crate) struct ClosePdaTokenAccountInstruction<'at, 'bt, 'ct, 'info> {
+    close_cpi_context: CpiContext<'at, 'bt, 'ct, 'info, CloseAccount<'info>>,
+}
+impl<'at, 'bt, 'ct, 'info> ClosePdaTokenAccountInstruction<'at, 'bt, 'ct, 'info>{
+
+    /// Creates a new instance of `ClosePdaTokenAccountInstruction`.
+    ///
+    /// # Arguments
+    /// - `token_account`: The PDA token account to be closed.
+    /// - `destination`: The account receiving the rent lamports.
+    /// - `authority`: The authority of the token account.
+    /// - `token_program`: The token program (SPL Token or Token 2022).
+    pub(crate) fn new(
+        token_account: AccountInfo<'info>,
+        destination: AccountInfo<'info>,
+        authority: AccountInfo<'info>,
+        token_program: AccountInfo<'info>
+    ) -> Self{
+        let close_cpi_context = CpiContext::new(
+            token_program,
+            CloseAccount {
+                account: token_account,
+                destination,
+                authority
+            }
+        );
+        Self{
+            close_cpi_context
+        }
+    }
+
+    /// Executes the closing of the PDA token account.
+    ///
+    /// # Arguments
+    /// - `signers_seeds`: The seeds required for signing the transaction as the token account authority.
+    #[inline(never)]
+    pub(crate) fn execute(self, signers_seeds: &'at[&'bt[&'ct[u8]]]) -> Result<()> {
+        close_account(self.close_cpi_context.with_signer(signers_seeds))
+    }
+}
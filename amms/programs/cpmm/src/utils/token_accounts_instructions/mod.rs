@@ -1,3 +1,5 @@
 mod create_pda_token_account;
+mod close_pda_token_account;
 
-pub(crate) use create_pda_token_account::*;
\ No newline at end of file
+pub(crate) use create_pda_token_account::*;
+pub(crate) use close_pda_token_account::*;
\ No newline at end of file
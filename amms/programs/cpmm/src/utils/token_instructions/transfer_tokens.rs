@@ -41,12 +41,33 @@ impl<'at, 'bt, 'ct, 'info>  TransferTokensInstruction<'at, 'bt, 'ct, 'info>  {
         from_authority: AccountInfo<'info>,
         to: &'_ InterfaceAccount<'info, TokenAccount>, 
         token_program: &'_ Interface<'info, TokenInterface>
+    ) -> Result<Self> {
+        Self::try_new_to_account_info(amount, mint, from, from_authority, to.to_account_info(), token_program)
+    }
+
+    /// Creates a new instance of `TransferTokensInstruction` to a destination passed as `AccountInfo`.
+    ///
+    /// Used when the destination token account is created in the same instruction,
+    /// so it can't be deserialized as an `InterfaceAccount` with the accounts lifetime.
+    ///
+    /// - `amount`: The amount of tokens to transfer.
+    /// - `mint`: The mint account of the token.
+    /// - `from`: The source token account.
+    /// - `from_authority`: Authority of the source account.
+    /// - `to_account_info`: The destination token account.
+    /// - `token_program`: Program for standard SPL tokens or SPL Token 2022.
+    pub fn try_new_to_account_info(
+        amount: u64, 
+        mint: &'_ InterfaceAccount<'info, Mint>, 
+        from: &'_ InterfaceAccount<'info, TokenAccount>, 
+        from_authority: AccountInfo<'info>,
+        to_account_info: AccountInfo<'info>, 
+        token_program: &'_ Interface<'info, TokenInterface>
     ) -> Result<Self> {
         require!(from.amount >= amount, ErrorCode::InsufficientBalanceForTransfer);
         require!(mint.to_account_info().owner.key() == token_program.key(), ErrorCode::MintAndTokenProgramMismatch);
         
         let from_account_info = from.to_account_info();
-        let to_account_info = to.to_account_info();
         
         let context = if mint.to_account_info().owner.key() == TOKEN_PROGRAM_ID {
             TransferContextType::Regular(
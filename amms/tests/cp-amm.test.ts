@@ -23,6 +23,7 @@ import {
     getWithdrawFromCpAmmInstruction,
     InitializeCpAmmInput,
     LaunchCpAmmInput,
+    PoolStatus,
     ProvideToCpAmmInput,
     SwapInCpAmmInput,
     WithdrawFromCpAmmInput
@@ -270,7 +271,8 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 systemProgram: SYSTEM_PROGRAM_ADDRESS,
                 lpTokenProgram: TOKEN_PROGRAM_ADDRESS,
                 baseTokenProgram: TOKEN_PROGRAM_ADDRESS,
-                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS
+                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS,
+                creatorFeeShareBasisPoints: 0
             }
 
             const ix = getInitializeCpAmmInstruction(input);
@@ -304,7 +306,8 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 systemProgram: SYSTEM_PROGRAM_ADDRESS,
                 lpTokenProgram: TOKEN_PROGRAM_ADDRESS,
                 baseTokenProgram: TOKEN_PROGRAM_ADDRESS,
-                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS
+                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS,
+                creatorFeeShareBasisPoints: 0
             }
 
             const ix = getInitializeCpAmmInstruction(input);
@@ -338,7 +341,8 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 systemProgram: SYSTEM_PROGRAM_ADDRESS,
                 lpTokenProgram: TOKEN_PROGRAM_ADDRESS,
                 baseTokenProgram: TOKEN_PROGRAM_ADDRESS,
-                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS
+                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS,
+                creatorFeeShareBasisPoints: 0
             }
 
             const ix = getInitializeCpAmmInstruction(input);
@@ -372,7 +376,8 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 systemProgram: SYSTEM_PROGRAM_ADDRESS,
                 lpTokenProgram: TOKEN_PROGRAM_ADDRESS,
                 baseTokenProgram: TOKEN_PROGRAM_ADDRESS,
-                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS
+                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS,
+                creatorFeeShareBasisPoints: 0
             }
 
             const ix = getInitializeCpAmmInstruction(input);
@@ -407,7 +412,8 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 systemProgram: SYSTEM_PROGRAM_ADDRESS,
                 lpTokenProgram: TOKEN_PROGRAM_ADDRESS,
                 baseTokenProgram: TOKEN_PROGRAM_ADDRESS,
-                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS
+                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS,
+                creatorFeeShareBasisPoints: 0
             }
 
             const ix = getInitializeCpAmmInstruction(input);
@@ -445,7 +451,8 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 systemProgram: SYSTEM_PROGRAM_ADDRESS,
                 lpTokenProgram: TOKEN_PROGRAM_ADDRESS,
                 baseTokenProgram: TOKEN_PROGRAM_ADDRESS,
-                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS
+                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS,
+                creatorFeeShareBasisPoints: 0
             }
 
             const ix = getInitializeCpAmmInstruction(input);
@@ -479,7 +486,8 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 systemProgram: SYSTEM_PROGRAM_ADDRESS,
                 lpTokenProgram: TOKEN_PROGRAM_ADDRESS,
                 baseTokenProgram: TOKEN_PROGRAM_ADDRESS,
-                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS
+                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS,
+                creatorFeeShareBasisPoints: 0
             }
 
             const ix = getInitializeCpAmmInstruction(input);
@@ -513,7 +521,8 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 systemProgram: SYSTEM_PROGRAM_ADDRESS,
                 lpTokenProgram: TOKEN_PROGRAM_ADDRESS,
                 baseTokenProgram: TOKEN_2022_PROGRAM_ADDRESS,
-                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS
+                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS,
+                creatorFeeShareBasisPoints: 0
             }
 
             const ix = getInitializeCpAmmInstruction(input);
@@ -548,7 +557,8 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 systemProgram: SYSTEM_PROGRAM_ADDRESS,
                 lpTokenProgram: TOKEN_PROGRAM_ADDRESS,
                 baseTokenProgram: TOKEN_PROGRAM_ADDRESS,
-                quoteTokenProgram: TOKEN_2022_PROGRAM_ADDRESS
+                quoteTokenProgram: TOKEN_2022_PROGRAM_ADDRESS,
+                creatorFeeShareBasisPoints: 0
             }
 
             const ix = getInitializeCpAmmInstruction(input);
@@ -589,7 +599,7 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
             assert.strictEqual(cpAmmAccount.data.lockedLpVault, TEST_CP_AMMS.lpVault1[0], "LP vault address mismatch");
 
             assert.strictEqual(cpAmmAccount.data.isInitialized, true,  "CpAmm should be initialized");
-            assert.strictEqual(cpAmmAccount.data.isLaunched, false,  "CpAmm shouldn't be launched");
+            assert.strictEqual(cpAmmAccount.data.status, PoolStatus.Initialized,  "CpAmm shouldn't be launched");
 
             assert.strictEqual(cpAmmAccount.data.initialLockedLiquidity, BigInt(0), "Initial locked liquidity should be 0");
             assert.strictEqual(cpAmmAccount.data.lpTokensSupply, BigInt(0), "LP token supply should be 0");
@@ -623,7 +633,8 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 systemProgram: SYSTEM_PROGRAM_ADDRESS,
                 lpTokenProgram: TOKEN_PROGRAM_ADDRESS,
                 baseTokenProgram: TOKEN_PROGRAM_ADDRESS,
-                quoteTokenProgram: TOKEN_2022_PROGRAM_ADDRESS
+                quoteTokenProgram: TOKEN_2022_PROGRAM_ADDRESS,
+                creatorFeeShareBasisPoints: 0
             }
 
             const ix = getInitializeCpAmmInstruction(input);
@@ -658,7 +669,8 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 systemProgram: SYSTEM_PROGRAM_ADDRESS,
                 lpTokenProgram: TOKEN_PROGRAM_ADDRESS,
                 baseTokenProgram: TOKEN_PROGRAM_ADDRESS,
-                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS
+                quoteTokenProgram: TOKEN_PROGRAM_ADDRESS,
+                creatorFeeShareBasisPoints: 0
             }
 
             const ix = getInitializeCpAmmInstruction(input);
@@ -699,7 +711,7 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
             assert.strictEqual(cpAmmAccount.data.lockedLpVault, TEST_CP_AMMS.lpVault2[0], "LP vault address mismatch");
 
             assert.strictEqual(cpAmmAccount.data.isInitialized, true,  "CpAmm should be initialized");
-            assert.strictEqual(cpAmmAccount.data.isLaunched, false,  "CpAmm shouldn't be launched");
+            assert.strictEqual(cpAmmAccount.data.status, PoolStatus.Initialized,  "CpAmm shouldn't be launched");
 
             assert.strictEqual(cpAmmAccount.data.initialLockedLiquidity, BigInt(0), "Initial locked liquidity should be 0");
             assert.strictEqual(cpAmmAccount.data.lpTokensSupply, BigInt(0), "LP token supply should be 0");
@@ -735,7 +747,8 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 systemProgram: SYSTEM_PROGRAM_ADDRESS,
                 lpTokenProgram: TOKEN_PROGRAM_ADDRESS,
                 baseTokenProgram: TOKEN_PROGRAM_ADDRESS,
-                quoteTokenProgram: TOKEN_2022_PROGRAM_ADDRESS
+                quoteTokenProgram: TOKEN_2022_PROGRAM_ADDRESS,
+                creatorFeeShareBasisPoints: 0
             }
             const ix = getInitializeCpAmmInstruction(input);
 
@@ -775,7 +788,7 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
             assert.strictEqual(cpAmmAccount.data.lockedLpVault, TEST_CP_AMMS.lpVault3[0], "LP vault address mismatch");
 
             assert.strictEqual(cpAmmAccount.data.isInitialized, true,  "CpAmm should be initialized");
-            assert.strictEqual(cpAmmAccount.data.isLaunched, false,  "CpAmm shouldn't be launched");
+            assert.strictEqual(cpAmmAccount.data.status, PoolStatus.Initialized,  "CpAmm shouldn't be launched");
 
             assert.strictEqual(cpAmmAccount.data.initialLockedLiquidity, BigInt(0), "Initial locked liquidity should be 0");
             assert.strictEqual(cpAmmAccount.data.lpTokensSupply, BigInt(0), "LP token supply should be 0");
@@ -815,6 +828,9 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 cpAmmLockedLpVault: TEST_CP_AMMS.lpVault1[0],
                 cpAmmQuoteVault: TEST_CP_AMMS.quoteVault1[0],
                 lpMint: cpAmmAccountBefore.data.lpMint,
+                creatorLpLockConfig: null,
+                launchFeeSchedule: null,
+                openTime: BigInt(0),
                 quoteLiquidity,
                 quoteMint: cpAmmAccountBefore.data.quoteMint,
                 creator: user,
@@ -861,6 +877,9 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 cpAmmLockedLpVault: TEST_CP_AMMS.lpVault1[0],
                 cpAmmQuoteVault: TEST_CP_AMMS.quoteVault1[0],
                 lpMint: cpAmmAccountBefore.data.lpMint,
+                creatorLpLockConfig: null,
+                launchFeeSchedule: null,
+                openTime: BigInt(0),
                 quoteLiquidity,
                 quoteMint: cpAmmAccountBefore.data.quoteMint,
                 creator: user,
@@ -907,6 +926,9 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 cpAmmLockedLpVault: TEST_CP_AMMS.lpVault1[0],
                 cpAmmQuoteVault: TEST_CP_AMMS.quoteVault1[0],
                 lpMint: cpAmmAccountBefore.data.lpMint,
+                creatorLpLockConfig: null,
+                launchFeeSchedule: null,
+                openTime: BigInt(0),
                 quoteLiquidity,
                 quoteMint: cpAmmAccountBefore.data.quoteMint,
                 creator: generalUser,
@@ -962,6 +984,9 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 cpAmmLockedLpVault: TEST_CP_AMMS.lpVault1[0],
                 cpAmmQuoteVault: TEST_CP_AMMS.quoteVault1[0],
                 lpMint: cpAmmAccountBefore.data.lpMint,
+                creatorLpLockConfig: null,
+                launchFeeSchedule: null,
+                openTime: BigInt(0),
                 quoteLiquidity,
                 quoteMint: cpAmmAccountBefore.data.quoteMint,
                 creator: user,
@@ -1022,7 +1047,8 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
             assert.strictEqual(cpAmmAccountBefore.data.lockedLpVaultBump[0], cpAmmAccountAfter.data.lockedLpVaultBump[0], "Locked LP vault bump value should remain unchanged");
 
             assert.strictEqual(cpAmmAccountAfter.data.isInitialized, true,  "CpAmm should be initialized");
-            assert.strictEqual(cpAmmAccountAfter.data.isLaunched, true,  "CpAmm should be launched");
+            assert.strictEqual(cpAmmAccountAfter.data.status, PoolStatus.Launched,  "CpAmm should be launched");
+            assert.strictEqual(cpAmmAccountAfter.data.openTime, BigInt(0),  "CpAmm should be open immediately");
 
             assert.strictEqual(cpAmmAccountAfter.data.initialLockedLiquidity, initialLockedLiquidity, `Initial locked liquidity does not match expected value`);
             assert.strictEqual(cpAmmAccountAfter.data.lpTokensSupply, totalLiquidity, `LP token supply does not match expected value`);
@@ -1053,6 +1079,9 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 cpAmmLockedLpVault: TEST_CP_AMMS.lpVault1[0],
                 cpAmmQuoteVault: TEST_CP_AMMS.quoteVault1[0],
                 lpMint: cpAmmAccountBefore.data.lpMint,
+                creatorLpLockConfig: null,
+                launchFeeSchedule: null,
+                openTime: BigInt(0),
                 quoteLiquidity,
                 quoteMint: cpAmmAccountBefore.data.quoteMint,
                 creator: user,
@@ -1101,6 +1130,9 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 cpAmmLockedLpVault: TEST_CP_AMMS.lpVault2[0],
                 cpAmmQuoteVault: TEST_CP_AMMS.quoteVault2[0],
                 lpMint: cpAmmAccountBefore.data.lpMint,
+                creatorLpLockConfig: null,
+                launchFeeSchedule: null,
+                openTime: BigInt(0),
                 quoteLiquidity,
                 quoteMint: cpAmmAccountBefore.data.quoteMint,
                 creator: user,
@@ -1156,6 +1188,9 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 cpAmmLockedLpVault: TEST_CP_AMMS.lpVault2[0],
                 cpAmmQuoteVault: TEST_CP_AMMS.quoteVault2[0],
                 lpMint: cpAmmAccountBefore.data.lpMint,
+                creatorLpLockConfig: null,
+                launchFeeSchedule: null,
+                openTime: BigInt(0),
                 quoteLiquidity,
                 quoteMint: cpAmmAccountBefore.data.quoteMint,
                 creator: user,
@@ -1216,7 +1251,7 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
             assert.strictEqual(cpAmmAccountBefore.data.lockedLpVaultBump[0], cpAmmAccountAfter.data.lockedLpVaultBump[0], "Locked LP vault bump value should remain unchanged");
 
             assert.strictEqual(cpAmmAccountAfter.data.isInitialized, true,  "CpAmm should be initialized");
-            assert.strictEqual(cpAmmAccountAfter.data.isLaunched, true,  "CpAmm should be launched");
+            assert.strictEqual(cpAmmAccountAfter.data.status, PoolStatus.Launched,  "CpAmm should be launched");
 
             assert.strictEqual(cpAmmAccountAfter.data.initialLockedLiquidity, initialLockedLiquidity, `Initial locked liquidity does not match expected value`);
             assert.strictEqual(cpAmmAccountAfter.data.lpTokensSupply, totalLiquidity, `LP token supply does not match expected value`);
@@ -1262,6 +1297,9 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
                 cpAmmLockedLpVault: TEST_CP_AMMS.lpVault3[0],
                 cpAmmQuoteVault: TEST_CP_AMMS.quoteVault3[0],
                 lpMint: cpAmmAccountBefore.data.lpMint,
+                creatorLpLockConfig: null,
+                launchFeeSchedule: null,
+                openTime: BigInt(0),
                 quoteLiquidity,
                 quoteMint: cpAmmAccountBefore.data.quoteMint,
                 creator: user,
@@ -1322,7 +1360,7 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
             assert.strictEqual(cpAmmAccountBefore.data.lockedLpVaultBump[0], cpAmmAccountAfter.data.lockedLpVaultBump[0], "Locked LP vault bump value should remain unchanged");
 
             assert.strictEqual(cpAmmAccountAfter.data.isInitialized, true,  "CpAmm should be initialized");
-            assert.strictEqual(cpAmmAccountAfter.data.isLaunched, true,  "CpAmm should be launched");
+            assert.strictEqual(cpAmmAccountAfter.data.status, PoolStatus.Launched,  "CpAmm should be launched");
 
             assert.strictEqual(cpAmmAccountAfter.data.initialLockedLiquidity, initialLockedLiquidity, `Initial locked liquidity does not match expected value`);
             assert.strictEqual(cpAmmAccountAfter.data.lpTokensSupply, totalLiquidity, `LP token supply does not match expected value`);
@@ -1469,7 +1507,7 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
             assert.strictEqual(cpAmmAccountBefore.data.lockedLpVaultBump[0], cpAmmAccountAfter.data.lockedLpVaultBump[0], "Locked LP vault bump value should remain unchanged");
 
             assert.strictEqual(cpAmmAccountAfter.data.isInitialized, true,  "CpAmm should be initialized");
-            assert.strictEqual(cpAmmAccountAfter.data.isLaunched, true,  "CpAmm should be launched");
+            assert.strictEqual(cpAmmAccountAfter.data.status, PoolStatus.Launched,  "CpAmm should be launched");
 
             assert.strictEqual(cpAmmAccountBefore.data.initialLockedLiquidity, cpAmmAccountAfter.data.initialLockedLiquidity, `Initial locked liquidity should remain unchanged`);
             assert.strictEqual(cpAmmAccountAfter.data.lpTokensSupply - cpAmmAccountBefore.data.lpTokensSupply, providedLiquidity, `LP token supply does not match expected value`);
@@ -1576,7 +1614,7 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
             assert.strictEqual(cpAmmAccountBefore.data.lockedLpVaultBump[0], cpAmmAccountAfter.data.lockedLpVaultBump[0], "Locked LP vault bump value should remain unchanged");
 
             assert.strictEqual(cpAmmAccountAfter.data.isInitialized, true,  "CpAmm should be initialized");
-            assert.strictEqual(cpAmmAccountAfter.data.isLaunched, true,  "CpAmm should be launched");
+            assert.strictEqual(cpAmmAccountAfter.data.status, PoolStatus.Launched,  "CpAmm should be launched");
 
             assert.strictEqual(cpAmmAccountBefore.data.initialLockedLiquidity, cpAmmAccountAfter.data.initialLockedLiquidity, `Initial locked liquidity should remain unchanged`);
             assert.strictEqual(cpAmmAccountAfter.data.lpTokensSupply - cpAmmAccountBefore.data.lpTokensSupply, providedLiquidity, `LP token supply does not match expected value`);
@@ -1774,7 +1812,7 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
             assert.strictEqual(cpAmmAccountBefore.data.lockedLpVaultBump[0], cpAmmAccountAfter.data.lockedLpVaultBump[0], "Locked LP vault bump value should remain unchanged");
 
             assert.strictEqual(cpAmmAccountAfter.data.isInitialized, true,  "CpAmm should be initialized");
-            assert.strictEqual(cpAmmAccountAfter.data.isLaunched, true,  "CpAmm should be launched");
+            assert.strictEqual(cpAmmAccountAfter.data.status, PoolStatus.Launched,  "CpAmm should be launched");
 
             assert.strictEqual(cpAmmAccountAfter.data.initialLockedLiquidity, cpAmmAccountBefore.data.initialLockedLiquidity, `Initial locked liquidity should remain unchanged`);
             assert.strictEqual(cpAmmAccountAfter.data.lpTokensSupply, cpAmmAccountBefore.data.lpTokensSupply, `LP token supply should remain unchanged`);
@@ -1871,7 +1909,7 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
             assert.strictEqual(cpAmmAccountBefore.data.lockedLpVaultBump[0], cpAmmAccountAfter.data.lockedLpVaultBump[0], "Locked LP vault bump value should remain unchanged");
 
             assert.strictEqual(cpAmmAccountAfter.data.isInitialized, true,  "CpAmm should be initialized");
-            assert.strictEqual(cpAmmAccountAfter.data.isLaunched, true,  "CpAmm should be launched");
+            assert.strictEqual(cpAmmAccountAfter.data.status, PoolStatus.Launched,  "CpAmm should be launched");
 
             assert.strictEqual(cpAmmAccountAfter.data.initialLockedLiquidity, cpAmmAccountBefore.data.initialLockedLiquidity, `Initial locked liquidity should remain unchanged`);
             assert.strictEqual(cpAmmAccountAfter.data.lpTokensSupply, cpAmmAccountBefore.data.lpTokensSupply, `LP token supply should remain unchanged`);
@@ -1976,7 +2014,7 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
             assert.strictEqual(cpAmmAccountBefore.data.lockedLpVaultBump[0], cpAmmAccountAfter.data.lockedLpVaultBump[0], "Locked LP vault bump value should remain unchanged");
 
             assert.strictEqual(cpAmmAccountAfter.data.isInitialized, true,  "CpAmm should be initialized");
-            assert.strictEqual(cpAmmAccountAfter.data.isLaunched, true,  "CpAmm should be launched");
+            assert.strictEqual(cpAmmAccountAfter.data.status, PoolStatus.Launched,  "CpAmm should be launched");
 
             assert.strictEqual(cpAmmAccountAfter.data.initialLockedLiquidity, cpAmmAccountBefore.data.initialLockedLiquidity, `Initial locked liquidity should remain unchanged`);
             assert.strictEqual(cpAmmAccountAfter.data.lpTokensSupply, cpAmmAccountBefore.data.lpTokensSupply, `LP token supply should remain unchanged`);
@@ -2115,7 +2153,7 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
             assert.strictEqual(cpAmmAccountBefore.data.lockedLpVaultBump[0], cpAmmAccountAfter.data.lockedLpVaultBump[0], "Locked LP vault bump value should remain unchanged");
 
             assert.strictEqual(cpAmmAccountAfter.data.isInitialized, true,  "CpAmm should be initialized");
-            assert.strictEqual(cpAmmAccountAfter.data.isLaunched, true,  "CpAmm should be launched");
+            assert.strictEqual(cpAmmAccountAfter.data.status, PoolStatus.Launched,  "CpAmm should be launched");
 
             assert.strictEqual(cpAmmAccountBefore.data.initialLockedLiquidity, cpAmmAccountAfter.data.initialLockedLiquidity, `Initial locked liquidity should remain unchanged`);
             assert.strictEqual(cpAmmAccountBefore.data.lpTokensSupply - cpAmmAccountAfter.data.lpTokensSupply, withdrawLiquidity, `LP token supply does not match expected value`);
@@ -2247,7 +2285,7 @@ export const cpAmmTests = (cpmmTestingEnvironment: CpmmTestingEnvironment, ammsC
             assert.strictEqual(cpAmmAccountBefore.data.lockedLpVaultBump[0], cpAmmAccountAfter.data.lockedLpVaultBump[0], "Locked LP vault bump value should remain unchanged");
 
             assert.strictEqual(cpAmmAccountAfter.data.isInitialized, true,  "CpAmm should be initialized");
-            assert.strictEqual(cpAmmAccountAfter.data.isLaunched, true,  "CpAmm should be launched");
+            assert.strictEqual(cpAmmAccountAfter.data.status, PoolStatus.Launched,  "CpAmm should be launched");
 
             assert.strictEqual(cpAmmAccountAfter.data.initialLockedLiquidity, cpAmmAccountBefore.data.initialLockedLiquidity, `Initial locked liquidity should remain unchanged`);
             assert.strictEqual(cpAmmAccountAfter.data.lpTokensSupply, cpAmmAccountBefore.data.lpTokensSupply, `LP token supply should remain unchanged`);